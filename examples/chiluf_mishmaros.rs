@@ -1,26 +1,18 @@
 // calculate the beginning of the second ashmura, the correct time to start
 // slichos according to some opinions
-use jiff::{Span, civil, tz::TimeZone};
+use jiff::{civil, tz::TimeZone};
 use rust_zmanim::prelude::*;
-use std::ops::Add;
 
 fn main() {
     let new_york = TimeZone::get("America/New_York").unwrap();
     // first night of slichos
     let date = civil::date(2025, 9, 13);
     let yu = GeoLocation::new(40.8506041, -73.9297205, 0.0, new_york).unwrap();
+    let czc = ComplexZmanimCalendar::new(yu, date, UseElevation::No);
 
-    // beginning of the night
-    let sunset = zmanim_calculator::shkia(date, &yu, false).unwrap();
-
-    // add a day to calculate sunrise the next day
-    let tomorrow = date.add(Span::new().days(1));
-    // end of the night
-    let sunrise = zmanim_calculator::hanetz(tomorrow, &yu, false).unwrap();
-
-    // each ashmura is 1/3 of the night
-    let ashmura = sunrise.duration_since(&sunset) / 3;
-    // first chiluf mishmaros is one ashmura into the night
-    let chiluf1 = sunset.add(ashmura).strftime("%Y-%m-%d %H:%M:%S %Z");
+    // the night from sunset to the next day's sunrise, divided into 3 ashmuros
+    let ashmuros = czc.ashmuros(3).unwrap();
+    // first chiluf mishmaros is the beginning of the second ashmura
+    let chiluf1 = ashmuros[1].strftime("%Y-%m-%d %H:%M:%S %Z");
     println!("{chiluf1}");
 }
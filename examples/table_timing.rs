@@ -0,0 +1,47 @@
+//! Time a year's table of 40 *zmanim* for a batch of cities, like a nightly
+//! job that publishes a *luach* for each of them. Run with `--release`.
+use std::time::Instant;
+
+use jiff::{civil, tz::TimeZone};
+use rust_zmanim::prelude::*;
+
+fn main() {
+    let zmanim: Vec<_> = ALL_ZMANIM
+        .iter()
+        .filter(|entry| entry.kind == ZmanKind::Time)
+        .step_by(5)
+        .take(40)
+        .collect();
+    // a spread of latitudes, where most degree-based zmanim still occur
+    let cities: Vec<_> = (0..100)
+        .map(|i| {
+            GeoLocation::new(
+                -45.0 + f64::from(i) * 0.9,
+                -74.0 + f64::from(i % 10),
+                0.0,
+                TimeZone::get("America/New_York").unwrap(),
+            )
+            .unwrap()
+        })
+        .collect();
+
+    let start = Instant::now();
+    let mut count = 0;
+    for city in &cities {
+        let table = ZmanimTable::new(
+            city.clone(),
+            civil::date(2026, 1, 1),
+            civil::date(2026, 12, 31),
+        )
+        .zmanim(zmanim.iter().copied());
+        for row in table.rows() {
+            count += row.values.iter().flatten().count();
+        }
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{count} zmanim for {} cities in {elapsed:.2?} ({:.2?} per city)",
+        cities.len(),
+        elapsed / cities.len() as u32
+    );
+}
@@ -25,6 +25,7 @@ fn main() {
                 println!("{}: {}", entry.name, time.strftime("%H:%M:%S %Z"));
             }
             Some(ZmanValue::Duration(duration)) => println!("{}: {duration:#}", entry.name),
+            Some(ZmanValue::Number(number)) => println!("{}: {number}", entry.name),
             None => println!("{}: does not occur today", entry.name),
         }
     }
@@ -20,9 +20,13 @@ use std::{
     ops::{Add, Sub},
 };
 
-use jiff::{SignedDuration, Zoned, civil::Date, tz::TimeZone};
+use jiff::{SignedDuration, Timestamp, Zoned, civil::Date, tz::TimeZone};
 
-use crate::util::{geolocation::GeoLocation, math_helper::HOUR_NANOS, noaa_calculator};
+use crate::util::{
+    geolocation::GeoLocation,
+    math_helper::HOUR_NANOS,
+    noaa_calculator::{self, SolarDay},
+};
 
 /// 90&deg; below the vertical. Used as a basis for most calculations since the
 /// location of the sun is 90&deg; below the vertical at sunrise and sunset.
@@ -160,6 +164,38 @@ pub fn sunset_offset_by_degrees(
     )
 }
 
+/// Returns sunrise at `zenith` (adjusted for refraction, the solar radius, and,
+/// if `adjust_for_elevation`, elevation) on a [`SolarDay`], for a calendar
+/// that calculates many events on the same day
+pub(crate) fn sunrise_on(
+    day: &SolarDay,
+    geo_location: &GeoLocation,
+    zenith: f64,
+    adjust_for_elevation: bool,
+) -> Option<Zoned> {
+    adjusted_date_time_from_time_of_day(
+        day.adjusted_date(),
+        day.utc_sunrise(geo_location, zenith, adjust_for_elevation)?,
+        geo_location,
+        &SolarEvent::Sunrise,
+    )
+}
+
+/// Returns sunset at `zenith` on a [`SolarDay`]. See [`sunrise_on`]
+pub(crate) fn sunset_on(
+    day: &SolarDay,
+    geo_location: &GeoLocation,
+    zenith: f64,
+    adjust_for_elevation: bool,
+) -> Option<Zoned> {
+    adjusted_date_time_from_time_of_day(
+        day.adjusted_date(),
+        day.utc_sunset(geo_location, zenith, adjust_for_elevation)?,
+        geo_location,
+        &SolarEvent::Sunset,
+    )
+}
+
 /// Returns a temporal (solar) hour based on the provided sunrise and sunset.
 #[must_use]
 pub fn temporal_hour(sunrise: &Zoned, sunset: &Zoned) -> SignedDuration {
@@ -209,9 +245,219 @@ pub fn solar_elevation(instant: &Zoned, geo_location: &GeoLocation) -> f64 {
     noaa_calculator::solar_elevation(instant, geo_location)
 }
 
+/// Returns the geometric solar elevation (in degrees) of the sun at the given
+/// datetime and location: the elevation of the center of the sun, without the
+/// refraction adjustment applied by [`solar_elevation`]. This is the elevation
+/// that the degree-based *zmanim* (such as [`sunrise_offset_by_degrees`]) are
+/// measured in.
+#[must_use]
+pub fn solar_geometric_elevation(instant: &Zoned, geo_location: &GeoLocation) -> f64 {
+    noaa_calculator::solar_geometric_elevation(instant, geo_location)
+}
+
+/// The sun's position and the earth's orbit at an instant, as calculated by
+/// the [NOAA algorithm](noaa_calculator). Returned by [`solar_ephemeris`].
+///
+/// The values depend only on the instant, not on the observer's location.
+/// They are calculated for the instant in Terrestrial Time (adding
+/// [`delta_t`](crate::util::delta_t)), as the orbital equations require.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarEphemeris {
+    /// The sun's apparent declination, in degrees north of the celestial
+    /// equator
+    pub declination: f64,
+    /// The sun's apparent right ascension, in degrees (`[0, 360)`) east of the
+    /// March equinox along the celestial equator. Divide by 15 for hours
+    pub right_ascension: f64,
+    /// The equation of time: apparent solar time minus mean solar time. A
+    /// positive value means a sundial is ahead of a clock keeping local mean
+    /// time
+    pub equation_of_time: SignedDuration,
+    /// The distance from the earth to the sun, in astronomical units
+    pub earth_sun_distance: f64,
+    /// The sun's apparent angular radius (semi-diameter), in degrees
+    pub apparent_radius: f64,
+    /// The obliquity of the ecliptic (the earth's axial tilt), corrected for
+    /// nutation, in degrees
+    pub obliquity: f64,
+    /// The (unitless) eccentricity of the earth's orbit
+    pub eccentricity: f64,
+}
+
+/// Returns the sun's [`SolarEphemeris`] (declination, right ascension,
+/// equation of time, and distance) at the given instant.
+#[must_use]
+pub fn solar_ephemeris(instant: &Zoned) -> SolarEphemeris {
+    noaa_calculator::solar_ephemeris(instant)
+}
+
+/// Whether the sun is rising or setting at a [`SolarCrossing`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolarDirection {
+    /// The sun is climbing, as at sunrise
+    Rising,
+    /// The sun is sinking, as at sunset
+    Setting,
+}
+
+/// An instant at which the sun crosses a given position in the sky, returned by
+/// [`times_at_elevation`] and [`times_at_azimuth`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolarCrossing {
+    /// When the crossing happens, in the location's timezone
+    pub time: Zoned,
+    /// Whether the sun is rising or setting at the crossing
+    pub direction: SolarDirection,
+}
+
+/// Returns every instant during the civil day `date` (local midnight to local
+/// midnight) at which the center of the sun crosses `elevation` degrees, in
+/// chronological order.
+///
+/// `elevation` is the [geometric elevation](solar_geometric_elevation) above
+/// the horizon, so it is negative below the horizon. For example, `10.0` is the
+/// sun 10&deg; above the horizon, and `-16.1` is the sun 16.1&deg; below the
+/// horizon, the same position as
+/// `sunrise_offset_by_degrees(date, geo_location, GEOMETRIC_ZENITH + 16.1)`.
+/// Unlike the sunrise and sunset functions, this is not limited to the first
+/// solution on each side of noon: a location and date with no crossing (such as
+/// the midnight sun, polar night, or the white nights of summer for deep
+/// twilight angles) returns an empty `Vec`, and one where the sun dips below
+/// and climbs back above the elevation within the same civil day returns all
+/// of the crossings.
+///
+/// Crossings are bracketed by sampling the sun's position every 10 minutes,
+/// then refined to the millisecond, so two crossings less than 10 minutes
+/// apart (the sun just grazing `elevation`) may not be found.
+#[must_use]
+pub fn times_at_elevation(
+    date: Date,
+    geo_location: &GeoLocation,
+    elevation: f64,
+) -> Vec<SolarCrossing> {
+    find_crossings(date, geo_location, |instant| {
+        noaa_calculator::solar_geometric_elevation(instant, geo_location) - elevation
+    })
+    .unwrap_or_default()
+}
+
+/// Interval at which [`find_crossings`] samples the sun's position
+const CROSSING_SCAN_STEP: SignedDuration = SignedDuration::from_mins(10);
+
+/// Precision to which [`find_crossings`] refines each crossing
+const CROSSING_PRECISION: SignedDuration = SignedDuration::from_millis(1);
+
+/// Returns every instant during the civil day `date` at which `difference`
+/// changes sign, in chronological order.
+///
+/// `difference` is the signed angle in degrees of the sun from the target
+/// position at an instant, in `[-180, 180)` or less. Each sample is
+/// [unwrapped](unwrap_degrees) to within half a turn of the one before it, so
+/// an angle wrapping around (such as an azimuth difference passing from
+/// 180&deg; to -180&deg; opposite the target) is not a crossing, however fast
+/// the sun moves. Returns `None` if the bounds of the civil day cannot be
+/// represented in the location's timezone.
+fn find_crossings(
+    date: Date,
+    geo_location: &GeoLocation,
+    difference: impl Fn(&Zoned) -> f64,
+) -> Option<Vec<SolarCrossing>> {
+    let tz = &geo_location.timezone;
+    let start = date.to_zoned(tz.clone()).ok()?.timestamp();
+    let end = date.tomorrow().ok()?.to_zoned(tz.clone()).ok()?.timestamp();
+    let value_at = |ts: Timestamp| difference(&ts.to_zoned(tz.clone()));
+
+    let mut crossings = Vec::new();
+    let mut t0 = start;
+    let mut f0 = value_at(t0);
+    while t0 < end {
+        let t1 = t0.checked_add(CROSSING_SCAN_STEP).ok()?.min(end);
+        let f1 = value_at(t1);
+        let unwrapped = unwrap_degrees(f1, f0);
+
+        let increasing = f0 < 0.0 && unwrapped >= 0.0;
+        let decreasing = f0 > 0.0 && unwrapped <= 0.0;
+        if increasing || decreasing {
+            // bisect the bracket down to the requested precision
+            let (mut lo, mut hi) = (t0, t1);
+            while hi.duration_since(lo) > CROSSING_PRECISION {
+                let mid = lo.checked_add(hi.duration_since(lo) / 2).ok()?;
+                if (unwrap_degrees(value_at(mid), f0) < 0.0) == increasing {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            crossings.push(solar_crossing(hi, geo_location)?);
+        }
+
+        t0 = t1;
+        f0 = f1;
+    }
+    Some(crossings)
+}
+
+/// Returns `angle` plus or minus whole turns, so that it is within half a turn
+/// of `reference`
+fn unwrap_degrees(angle: f64, reference: f64) -> f64 {
+    reference + (angle - reference + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Makes a [`SolarCrossing`] at `timestamp`, determining the sun's direction
+/// from the change in its elevation over the surrounding minute.
+fn solar_crossing(timestamp: Timestamp, geo_location: &GeoLocation) -> Option<SolarCrossing> {
+    let tz = &geo_location.timezone;
+    let half_minute = SignedDuration::from_secs(30);
+    let elevation_at = |ts: Timestamp| {
+        noaa_calculator::solar_geometric_elevation(&ts.to_zoned(tz.clone()), geo_location)
+    };
+    let before = elevation_at(timestamp.checked_sub(half_minute).ok()?);
+    let after = elevation_at(timestamp.checked_add(half_minute).ok()?);
+    Some(SolarCrossing {
+        time: timestamp.to_zoned(tz.clone()),
+        direction: if after >= before {
+            SolarDirection::Rising
+        } else {
+            SolarDirection::Setting
+        },
+    })
+}
+
+/// Returns every instant during the civil day `date` (local midnight to local
+/// midnight) at which the sun is at `azimuth` degrees (measured clockwise from
+/// due north, as in [`solar_azimuth`]), in chronological order.
+///
+/// The sun's direction at each crossing is whether its elevation is climbing
+/// or sinking at that moment. For example, `180.0` is the sun due south (solar
+/// noon in the northern temperate zone), and the azimuth of a synagogue's
+/// *mizrach* wall gives the times the sun is aligned with it. Whether the sun
+/// is above the horizon is not checked; use [`solar_elevation`] to filter the
+/// results if needed.
+///
+/// Depending on the location and date, the sun may not reach `azimuth` at all
+/// (due east and due west are never reached near the poles in the winter,
+/// or from the tropics when the sun passes north of the zenith), in which case
+/// an empty `Vec` is returned. As with [`times_at_elevation`], crossings are
+/// found by sampling every 10 minutes, so two crossings less than 10 minutes
+/// apart may not be found.
+#[must_use]
+pub fn times_at_azimuth(
+    date: Date,
+    geo_location: &GeoLocation,
+    azimuth: f64,
+) -> Vec<SolarCrossing> {
+    let target = azimuth.rem_euclid(360.0);
+    find_crossings(date, geo_location, |instant| {
+        (noaa_calculator::solar_azimuth(instant, geo_location) - target + 540.0).rem_euclid(360.0)
+            - 180.0
+    })
+    .unwrap_or_default()
+}
+
 /// A cardinal direction on the horizon, identifying the two solar azimuths
 /// used by [`time_at_azimuth`] for polar *zmanim*.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Azimuth {
     /// Due east, an azimuth of 90&deg;. Treated as sunrise in polar regions.
     East,
@@ -219,26 +465,34 @@ pub enum Azimuth {
     West,
 }
 
+impl Azimuth {
+    /// Returns the azimuth of this direction in degrees, measured clockwise
+    /// from due north, for use with [`times_at_azimuth`].
+    #[must_use]
+    pub const fn degrees(self) -> f64 {
+        match self {
+            Self::East => 90.0,
+            Self::West => 270.0,
+        }
+    }
+}
+
 /// Returns the time at which the sun is directly due [east](Azimuth::East)
 /// (azimuth 90&deg;) or due [west](Azimuth::West) (azimuth 270&deg;).
 ///
 /// This is used in polar regions on days when there is no sunrise or sunset,
 /// where some *halachic* opinions treat sunrise as the time the sun is directly
-/// due east and sunset as the time it is directly due west.
+/// due east and sunset as the time it is directly due west. It is the first of
+/// the [`times_at_azimuth`] for the direction.
 ///
 /// Returns `None` when the azimuth is never reached for the requested date and
 /// location.
 #[must_use]
 pub fn time_at_azimuth(date: Date, geo_location: &GeoLocation, azimuth: Azimuth) -> Option<Zoned> {
-    date_time_from_time_of_day(
-        date,
-        noaa_calculator::utc_time_at_azimuth(date, geo_location, azimuth)?,
-        geo_location,
-        match azimuth {
-            Azimuth::East => &SolarEvent::Sunrise,
-            Azimuth::West => &SolarEvent::Sunset,
-        },
-    )
+    times_at_azimuth(date, geo_location, azimuth.degrees())
+        .into_iter()
+        .next()
+        .map(|crossing| crossing.time)
 }
 
 /// The type of solar event being calculated, used to anchor a UTC time-of-day
@@ -268,7 +522,17 @@ fn date_time_from_time_of_day(
         &date.to_zoned(geo_location.timezone.clone()).ok()?,
         geo_location.longitude,
     );
+    adjusted_date_time_from_time_of_day(anchor, time_of_day, geo_location, event)
+}
 
+/// Like [`date_time_from_time_of_day`], with the date already
+/// [adjusted](noaa_calculator::antimeridian_adjusted_date) for the antimeridian
+fn adjusted_date_time_from_time_of_day(
+    anchor: Date,
+    time_of_day: f64,
+    geo_location: &GeoLocation,
+    event: &SolarEvent,
+) -> Option<Zoned> {
     // apparent solar time of the event, per the longitude's natural offset
     let local_time_hours = geo_location.longitude / 15.0 + time_of_day;
     let anchor = match event {
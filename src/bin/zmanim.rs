@@ -0,0 +1,501 @@
+//! `zmanim`: print the *zmanim* of a location for a day or a range of days, as
+//! a table, CSV, or JSON.
+//!
+//! Built with the `cli` feature: `cargo install rust-zmanim --features cli`.
+//! Run `zmanim --help` for the options.
+
+use std::{
+    io::{self, Write},
+    process::ExitCode,
+};
+
+use jiff::{Zoned, civil::Date, tz::TimeZone};
+use rust_zmanim::prelude::*;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+const USAGE: &str = "\
+Usage: zmanim (--city NAME | --lat DEG --lon DEG --tz IANA_NAME [--elevation M])
+              [--date YYYY-MM-DD | --from YYYY-MM-DD --to YYYY-MM-DD]
+              [--zman SPEC]... [--profile NAME]
+              [--use-elevation no|hanetz-shkia|all] [--format table|csv|json]
+       zmanim --list | --list-cities | --list-profiles | --help
+
+Location:
+  --city NAME          a built-in city (see --list-cities)
+  --lat, --lon DEG     degrees North and East
+  --elevation M        meters above sea level (default 0, or the city's)
+  --tz IANA_NAME       the time zone, such as America/New_York
+
+Dates (default today):
+  --date YYYY-MM-DD    one day
+  --from, --to DATE    every day from one date to another, inclusive
+
+Zmanim (default the GRA's zmanim of the day):
+  --zman SPEC          a registry name, such as sof_zman_shema_mga, or a
+                       zman with arguments, such as tzeis(8.5deg) or
+                       alos(72min); may be given more than once
+                       (each zman is printed once)
+  --profile NAME       every zman of a minhag profile (see --list-profiles),
+                       with its elevation setting and rounding
+
+Output:
+  --use-elevation      when to use the elevation (default no, or the
+                       profile's setting)
+  --format FORMAT      table (default), csv, or json
+";
+
+/// The *zmanim* printed when neither `--zman` nor `--profile` is given
+const DEFAULT_ZMANIM: &[&str] = &[
+    "alos_72_minutes",
+    "hanetz",
+    "sof_zman_shema_gra",
+    "sof_zman_tefila_gra",
+    "chatzos_hayom",
+    "mincha_gedola_gra",
+    "mincha_ketana_gra",
+    "plag_gra",
+    "shkia",
+    "tzeis_geonim_8_5_degrees",
+];
+
+/// A built-in location, at approximately the center of the city
+struct City {
+    name: &'static str,
+    latitude: f64,
+    longitude: f64,
+    elevation: f64,
+    timezone: &'static str,
+}
+
+const CITIES: &[City] = &[
+    City {
+        name: "jerusalem",
+        latitude: 31.778,
+        longitude: 35.2354,
+        elevation: 754.0,
+        timezone: "Asia/Jerusalem",
+    },
+    City {
+        name: "bnei_brak",
+        latitude: 32.0807,
+        longitude: 34.8338,
+        elevation: 40.0,
+        timezone: "Asia/Jerusalem",
+    },
+    City {
+        name: "lakewood",
+        latitude: 40.0821,
+        longitude: -74.2097,
+        elevation: 20.0,
+        timezone: "America/New_York",
+    },
+    City {
+        name: "new_york",
+        latitude: 40.7128,
+        longitude: -74.006,
+        elevation: 10.0,
+        timezone: "America/New_York",
+    },
+    City {
+        name: "monsey",
+        latitude: 41.1112,
+        longitude: -74.0685,
+        elevation: 150.0,
+        timezone: "America/New_York",
+    },
+    City {
+        name: "chicago",
+        latitude: 41.8781,
+        longitude: -87.6298,
+        elevation: 181.0,
+        timezone: "America/Chicago",
+    },
+    City {
+        name: "los_angeles",
+        latitude: 34.0522,
+        longitude: -118.2437,
+        elevation: 71.0,
+        timezone: "America/Los_Angeles",
+    },
+    City {
+        name: "toronto",
+        latitude: 43.6532,
+        longitude: -79.3832,
+        elevation: 76.0,
+        timezone: "America/Toronto",
+    },
+    City {
+        name: "montreal",
+        latitude: 45.5019,
+        longitude: -73.5674,
+        elevation: 36.0,
+        timezone: "America/Toronto",
+    },
+    City {
+        name: "london",
+        latitude: 51.5074,
+        longitude: -0.1278,
+        elevation: 11.0,
+        timezone: "Europe/London",
+    },
+    City {
+        name: "manchester",
+        latitude: 53.4808,
+        longitude: -2.2426,
+        elevation: 38.0,
+        timezone: "Europe/London",
+    },
+    City {
+        name: "paris",
+        latitude: 48.8566,
+        longitude: 2.3522,
+        elevation: 35.0,
+        timezone: "Europe/Paris",
+    },
+    City {
+        name: "antwerp",
+        latitude: 51.2194,
+        longitude: 4.4025,
+        elevation: 10.0,
+        timezone: "Europe/Brussels",
+    },
+    City {
+        name: "johannesburg",
+        latitude: -26.2041,
+        longitude: 28.0473,
+        elevation: 1753.0,
+        timezone: "Africa/Johannesburg",
+    },
+    City {
+        name: "melbourne",
+        latitude: -37.8136,
+        longitude: 144.9631,
+        elevation: 31.0,
+        timezone: "Australia/Melbourne",
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+/// What to print
+enum Command {
+    Help,
+    ListZmanim,
+    ListCities,
+    ListProfiles,
+    Zmanim(ZmanimTable, Format),
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("zmanim: {message}\nRun `zmanim --help` for usage.");
+            return ExitCode::from(2);
+        }
+    };
+    let mut out = io::stdout().lock();
+    let result = match command {
+        Command::Help => out.write_all(USAGE.as_bytes()),
+        Command::ListZmanim => list_zmanim(&mut out),
+        Command::ListCities => list_cities(&mut out),
+        Command::ListProfiles => MinhagProfile::BUILT_IN
+            .iter()
+            .try_for_each(|profile| writeln!(out, "{}", profile.name)),
+        Command::Zmanim(table, Format::Table) => write_table(&mut out, &table),
+        Command::Zmanim(table, Format::Csv) => write_csv(&mut out, &table),
+        Command::Zmanim(table, Format::Json) => write_json(&mut out, &table),
+    };
+    match result.and_then(|()| out.flush()) {
+        Ok(()) => ExitCode::SUCCESS,
+        // such as `zmanim ... | head`
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("zmanim: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses the command line (without the program name)
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut city = None;
+    let (mut latitude, mut longitude, mut elevation, mut timezone) = (None, None, None, None);
+    let (mut date, mut from, mut to) = (None, None, None);
+    let mut zmanim = Vec::new();
+    let mut profile = None;
+    let mut use_elevation = None;
+    let mut format = Format::Table;
+
+    while let Some(arg) = args.next() {
+        // `--name value` or `--name=value`
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.into())),
+            _ => (arg, None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{flag} needs a value"))
+        };
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--list" => return Ok(Command::ListZmanim),
+            "--list-cities" => return Ok(Command::ListCities),
+            "--list-profiles" => return Ok(Command::ListProfiles),
+            "--city" => {
+                let name = value()?;
+                city = Some(
+                    CITIES
+                        .iter()
+                        .find(|city| city.name == name)
+                        .ok_or_else(|| format!("unknown city {name:?} (see --list-cities)"))?,
+                );
+            }
+            "--lat" => latitude = Some(parse_number(&flag, &value()?)?),
+            "--lon" => longitude = Some(parse_number(&flag, &value()?)?),
+            "--elevation" => elevation = Some(parse_number(&flag, &value()?)?),
+            "--tz" => {
+                let name = value()?;
+                timezone = Some(
+                    TimeZone::get(&name)
+                        .map_err(|error| format!("unknown time zone {name:?}: {error}"))?,
+                );
+            }
+            "--date" => date = Some(parse_date(&flag, &value()?)?),
+            "--from" => from = Some(parse_date(&flag, &value()?)?),
+            "--to" => to = Some(parse_date(&flag, &value()?)?),
+            "--zman" => {
+                let spec = value()?;
+                let spec =
+                    ZmanSpec::parse(&spec).map_err(|error| format!("--zman {spec:?}: {error}"))?;
+                zmanim.push(spec);
+            }
+            "--profile" => {
+                let name = value()?;
+                profile =
+                    Some(MinhagProfile::find(&name).ok_or_else(|| {
+                        format!("unknown profile {name:?} (see --list-profiles)")
+                    })?);
+            }
+            "--use-elevation" => {
+                use_elevation = Some(match value()?.as_str() {
+                    "no" => UseElevation::No,
+                    "hanetz-shkia" => UseElevation::HanetzShkia,
+                    "all" => UseElevation::All,
+                    other => return Err(format!("unknown --use-elevation {other:?}")),
+                });
+            }
+            "--format" => {
+                format = match value()?.as_str() {
+                    "table" => Format::Table,
+                    "csv" => Format::Csv,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown --format {other:?}")),
+                };
+            }
+            _ => return Err(format!("unknown argument {flag:?}")),
+        }
+    }
+
+    let geo_location = match (city, latitude, longitude, timezone) {
+        (Some(city), None, None, None) => {
+            let timezone = TimeZone::get(city.timezone).map_err(|error| error.to_string())?;
+            let elevation = elevation.unwrap_or(city.elevation);
+            GeoLocation::new(city.latitude, city.longitude, elevation, timezone)
+        }
+        (None, Some(latitude), Some(longitude), Some(timezone)) => {
+            GeoLocation::new(latitude, longitude, elevation.unwrap_or(0.0), timezone)
+        }
+        (Some(_), ..) => return Err("give either --city or --lat, --lon, and --tz".into()),
+        _ => return Err("a location is needed: --city, or --lat, --lon, and --tz".into()),
+    }
+    .map_err(|error| error.to_string())?;
+
+    let (start, end) = match (date, from, to) {
+        (Some(date), None, None) => (date, date),
+        (None, Some(from), Some(to)) if from <= to => (from, to),
+        (None, Some(_), Some(_)) => return Err("--from is after --to".into()),
+        (None, None, None) => {
+            let today = Zoned::now().with_time_zone(geo_location.timezone().clone());
+            (today.date(), today.date())
+        }
+        (Some(_), ..) => return Err("give either --date or --from and --to".into()),
+        _ => return Err("--from and --to go together".into()),
+    };
+
+    let mut table = ZmanimTable::new(geo_location, start, end);
+    if let Some(profile) = profile {
+        table = table.profile(profile).map_err(|error| error.to_string())?;
+    } else if zmanim.is_empty() {
+        table = table.zmanim(DEFAULT_ZMANIM.iter().filter_map(|name| find_zman(name)));
+    }
+    // each column once, so that JSON objects have no repeated keys
+    for spec in zmanim {
+        let name = spec.to_string();
+        if !table.column_names().any(|column| column == name) {
+            table = table.zmanim([spec]);
+        }
+    }
+    if let Some(use_elevation) = use_elevation {
+        table = table.use_elevation(use_elevation);
+    }
+    Ok(Command::Zmanim(table, format))
+}
+
+fn parse_number(flag: &str, value: &str) -> Result<f64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{flag} expects a number, not {value:?}"))
+}
+
+fn parse_date(flag: &str, value: &str) -> Result<Date, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{flag} expects a date like 2025-03-14, not {value:?}"))
+}
+
+/// Lists the registry and the *zmanim* with arguments
+fn list_zmanim(out: &mut impl Write) -> io::Result<()> {
+    for entry in ALL_ZMANIM {
+        writeln!(out, "{}\t{}", entry.name, entry.summary)?;
+    }
+    for entry in PARAMETERIZED_ZMANIM {
+        let params: Vec<_> = entry.params.iter().map(|param| param.name).collect();
+        writeln!(
+            out,
+            "{}({})\t{}",
+            entry.name,
+            params.join(", "),
+            entry.summary
+        )?;
+    }
+    Ok(())
+}
+
+fn list_cities(out: &mut impl Write) -> io::Result<()> {
+    for city in CITIES {
+        writeln!(
+            out,
+            "{}\t{}, {}, {}m\t{}",
+            city.name, city.latitude, city.longitude, city.elevation, city.timezone
+        )?;
+    }
+    Ok(())
+}
+
+/// Columns aligned with spaces, with times in the location's time zone, and
+/// their date too if it is not the row's (such as *chatzos halayla* after
+/// midnight)
+fn write_table(out: &mut impl Write, table: &ZmanimTable) -> io::Result<()> {
+    let header: Vec<String> = std::iter::once("date")
+        .chain(table.column_names())
+        .map(String::from)
+        .collect();
+    let rows: Vec<Vec<String>> = table
+        .rows()
+        .map(|row| {
+            std::iter::once(row.date.to_string())
+                .chain(row.values.iter().map(|value| match value {
+                    None => "-".into(),
+                    Some(ZmanValue::Time(time)) if time.date() == row.date => {
+                        time.strftime("%H:%M:%S").to_string()
+                    }
+                    Some(ZmanValue::Time(time)) => time.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                    Some(ZmanValue::Duration(duration)) => format!("{duration:#}"),
+                    Some(ZmanValue::Number(number)) => format!("{number:.3}"),
+                }))
+                .collect()
+        })
+        .collect();
+    let mut widths: Vec<usize> = header.iter().map(String::len).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:width$}"))
+            .collect();
+        writeln!(out, "{}", cells.join("  ").trim_end())?;
+    }
+    Ok(())
+}
+
+/// A header and a line per day, with RFC 3339 times, ISO 8601 durations, and
+/// empty fields for *zmanim* that don't occur
+fn write_csv(out: &mut impl Write, table: &ZmanimTable) -> io::Result<()> {
+    let header: Vec<String> = std::iter::once("date")
+        .chain(table.column_names())
+        .map(csv_field)
+        .collect();
+    writeln!(out, "{}", header.join(","))?;
+    for row in table.rows() {
+        let fields: Vec<String> = std::iter::once(row.date.to_string())
+            .chain(
+                row.values
+                    .iter()
+                    .map(|value| value.as_ref().map(machine_value).unwrap_or_default()),
+            )
+            .map(|field| csv_field(&field))
+            .collect();
+        writeln!(out, "{}", fields.join(","))?;
+    }
+    Ok(())
+}
+
+/// An array of days, each an object of its `date` and its *zmanim* (as
+/// serialized by [`ZmanValue`]), with `null` for *zmanim* that don't occur
+fn write_json(out: &mut impl Write, table: &ZmanimTable) -> io::Result<()> {
+    let names: Vec<&str> = table.column_names().collect();
+    let days: Vec<JsonDay> = table
+        .rows()
+        .map(|row| JsonDay { names: &names, row })
+        .collect();
+    serde_json::to_writer_pretty(&mut *out, &days)?;
+    writeln!(out)
+}
+
+/// A row of the table, serialized as a JSON object in column order
+struct JsonDay<'a> {
+    names: &'a [&'a str],
+    row: ZmanimRow,
+}
+
+impl Serialize for JsonDay<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.names.len() + 1))?;
+        map.serialize_entry("date", &self.row.date)?;
+        for (name, value) in self.names.iter().zip(&self.row.values) {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+/// A value for CSV
+fn machine_value(value: &ZmanValue) -> String {
+    match value {
+        ZmanValue::Time(time) => time.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+        ZmanValue::Duration(duration) => duration.to_string(),
+        ZmanValue::Number(number) => number.to_string(),
+    }
+}
+
+/// Quotes a CSV field if it has a comma, quote, or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
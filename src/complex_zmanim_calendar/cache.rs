@@ -1,21 +1,113 @@
-use std::cell::{OnceCell, RefCell};
+#[cfg(not(feature = "sync"))]
+use std::cell::{OnceCell as Once, RefCell};
+#[cfg(feature = "sync")]
+use std::sync::{Mutex, OnceLock as Once, PoisonError};
 
-use jiff::Zoned;
+use jiff::{SignedDuration, Zoned, civil::Date};
+
+use super::ComplexZmanimCalendar;
+use crate::util::noaa_calculator::SolarDay;
 
 /// Lazily computed solar events for a single date/location, so that repeated
 /// *zmanim* calculations on the same calendar reuse the underlying
 /// astronomical results instead of re-running the solar position algorithm.
+///
+/// With the `sync` feature the cells are [`OnceLock`](std::sync::OnceLock)s
+/// and the degree caches are behind a [`Mutex`], so that a calendar is `Sync`
+/// and can be shared between threads.
 #[derive(Debug, Clone, Default)]
 pub(crate) struct ZmanCache {
-    pub(crate) elevation_sunrise: OnceCell<Option<Zoned>>,
-    pub(crate) elevation_sunset: OnceCell<Option<Zoned>>,
-    pub(crate) sea_level_sunrise: OnceCell<Option<Zoned>>,
-    pub(crate) sea_level_sunset: OnceCell<Option<Zoned>>,
-    pub(crate) solar_noon: OnceCell<Option<Zoned>>,
-    pub(crate) solar_midnight: OnceCell<Option<Zoned>>,
+    // The date's Julian day and solar noon ephemeris, shared by all of the
+    // sunrise and sunset events below.
+    pub(crate) solar_day: Once<Option<SolarDay>>,
+    pub(crate) elevation_sunrise: Once<Option<Zoned>>,
+    pub(crate) elevation_sunset: Once<Option<Zoned>>,
+    pub(crate) sea_level_sunrise: Once<Option<Zoned>>,
+    pub(crate) sea_level_sunset: Once<Option<Zoned>>,
+    pub(crate) solar_noon: Once<Option<Zoned>>,
+    pub(crate) solar_midnight: Once<Option<Zoned>>,
     // Degree-based events, keyed by `f64::to_bits` of the degrees below
-    // GEOMETRIC_ZENITH. Only ~25 distinct values are ever used, so a linear
-    // scan over a Vec beats hashing.
-    pub(crate) sunrise_by_degrees: RefCell<Vec<(u64, Option<Zoned>)>>,
-    pub(crate) sunset_by_degrees: RefCell<Vec<(u64, Option<Zoned>)>>,
+    // GEOMETRIC_ZENITH.
+    pub(crate) sunrise_by_degrees: DegreeCache,
+    pub(crate) sunset_by_degrees: DegreeCache,
+    // For `TwilightFallback::LastDayReached`: the last earlier date on which
+    // the depression was reached before sunrise (or after sunset), with the
+    // twilight on that date. Carried forward by `advance_day`.
+    pub(crate) alos_last_day_reached: DegreeCache<LastDayReached>,
+    pub(crate) tzeis_last_day_reached: DegreeCache<LastDayReached>,
+    // The calendar for the following day, with its own cache, for the
+    // nighttime zmanim that end on the next civil date. Its settings are kept
+    // in sync by the setters.
+    pub(crate) next_day: Once<Option<Box<ComplexZmanimCalendar>>>,
+}
+
+/// The last date on which a depression was reached, and the twilight (from
+/// sunrise back to it, or from sunset forward to it) on that date
+pub(crate) type LastDayReached = Option<(Date, SignedDuration)>;
+
+/// Solar events (or other values) by degrees below the horizon. Only ~25
+/// distinct values are ever used, so a linear scan over a Vec beats hashing.
+#[derive(Debug)]
+pub(crate) struct DegreeCache<T = Option<Zoned>> {
+    #[cfg(not(feature = "sync"))]
+    events: RefCell<Vec<(u64, T)>>,
+    #[cfg(feature = "sync")]
+    events: Mutex<Vec<(u64, T)>>,
+}
+
+impl<T: Clone> DegreeCache<T> {
+    /// Returns the cached value for `degrees`, computing and caching it with
+    /// `compute` the first time
+    pub(crate) fn get_or_insert_with(&self, degrees: f64, compute: impl FnOnce() -> T) -> T {
+        let key = degrees.to_bits();
+        if let Some((_, cached)) = self.events().iter().find(|(k, _)| *k == key) {
+            return cached.clone();
+        }
+        // computed without holding the lock; a race only computes an event
+        // twice
+        let result = compute();
+        self.events().push((key, result.clone()));
+        result
+    }
+
+    /// Caches `value` for `degrees`, unless a value is already cached
+    pub(crate) fn insert(&self, degrees: f64, value: T) {
+        self.get_or_insert_with(degrees, || value);
+    }
+
+    /// Every cached value, with its degrees
+    pub(crate) fn entries(&self) -> Vec<(f64, T)> {
+        self.events()
+            .iter()
+            .map(|(key, value)| (f64::from_bits(*key), value.clone()))
+            .collect()
+    }
+
+    #[cfg(not(feature = "sync"))]
+    fn events(&self) -> std::cell::RefMut<'_, Vec<(u64, T)>> {
+        self.events.borrow_mut()
+    }
+
+    #[cfg(feature = "sync")]
+    fn events(&self) -> std::sync::MutexGuard<'_, Vec<(u64, T)>> {
+        // the Vec is always valid, even if another thread panicked
+        self.events.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Default for DegreeCache<T> {
+    fn default() -> Self {
+        Self {
+            events: Vec::new().into(),
+        }
+    }
+}
+
+impl<T: Clone> Clone for DegreeCache<T> {
+    fn clone(&self) -> Self {
+        let events = self.events().clone();
+        Self {
+            events: events.into(),
+        }
+    }
 }
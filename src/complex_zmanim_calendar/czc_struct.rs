@@ -1,14 +1,23 @@
 use crate::{
     astronomical_calculator,
-    complex_zmanim_calendar::cache::ZmanCache,
-    util::geolocation::GeoLocation,
+    complex_zmanim_calendar::{
+        DayAnchor, DayDefinition, DayEvent, MinhagProfile, ProfileError, ProfileItem,
+        ScheduleEntry, Uncertainty, ZmanInterval, ZmanKind, ZmanValue,
+        cache::{DegreeCache, LastDayReached, ZmanCache},
+        find_zman,
+    },
+    util::{geolocation::GeoLocation, noaa_calculator::SolarDay, zenith_adjustments},
     zmanim_calculator::{
-        self,
+        self, ZmanError,
         ZmanOffset::{self, Degrees, Minutes, MinutesZmaniyos},
     },
 };
 
-use jiff::{SignedDuration, Zoned, civil::Date};
+use jiff::{SignedDuration, Span, Zoned, civil::Date};
+
+/// How many days [`TwilightFallback::LastDayReached`] searches back for the
+/// last day the depression was reached
+const LAST_DAY_REACHED_SEARCH_DAYS: i32 = 366;
 
 /// Struct to store a 4-dimensional location and settings, to simplify getting
 /// many *zmanim* for the same location. Has premade methods for many common
@@ -17,22 +26,34 @@ use jiff::{SignedDuration, Zoned, civil::Date};
 /// Construct with [`ComplexZmanimCalendar::new`]. The underlying solar events
 /// are computed lazily and cached per instance, so calculating many *zmanim*
 /// for the same date and location is cheap. The cache makes this type
-/// `!Sync`; to share a calendar across threads, clone it.
+/// `!Sync`; to share a calendar across threads, clone it, or enable the `sync`
+/// feature, which makes the cache (and so the calendar) `Sync` at the cost of
+/// some locking, so that many threads can read from one calendar in an
+/// [`Arc`](std::sync::Arc).
 #[derive(Debug, Clone)]
 pub struct ComplexZmanimCalendar {
     geo_location: GeoLocation,
     date: Date,
     use_elevation: UseElevation,
+    twilight_fallback: TwilightFallback,
+    use_astronomical_chatzos_for_other_zmanim: bool,
+    // Degrees added to the zenith of sunrise and sunset, for the perturbed
+    // calendars of uncertainty intervals
+    sunrise_zenith_offset: f64,
     cache: ZmanCache,
 }
 
 /// Cached solar events are ignored; two calendars are equal when their
-/// location, date, and elevation setting are equal.
+/// location, date, and settings are equal.
 impl PartialEq for ComplexZmanimCalendar {
     fn eq(&self, other: &Self) -> bool {
         self.geo_location == other.geo_location
             && self.date == other.date
             && self.use_elevation == other.use_elevation
+            && self.twilight_fallback == other.twilight_fallback
+            && self.use_astronomical_chatzos_for_other_zmanim
+                == other.use_astronomical_chatzos_for_other_zmanim
+            && self.sunrise_zenith_offset == other.sunrise_zenith_offset
     }
 }
 
@@ -49,13 +70,16 @@ impl PartialEq for ComplexZmanimCalendar {
 /// regions).
 impl ComplexZmanimCalendar {
     /// Returns a new `ComplexZmanimCalendar` for the given location, day, and
-    /// elevation setting
+    /// elevation setting, with no [`TwilightFallback`]
     #[must_use]
     pub fn new(geo_location: GeoLocation, date: Date, use_elevation: UseElevation) -> Self {
         Self {
             geo_location,
             date,
             use_elevation,
+            twilight_fallback: TwilightFallback::Disabled,
+            use_astronomical_chatzos_for_other_zmanim: false,
+            sunrise_zenith_offset: 0.0,
             cache: ZmanCache::default(),
         }
     }
@@ -85,6 +109,45 @@ impl ComplexZmanimCalendar {
         self.cache = ZmanCache::default();
     }
 
+    /// Moves the calendar to the following day. Unlike
+    /// [`set_date`](ComplexZmanimCalendar::set_date), the solar events already
+    /// calculated for that day by the *zmanim* of the night (which end on it,
+    /// such as [`shaah_zmanis_halayla`](ComplexZmanimCalendar::shaah_zmanis_halayla))
+    /// are kept. Only those events are reused: each day's events are calculated
+    /// independently, so if no night *zmanim* were calculated this is the same
+    /// as `set_date` for the next day, except that the last day reached found
+    /// by [`TwilightFallback::LastDayReached`] is carried on rather than
+    /// searched for again. Does nothing on the last day [`Date`] can represent.
+    pub fn advance_day(&mut self) {
+        let alos_last_days = self.cache.alos_last_day_reached.entries();
+        let tzeis_last_days = self.cache.tzeis_last_day_reached.entries();
+        match self.cache.next_day.take().flatten() {
+            Some(next_day) => *self = *next_day,
+            None => {
+                if let Ok(tomorrow) = self.date.tomorrow() {
+                    self.set_date(tomorrow);
+                }
+            }
+        }
+        // a depression that needed the last day it was reached was not reached
+        // on the previous day, so that is still the last day it was reached, as
+        // long as it is not too long ago to search back to
+        let oldest = self
+            .date
+            .saturating_sub(Span::new().days(LAST_DAY_REACHED_SEARCH_DAYS));
+        let in_range = |last_day: LastDayReached| last_day.filter(|(date, _)| *date >= oldest);
+        for (degrees, last_day) in alos_last_days {
+            self.cache
+                .alos_last_day_reached
+                .insert(degrees, in_range(last_day));
+        }
+        for (degrees, last_day) in tzeis_last_days {
+            self.cache
+                .tzeis_last_day_reached
+                .insert(degrees, in_range(last_day));
+        }
+    }
+
     /// Sets the location at which to calculate *zmanim*, clearing the cached
     /// solar events
     pub fn set_geo_location(&mut self, geo_location: GeoLocation) {
@@ -96,6 +159,62 @@ impl ComplexZmanimCalendar {
     /// they are stored per physical event, not per elevation setting
     pub fn set_use_elevation(&mut self, use_elevation: UseElevation) {
         self.use_elevation = use_elevation;
+        // the twilight on the last day reached is measured from sunrise or
+        // sunset, which can depend on elevation
+        self.cache.alos_last_day_reached = DegreeCache::default();
+        self.cache.tzeis_last_day_reached = DegreeCache::default();
+        if let Some(next_day) = self.cached_next_day_mut() {
+            next_day.set_use_elevation(use_elevation);
+        }
+    }
+
+    /// How degree-based *alos* and *tzeis* are calculated when the sun does
+    /// not get that far below the horizon. See [`TwilightFallback`]
+    #[must_use]
+    pub fn twilight_fallback(&self) -> TwilightFallback {
+        self.twilight_fallback
+    }
+
+    /// Sets how degree-based *alos* and *tzeis*, and the *zmanim* whose day is
+    /// measured from them, are calculated when the sun does not get that far
+    /// below the horizon. Cached solar events are kept, as the fallback is
+    /// applied on top of them
+    pub fn set_twilight_fallback(&mut self, twilight_fallback: TwilightFallback) {
+        self.twilight_fallback = twilight_fallback;
+        if let Some(next_day) = self.cached_next_day_mut() {
+            next_day.set_twilight_fallback(twilight_fallback);
+        }
+    }
+
+    /// Whether the *zmanim* of the GRA, MGA, and *Baal Hatanya* are calculated
+    /// from the two halves of the day split at astronomical *chatzos*, rather
+    /// than from the whole day. See
+    /// [`set_use_astronomical_chatzos_for_other_zmanim`](ComplexZmanimCalendar::set_use_astronomical_chatzos_for_other_zmanim)
+    #[must_use]
+    pub fn use_astronomical_chatzos_for_other_zmanim(&self) -> bool {
+        self.use_astronomical_chatzos_for_other_zmanim
+    }
+
+    /// Sets whether the *zmanim* of the GRA, MGA, and *Baal Hatanya* are
+    /// calculated from the two halves of the day split at [astronomical
+    /// *chatzos*](ComplexZmanimCalendar::chatzos_hayom). Defaults to `false`.
+    ///
+    /// When set, morning *zmanim* (such as *sof zman krias shema*) are
+    /// calculated with a [half-day based
+    /// *zman*](zmanim_calculator::half_day_based_zman) from the start of the
+    /// day to *chatzos*, and afternoon *zmanim* (such as *mincha gedola* and
+    /// *plag hamincha*) from *chatzos* to the end of the day, so that *chatzos*
+    /// is always exactly 6 *shaos zmaniyos* into the day. It applies only to
+    /// opinions whose day is symmetric, starting and ending the same offset
+    /// from sunrise and sunset (as in the methods that take a [`ZmanOffset`],
+    /// and a [`DayDefinition`] from sunrise to sunset at the same offset);
+    /// other days, such as *alos* 16.1&deg; to sunset or the *Ateret Torah*'s
+    /// day, are unaffected. Cached solar events are kept.
+    pub fn set_use_astronomical_chatzos_for_other_zmanim(&mut self, value: bool) {
+        self.use_astronomical_chatzos_for_other_zmanim = value;
+        if let Some(next_day) = self.cached_next_day_mut() {
+            next_day.set_use_astronomical_chatzos_for_other_zmanim(value);
+        }
     }
 
     // Cached solar events. All zmanim methods reach the astronomical layer
@@ -107,8 +226,24 @@ impl ComplexZmanimCalendar {
         } else {
             &self.cache.sea_level_sunrise
         };
-        cell.get_or_init(|| zmanim_calculator::hanetz(self.date, &self.geo_location, use_elevation))
-            .clone()
+        cell.get_or_init(|| {
+            if self.sunrise_zenith_offset == 0.0 {
+                astronomical_calculator::sunrise_on(
+                    self.cached_solar_day()?,
+                    &self.geo_location,
+                    astronomical_calculator::GEOMETRIC_ZENITH,
+                    use_elevation,
+                )
+            } else {
+                astronomical_calculator::sunrise_on(
+                    self.cached_solar_day()?,
+                    &self.geo_location,
+                    self.offset_sunrise_zenith(use_elevation),
+                    false,
+                )
+            }
+        })
+        .clone()
     }
 
     fn cached_sunset(&self, use_elevation: bool) -> Option<Zoned> {
@@ -117,8 +252,49 @@ impl ComplexZmanimCalendar {
         } else {
             &self.cache.sea_level_sunset
         };
-        cell.get_or_init(|| zmanim_calculator::shkia(self.date, &self.geo_location, use_elevation))
-            .clone()
+        cell.get_or_init(|| {
+            if self.sunrise_zenith_offset == 0.0 {
+                astronomical_calculator::sunset_on(
+                    self.cached_solar_day()?,
+                    &self.geo_location,
+                    astronomical_calculator::GEOMETRIC_ZENITH,
+                    use_elevation,
+                )
+            } else {
+                astronomical_calculator::sunset_on(
+                    self.cached_solar_day()?,
+                    &self.geo_location,
+                    self.offset_sunrise_zenith(use_elevation),
+                    false,
+                )
+            }
+        })
+        .clone()
+    }
+
+    /// The zenith of sunrise and sunset, adjusted for refraction, the solar
+    /// radius, and (if `use_elevation`) elevation as usual, plus
+    /// `sunrise_zenith_offset`
+    fn offset_sunrise_zenith(&self, use_elevation: bool) -> f64 {
+        let elevation = if use_elevation {
+            self.geo_location.elevation
+        } else {
+            0.0
+        };
+        zenith_adjustments::adjusted_zenith(
+            astronomical_calculator::GEOMETRIC_ZENITH,
+            elevation,
+            self.date,
+        ) + self.sunrise_zenith_offset
+    }
+
+    /// The parts of the sunrise and sunset calculation shared by every solar
+    /// event of the day
+    fn cached_solar_day(&self) -> Option<&SolarDay> {
+        self.cache
+            .solar_day
+            .get_or_init(|| SolarDay::new(self.date, &self.geo_location))
+            .as_ref()
     }
 
     fn cached_solar_noon(&self) -> Option<Zoned> {
@@ -128,6 +304,28 @@ impl ComplexZmanimCalendar {
             .clone()
     }
 
+    /// The cached calendar for the following day with the same location and
+    /// settings, for *zmanim* of the night, which ends on the next civil date
+    fn next_day(&self) -> Option<&Self> {
+        self.cache
+            .next_day
+            .get_or_init(|| {
+                Some(Box::new(Self {
+                    geo_location: self.geo_location.clone(),
+                    date: self.date.tomorrow().ok()?,
+                    cache: ZmanCache::default(),
+                    ..*self
+                }))
+            })
+            .as_deref()
+    }
+
+    /// The cached calendar for the following day, if it was calculated, so
+    /// that the setters can keep its settings in sync
+    fn cached_next_day_mut(&mut self) -> Option<&mut Self> {
+        self.cache.next_day.get_mut()?.as_deref_mut()
+    }
+
     fn cached_solar_midnight(&self) -> Option<Zoned> {
         self.cache
             .solar_midnight
@@ -136,49 +334,150 @@ impl ComplexZmanimCalendar {
     }
 
     fn cached_sunrise_offset_by_degrees(&self, degrees: f64) -> Option<Zoned> {
-        let key = degrees.to_bits();
-        if let Some((_, cached)) = self
-            .cache
-            .sunrise_by_degrees
-            .borrow()
-            .iter()
-            .find(|(k, _)| *k == key)
-        {
-            return cached.clone();
-        }
-        let result = astronomical_calculator::sunrise_offset_by_degrees(
-            self.date,
-            &self.geo_location,
-            astronomical_calculator::GEOMETRIC_ZENITH + degrees,
-        );
         self.cache
             .sunrise_by_degrees
-            .borrow_mut()
-            .push((key, result.clone()));
-        result
+            .get_or_insert_with(degrees, || {
+                astronomical_calculator::sunrise_on(
+                    self.cached_solar_day()?,
+                    &self.geo_location,
+                    astronomical_calculator::GEOMETRIC_ZENITH + degrees,
+                    false,
+                )
+            })
     }
 
     fn cached_sunset_offset_by_degrees(&self, degrees: f64) -> Option<Zoned> {
-        let key = degrees.to_bits();
-        if let Some((_, cached)) = self
-            .cache
-            .sunset_by_degrees
-            .borrow()
-            .iter()
-            .find(|(k, _)| *k == key)
-        {
-            return cached.clone();
-        }
-        let result = astronomical_calculator::sunset_offset_by_degrees(
-            self.date,
-            &self.geo_location,
-            astronomical_calculator::GEOMETRIC_ZENITH + degrees,
-        );
         self.cache
             .sunset_by_degrees
-            .borrow_mut()
-            .push((key, result.clone()));
-        result
+            .get_or_insert_with(degrees, || {
+                astronomical_calculator::sunset_on(
+                    self.cached_solar_day()?,
+                    &self.geo_location,
+                    astronomical_calculator::GEOMETRIC_ZENITH + degrees,
+                    false,
+                )
+            })
+    }
+
+    /// Returns the [`TwilightFallback`] for *alos* (or *tzeis* if `evening`)
+    /// `degrees` below the horizon, or `None` if the fallback is disabled or
+    /// the sun is missing the depression for any other reason than staying
+    /// too high all night.
+    fn twilight_fallback_time(&self, degrees: f64, evening: bool) -> Option<Zoned> {
+        if self.twilight_fallback == TwilightFallback::Disabled {
+            return None;
+        }
+        let offset = Degrees(degrees);
+        let error = if evening {
+            zmanim_calculator::missing_sunset_error(
+                self.date,
+                &self.geo_location,
+                false,
+                Some(&offset),
+            )
+        } else {
+            zmanim_calculator::missing_sunrise_error(
+                self.date,
+                &self.geo_location,
+                false,
+                Some(&offset),
+            )
+        };
+        if error != ZmanError::DepressionNotReached(degrees) {
+            return None;
+        }
+
+        let use_elevation = self.use_elevation.to_bool(false);
+        let sunrise_on =
+            |date: Date| zmanim_calculator::hanetz(date, &self.geo_location, use_elevation);
+        let sunset_on =
+            |date: Date| zmanim_calculator::shkia(date, &self.geo_location, use_elevation);
+        // the clock time from sunrise back to (or sunset forward to) the
+        // depression on another date
+        let twilight_on = |date: Date| -> Option<SignedDuration> {
+            Some(if evening {
+                astronomical_calculator::sunset_offset_by_degrees(
+                    date,
+                    &self.geo_location,
+                    astronomical_calculator::GEOMETRIC_ZENITH + degrees,
+                )?
+                .duration_since(&sunset_on(date)?)
+            } else {
+                sunrise_on(date)?.duration_since(
+                    &astronomical_calculator::sunrise_offset_by_degrees(
+                        date,
+                        &self.geo_location,
+                        astronomical_calculator::GEOMETRIC_ZENITH + degrees,
+                    )?,
+                )
+            })
+        };
+        let from_event = |twilight: SignedDuration| {
+            if evening {
+                Some(&self.cached_sunset(use_elevation)? + twilight)
+            } else {
+                Some(&self.cached_sunrise(use_elevation)? - twilight)
+            }
+        };
+
+        match self.twilight_fallback {
+            TwilightFallback::Disabled => None,
+            TwilightFallback::SolarMidnight => {
+                if evening {
+                    self.cached_solar_midnight()
+                } else {
+                    zmanim_calculator::chatzos_halayla(
+                        self.date.yesterday().ok()?,
+                        &self.geo_location,
+                    )
+                }
+            }
+            TwilightFallback::LastDayReached => {
+                let cache = if evening {
+                    &self.cache.tzeis_last_day_reached
+                } else {
+                    &self.cache.alos_last_day_reached
+                };
+                let (_, twilight) = cache.get_or_insert_with(degrees, || {
+                    let mut date = self.date;
+                    for _ in 0..LAST_DAY_REACHED_SEARCH_DAYS {
+                        date = date.yesterday().ok()?;
+                        if let Some(twilight) = twilight_on(date) {
+                            return Some((date, twilight));
+                        }
+                    }
+                    None
+                })?;
+                from_event(twilight)
+            }
+            TwilightFallback::SeventhOfNight => {
+                let night = if evening {
+                    sunrise_on(self.date.tomorrow().ok()?)?
+                        .duration_since(&self.cached_sunset(use_elevation)?)
+                } else {
+                    self.cached_sunrise(use_elevation)?
+                        .duration_since(&sunset_on(self.date.yesterday().ok()?)?)
+                };
+                from_event(night / 7)
+            }
+            TwilightFallback::EquinoxMinutes => from_event(twilight_on(self.march_equinox()?)?),
+        }
+    }
+
+    /// Returns the local date of this year's March equinox, the day during
+    /// which the sun's declination turns from south to north
+    fn march_equinox(&self) -> Option<Date> {
+        let timezone = self.geo_location.timezone();
+        let mut date = jiff::civil::date(self.date.year(), 3, 15);
+        // the equinox always falls between March 18 and 22
+        for _ in 0..14 {
+            let end_of_day = date.tomorrow().ok()?.to_zoned(timezone.clone()).ok()?;
+            if astronomical_calculator::solar_ephemeris(&end_of_day).declination >= 0.0 {
+                return Some(date);
+            }
+            date = date.tomorrow().ok()?;
+        }
+        None
     }
 
     // Basics
@@ -188,7 +487,9 @@ impl ComplexZmanimCalendar {
     #[must_use]
     pub fn alos(&self, offset: &ZmanOffset) -> Option<Zoned> {
         match offset {
-            Degrees(deg) => self.cached_sunrise_offset_by_degrees(*deg),
+            Degrees(deg) => self
+                .cached_sunrise_offset_by_degrees(*deg)
+                .or_else(|| self.twilight_fallback_time(*deg, false)),
             _ => Some(zmanim_calculator::offset_before_event(
                 &self.cached_sunrise(self.use_elevation.to_bool(false))?,
                 offset,
@@ -196,6 +497,24 @@ impl ComplexZmanimCalendar {
         }
     }
 
+    /// Returns [*alos*](ComplexZmanimCalendar::alos), or a [`ZmanError`]
+    /// explaining why there is none (for example, that the sun never gets
+    /// 18&deg; below the horizon on a summer night in northern Europe).
+    ///
+    /// # Errors
+    ///
+    /// See [`zmanim_calculator::try_alos`].
+    pub fn try_alos(&self, offset: &ZmanOffset) -> Result<Zoned, ZmanError> {
+        self.alos(offset).ok_or_else(|| {
+            zmanim_calculator::missing_sunrise_error(
+                self.date,
+                &self.geo_location,
+                self.use_elevation.to_bool(false),
+                Some(offset),
+            )
+        })
+    }
+
     /// Returns sea level sunrise
     #[must_use]
     pub fn sea_level_sunrise(&self) -> Option<Zoned> {
@@ -234,6 +553,52 @@ impl ComplexZmanimCalendar {
         self.cached_sunset(self.use_elevation.to_bool(true))
     }
 
+    /// Returns candle lighting time, `minutes` before sea level sunset. This
+    /// is commonly 18 minutes, though some communities use 20, 30, or 40
+    /// (notably Jerusalem). Elevation is not used, as the actual time of sunset
+    /// is not well defined with elevation
+    #[must_use]
+    pub fn candle_lighting(&self, minutes: f64) -> Option<Zoned> {
+        Some(zmanim_calculator::offset_before_event(
+            &self.sea_level_sunset()?,
+            &Minutes(minutes),
+        ))
+    }
+
+    /// Returns [*hanetz*](ComplexZmanimCalendar::hanetz), or a [`ZmanError`]
+    /// explaining why there is none.
+    ///
+    /// # Errors
+    ///
+    /// See [`zmanim_calculator::try_hanetz`].
+    pub fn try_hanetz(&self) -> Result<Zoned, ZmanError> {
+        self.hanetz().ok_or_else(|| {
+            zmanim_calculator::missing_sunrise_error(
+                self.date,
+                &self.geo_location,
+                self.use_elevation.to_bool(true),
+                None,
+            )
+        })
+    }
+
+    /// Returns [*shkia*](ComplexZmanimCalendar::shkia), or a [`ZmanError`]
+    /// explaining why there is none.
+    ///
+    /// # Errors
+    ///
+    /// See [`zmanim_calculator::try_shkia`].
+    pub fn try_shkia(&self) -> Result<Zoned, ZmanError> {
+        self.shkia().ok_or_else(|| {
+            zmanim_calculator::missing_sunset_error(
+                self.date,
+                &self.geo_location,
+                self.use_elevation.to_bool(true),
+                None,
+            )
+        })
+    }
+
     /// Returns sunrise, for use internally. Will be elevation-adjusted only if
     /// `use_elevation == All`
     #[must_use]
@@ -248,16 +613,44 @@ impl ComplexZmanimCalendar {
         self.cached_sunset(self.use_elevation.to_bool(false))
     }
 
+    /// Returns the *zman* `hours` *shaos zmaniyos* into the day from
+    /// `day_start` to `day_end`, for use internally by the *zmanim* of opinions
+    /// whose day is symmetric around *chatzos*. This is `zman(day_start,
+    /// day_end)`, unless
+    /// [`use_astronomical_chatzos_for_other_zmanim`](ComplexZmanimCalendar::use_astronomical_chatzos_for_other_zmanim)
+    /// is set, in which case it is a [half-day based
+    /// *zman*](zmanim_calculator::half_day_based_zman) in the morning or
+    /// afternoon half of the day, split at astronomical *chatzos*
+    fn day_based_zman(
+        &self,
+        day_start: &Zoned,
+        day_end: &Zoned,
+        hours: f64,
+        zman: impl FnOnce(&Zoned, &Zoned) -> Zoned,
+    ) -> Option<Zoned> {
+        if !self.use_astronomical_chatzos_for_other_zmanim {
+            return Some(zman(day_start, day_end));
+        }
+        let chatzos = self.cached_solar_noon()?;
+        Some(if hours <= 6.0 {
+            zmanim_calculator::half_day_based_zman(day_start, &chatzos, hours)
+        } else {
+            zmanim_calculator::half_day_based_zman(&chatzos, day_end, hours - 6.0)
+        })
+    }
+
     /// Returns the latest *zman krias shema* (time to recite *Shema* in the
     /// morning) according to the opinion of the *Magen Avraham* (MGA) based on
     /// *alos* and *tzeis* being given offset from sunrise and sunset,
     /// respectively.
     #[must_use]
     pub fn sof_zman_shema_mga(&self, offset: &ZmanOffset) -> Option<Zoned> {
-        Some(zmanim_calculator::sof_zman_shema(
+        self.day_based_zman(
             &self.alos(offset)?,
             &self.tzeis(offset)?,
-        ))
+            3.0,
+            zmanim_calculator::sof_zman_shema,
+        )
     }
 
     /// Returns the latest *zman tefila* (time to recite *shacharis* in the
@@ -266,10 +659,12 @@ impl ComplexZmanimCalendar {
     /// respectively.
     #[must_use]
     pub fn sof_zman_tefila_mga(&self, offset: &ZmanOffset) -> Option<Zoned> {
-        Some(zmanim_calculator::sof_zman_tefila(
+        self.day_based_zman(
             &self.alos(offset)?,
             &self.tzeis(offset)?,
-        ))
+            4.0,
+            zmanim_calculator::sof_zman_tefila,
+        )
     }
 
     /// Returns the latest *zman biur chametz* (the latest time for burning
@@ -278,10 +673,12 @@ impl ComplexZmanimCalendar {
     /// from sunrise and sunset, respectively.
     #[must_use]
     pub fn sof_zman_biur_chametz_mga(&self, offset: &ZmanOffset) -> Option<Zoned> {
-        Some(zmanim_calculator::sof_zman_biur_chametz(
+        self.day_based_zman(
             &self.alos(offset)?,
             &self.tzeis(offset)?,
-        ))
+            5.0,
+            zmanim_calculator::sof_zman_biur_chametz,
+        )
     }
 
     /// Returns Astronomical *chatzos* (noon)
@@ -322,14 +719,141 @@ impl ComplexZmanimCalendar {
         self.cached_solar_midnight()
     }
 
+    /// Returns *chatzos halayla* **at the end of the day** calculated as
+    /// halfway between sunset and the next day's sunrise, as opposed to
+    /// [astronomical midnight](ComplexZmanimCalendar::chatzos_halayla).
+    ///
+    /// The night is the same one that [`ashmuros`](ComplexZmanimCalendar::ashmuros)
+    /// and [`shaah_zmanis_halayla`](ComplexZmanimCalendar::shaah_zmanis_halayla)
+    /// divide: like the GRA's day, its sunset and sunrise are elevation
+    /// adjusted only with [`UseElevation::All`], so this is the middle of
+    /// `ashmuros(2)` and 6 nighttime *shaos zmaniyos* after sunset. Unlike
+    /// [`chatzos_hayom_as_half_day`](ComplexZmanimCalendar::chatzos_hayom_as_half_day),
+    /// it is not always at sea level
+    #[must_use]
+    pub fn chatzos_halayla_as_half_night(&self) -> Option<Zoned> {
+        Some(zmanim_calculator::chatzos_halayla_as_half_night(
+            &self.zmanim_sunset()?,
+            &self.next_day()?.zmanim_sunrise()?,
+        ))
+    }
+
+    /// Returns *chatzos halayla* **at the end of the day** calculated as
+    /// halfway between [*tzeis*](ComplexZmanimCalendar::tzeis) and the next
+    /// day's [*alos*](ComplexZmanimCalendar::alos), both using the given
+    /// offset
+    #[must_use]
+    pub fn chatzos_halayla_tzeis_to_alos(&self, offset: &ZmanOffset) -> Option<Zoned> {
+        Some(zmanim_calculator::chatzos_halayla_as_half_night(
+            &self.tzeis(offset)?,
+            &self.next_day()?.alos(offset)?,
+        ))
+    }
+
+    /// Returns the beginning of each of the `ashmuros` (watches) of the night
+    /// **at the end of the day**, from sunset to the next day's sunrise
+    /// (elevation adjusted only with [`UseElevation::All`]), followed by the
+    /// end of the night. See [`zmanim_calculator::ashmuros`]
+    #[must_use]
+    pub fn ashmuros(&self, ashmuros: u32) -> Option<Vec<Zoned>> {
+        Some(zmanim_calculator::ashmuros(
+            &self.zmanim_sunset()?,
+            &self.next_day()?.zmanim_sunrise()?,
+            ashmuros,
+        ))
+    }
+
+    /// Returns the beginning of each of the `ashmuros` (watches) of the night
+    /// **at the end of the day**, from [*tzeis*](ComplexZmanimCalendar::tzeis)
+    /// to the next day's [*alos*](ComplexZmanimCalendar::alos) using the given
+    /// offset, followed by the end of the night. See
+    /// [`zmanim_calculator::ashmuros`]
+    #[must_use]
+    pub fn ashmuros_tzeis_to_alos(&self, offset: &ZmanOffset, ashmuros: u32) -> Option<Vec<Zoned>> {
+        Some(zmanim_calculator::ashmuros(
+            &self.tzeis(offset)?,
+            &self.next_day()?.alos(offset)?,
+            ashmuros,
+        ))
+    }
+
+    /// Returns a nighttime *shaah zmanis* (temporal hour) **at the end of the
+    /// day**. The night from sunset to the next day's sunrise (elevation
+    /// adjusted only with [`UseElevation::All`]) is split into 12 equal parts
+    /// with each one being a *shaah zmanis*. See
+    /// [`zmanim_calculator::shaah_zmanis_halayla`]
+    #[must_use]
+    pub fn shaah_zmanis_halayla(&self) -> Option<SignedDuration> {
+        Some(zmanim_calculator::shaah_zmanis_halayla(
+            &self.zmanim_sunset()?,
+            &self.next_day()?.zmanim_sunrise()?,
+        ))
+    }
+
+    /// Returns a nighttime *shaah zmanis* (temporal hour) **at the end of the
+    /// day**, based on a night from [*tzeis*](ComplexZmanimCalendar::tzeis) to
+    /// the next day's [*alos*](ComplexZmanimCalendar::alos) using the given
+    /// offset
+    #[must_use]
+    pub fn shaah_zmanis_halayla_tzeis_to_alos(
+        &self,
+        offset: &ZmanOffset,
+    ) -> Option<SignedDuration> {
+        Some(zmanim_calculator::shaah_zmanis_halayla(
+            &self.tzeis(offset)?,
+            &self.next_day()?.alos(offset)?,
+        ))
+    }
+
+    /// Returns the time `shaos` [nighttime *shaos
+    /// zmaniyos*](ComplexZmanimCalendar::shaah_zmanis_halayla) after sunset,
+    /// in the night **at the end of the day**. See
+    /// [`zmanim_calculator::shaos_into_night`]
+    #[must_use]
+    pub fn shaos_into_night(&self, shaos: f64) -> Option<Zoned> {
+        Some(zmanim_calculator::shaos_into_night(
+            &self.zmanim_sunset()?,
+            &self.next_day()?.zmanim_sunrise()?,
+            shaos,
+        ))
+    }
+
+    /// Returns the time `shaos` nighttime *shaos zmaniyos* after
+    /// [*tzeis*](ComplexZmanimCalendar::tzeis), in the night from *tzeis* to
+    /// the next day's [*alos*](ComplexZmanimCalendar::alos) using the given
+    /// offset **at the end of the day**
+    #[must_use]
+    pub fn shaos_into_night_tzeis_to_alos(&self, offset: &ZmanOffset, shaos: f64) -> Option<Zoned> {
+        Some(zmanim_calculator::shaos_into_night(
+            &self.tzeis(offset)?,
+            &self.next_day()?.alos(offset)?,
+            shaos,
+        ))
+    }
+
     /// Returns *mincha gedola* according to the opinion of the *Magen Avraham*
     /// (MGA) based on *alos* and *tzeis* being the given offset from sunrise
     /// and sunset, respectively.
     #[must_use]
     pub fn mincha_gedola_mga(&self, offset: &ZmanOffset) -> Option<Zoned> {
-        Some(zmanim_calculator::mincha_gedola(
+        self.day_based_zman(
             &self.alos(offset)?,
             &self.tzeis(offset)?,
+            6.5,
+            zmanim_calculator::mincha_gedola,
+        )
+    }
+
+    /// Returns the later of [*mincha gedola* according to the
+    /// MGA](ComplexZmanimCalendar::mincha_gedola_mga) (based on *alos* and
+    /// *tzeis* being the given offset from sunrise and sunset, respectively)
+    /// and [`mincha_gedola_30_minutes`](ComplexZmanimCalendar::mincha_gedola_30_minutes).
+    /// See [`zmanim_calculator::mincha_gedola_greater_than_30_minutes`]
+    #[must_use]
+    pub fn mincha_gedola_mga_greater_than_30_minutes(&self, offset: &ZmanOffset) -> Option<Zoned> {
+        Some(zmanim_calculator::mincha_gedola_greater_than_30_minutes(
+            &self.mincha_gedola_mga(offset)?,
+            &self.cached_solar_noon()?,
         ))
     }
 
@@ -338,10 +862,12 @@ impl ComplexZmanimCalendar {
     /// from sunrise and sunset, respectively.
     #[must_use]
     pub fn samuch_lemincha_ketana_mga(&self, offset: &ZmanOffset) -> Option<Zoned> {
-        Some(zmanim_calculator::samuch_lemincha_ketana(
+        self.day_based_zman(
             &self.alos(offset)?,
             &self.tzeis(offset)?,
-        ))
+            9.0,
+            zmanim_calculator::samuch_lemincha_ketana,
+        )
     }
 
     /// Returns *mincha ketana* according to the opinion of the *Magen Avraham*
@@ -349,10 +875,12 @@ impl ComplexZmanimCalendar {
     /// and sunset, respectively.
     #[must_use]
     pub fn mincha_ketana_mga(&self, offset: &ZmanOffset) -> Option<Zoned> {
-        Some(zmanim_calculator::mincha_ketana(
+        self.day_based_zman(
             &self.alos(offset)?,
             &self.tzeis(offset)?,
-        ))
+            9.5,
+            zmanim_calculator::mincha_ketana,
+        )
     }
 
     /// Returns *plag hamincha* according to the opinion of the *Magen Avraham*
@@ -360,10 +888,12 @@ impl ComplexZmanimCalendar {
     /// and sunset, respectively.
     #[must_use]
     pub fn plag_mga(&self, offset: &ZmanOffset) -> Option<Zoned> {
-        Some(zmanim_calculator::plag_hamincha(
+        self.day_based_zman(
             &self.alos(offset)?,
             &self.tzeis(offset)?,
-        ))
+            10.75,
+            zmanim_calculator::plag_hamincha,
+        )
     }
 
     /// Returns *mincha gedola* calculated as 30 minutes after *chatzos* and not
@@ -381,7 +911,9 @@ impl ComplexZmanimCalendar {
     #[must_use]
     pub fn tzeis(&self, offset: &ZmanOffset) -> Option<Zoned> {
         match offset {
-            Degrees(deg) => self.cached_sunset_offset_by_degrees(*deg),
+            Degrees(deg) => self
+                .cached_sunset_offset_by_degrees(*deg)
+                .or_else(|| self.twilight_fallback_time(*deg, true)),
             _ => Some(zmanim_calculator::offset_after_event(
                 &self.cached_sunset(self.use_elevation.to_bool(false))?,
                 offset,
@@ -389,6 +921,23 @@ impl ComplexZmanimCalendar {
         }
     }
 
+    /// Returns [*tzeis*](ComplexZmanimCalendar::tzeis), or a [`ZmanError`]
+    /// explaining why there is none.
+    ///
+    /// # Errors
+    ///
+    /// See [`zmanim_calculator::try_tzeis`].
+    pub fn try_tzeis(&self, offset: &ZmanOffset) -> Result<Zoned, ZmanError> {
+        self.tzeis(offset).ok_or_else(|| {
+            zmanim_calculator::missing_sunset_error(
+                self.date,
+                &self.geo_location,
+                self.use_elevation.to_bool(false),
+                Some(offset),
+            )
+        })
+    }
+
     /// Returns *shaah zmanis* (temporal hour) according to the opinion of the
     /// *Magen Avraham* (MGA) based on *alos* and *tzeis* being the given
     /// offset from sunrise and sunset, respectively.
@@ -400,6 +949,126 @@ impl ComplexZmanimCalendar {
         ))
     }
 
+    // Day definitions
+    /// Returns the time of a [`DayEvent`]: the start or end of a
+    /// [`DayDefinition`]
+    #[must_use]
+    pub fn day_event(&self, event: &DayEvent) -> Option<Zoned> {
+        match (event.anchor, &event.offset) {
+            (DayAnchor::Sunrise, offset) => self.alos(offset),
+            (DayAnchor::Sunset, offset) => self.tzeis(offset),
+            (_, Degrees(_)) => None,
+            (DayAnchor::Noon, offset) => Some(zmanim_calculator::offset_after_event(
+                &self.cached_solar_noon()?,
+                offset,
+            )),
+            (DayAnchor::Azimuth(azimuth), offset) => Some(zmanim_calculator::offset_after_event(
+                &self.times_at_azimuth(azimuth).first()?.time,
+                offset,
+            )),
+        }
+    }
+
+    /// Returns a *shaah zmanis* (temporal hour) of `day`, which is split into
+    /// 12 equal parts with each one being a *shaah zmanis*
+    #[must_use]
+    pub fn shaah_zmanis(&self, day: &DayDefinition) -> Option<SignedDuration> {
+        Some(zmanim_calculator::shaah_zmanis(
+            &self.day_event(&day.start)?,
+            &self.day_event(&day.end)?,
+        ))
+    }
+
+    /// Returns the time `shaos` [*shaos zmaniyos*](ComplexZmanimCalendar::shaah_zmanis)
+    /// after the start of `day`. See [`zmanim_calculator::shaos_into_day`]
+    #[must_use]
+    pub fn shaos_into_day(&self, day: &DayDefinition, shaos: f64) -> Option<Zoned> {
+        self.zman_for_day(day, shaos, |start, end| {
+            zmanim_calculator::shaos_into_day(start, end, shaos)
+        })
+    }
+
+    /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+    /// morning), 3 *shaos zmaniyos* after the start of `day`
+    #[must_use]
+    pub fn sof_zman_shema(&self, day: &DayDefinition) -> Option<Zoned> {
+        self.zman_for_day(day, 3.0, zmanim_calculator::sof_zman_shema)
+    }
+
+    /// Returns the latest *zman tefila* (time to recite *shacharis* in the
+    /// morning), 4 *shaos zmaniyos* after the start of `day`
+    #[must_use]
+    pub fn sof_zman_tefila(&self, day: &DayDefinition) -> Option<Zoned> {
+        self.zman_for_day(day, 4.0, zmanim_calculator::sof_zman_tefila)
+    }
+
+    /// Returns the latest time for burning *chametz* on *Erev Pesach*, 5
+    /// *shaos zmaniyos* after the start of `day`
+    #[must_use]
+    pub fn sof_zman_biur_chametz(&self, day: &DayDefinition) -> Option<Zoned> {
+        self.zman_for_day(day, 5.0, zmanim_calculator::sof_zman_biur_chametz)
+    }
+
+    /// Returns *chatzos* (midday) calculated as halfway through `day`
+    #[must_use]
+    pub fn chatzos_as_half_day(&self, day: &DayDefinition) -> Option<Zoned> {
+        Some(zmanim_calculator::chatzos_hayom_as_half_day(
+            &self.day_event(&day.start)?,
+            &self.day_event(&day.end)?,
+        ))
+    }
+
+    /// Returns *mincha gedola*, 6.5 *shaos zmaniyos* after the start of `day`
+    #[must_use]
+    pub fn mincha_gedola(&self, day: &DayDefinition) -> Option<Zoned> {
+        self.zman_for_day(day, 6.5, zmanim_calculator::mincha_gedola)
+    }
+
+    /// Returns *samuch lemincha ketana*, 9 *shaos zmaniyos* after the start of
+    /// `day`
+    #[must_use]
+    pub fn samuch_lemincha_ketana(&self, day: &DayDefinition) -> Option<Zoned> {
+        self.zman_for_day(day, 9.0, zmanim_calculator::samuch_lemincha_ketana)
+    }
+
+    /// Returns *mincha ketana*, 9.5 *shaos zmaniyos* after the start of `day`
+    #[must_use]
+    pub fn mincha_ketana(&self, day: &DayDefinition) -> Option<Zoned> {
+        self.zman_for_day(day, 9.5, zmanim_calculator::mincha_ketana)
+    }
+
+    /// Returns *plag hamincha*, 10.75 *shaos zmaniyos* after the start of
+    /// `day`
+    #[must_use]
+    pub fn plag(&self, day: &DayDefinition) -> Option<Zoned> {
+        self.zman_for_day(day, 10.75, zmanim_calculator::plag_hamincha)
+    }
+
+    /// Returns the *zman* `hours` *shaos zmaniyos* into `day`, for use
+    /// internally by the [`DayDefinition`] *zmanim*. A day from sunrise to
+    /// sunset with the same offset on both ends is symmetric around *chatzos*
+    /// like the GRA and MGA days, so it is a [day based
+    /// *zman*](Self::day_based_zman) that follows
+    /// [`use_astronomical_chatzos_for_other_zmanim`](ComplexZmanimCalendar::use_astronomical_chatzos_for_other_zmanim);
+    /// any other day is always `zman(start, end)`
+    fn zman_for_day(
+        &self,
+        day: &DayDefinition,
+        hours: f64,
+        zman: impl FnOnce(&Zoned, &Zoned) -> Zoned,
+    ) -> Option<Zoned> {
+        let start = self.day_event(&day.start)?;
+        let end = self.day_event(&day.end)?;
+        let symmetric = day.start.anchor == DayAnchor::Sunrise
+            && day.end.anchor == DayAnchor::Sunset
+            && day.start.offset == day.end.offset;
+        if symmetric {
+            self.day_based_zman(&start, &end, hours, zman)
+        } else {
+            Some(zman(&start, &end))
+        }
+    }
+
     // GRA
     /// Returns the latest *Zman Shema* (time to recite *Shema* in the morning)
     /// that is 3 *shaos zmaniyos* (solar hours) after
@@ -407,10 +1076,12 @@ impl ComplexZmanimCalendar {
     /// calculated from sunrise to sunset.
     #[must_use]
     pub fn sof_zman_shema_gra(&self) -> Option<Zoned> {
-        Some(zmanim_calculator::sof_zman_shema(
+        self.day_based_zman(
             &self.zmanim_sunrise()?,
             &self.zmanim_sunset()?,
-        ))
+            3.0,
+            zmanim_calculator::sof_zman_shema,
+        )
     }
 
     /// Returns the latest *zman tefila* (time to recite *shacharis* in the
@@ -419,10 +1090,12 @@ impl ComplexZmanimCalendar {
     /// The day is calculated from sunrise to sunset
     #[must_use]
     pub fn sof_zman_tefila_gra(&self) -> Option<Zoned> {
-        Some(zmanim_calculator::sof_zman_tefila(
+        self.day_based_zman(
             &self.zmanim_sunrise()?,
             &self.zmanim_sunset()?,
-        ))
+            4.0,
+            zmanim_calculator::sof_zman_tefila,
+        )
     }
 
     /// Returns the latest time for burning *chametz* on *Erev
@@ -432,24 +1105,31 @@ impl ComplexZmanimCalendar {
     /// this method will return the *zman* any day of the year.
     #[must_use]
     pub fn sof_zman_biur_chametz_gra(&self) -> Option<Zoned> {
-        Some(self.zmanim_sunrise()? + (self.shaah_zmanis_gra()? * 5))
+        self.day_based_zman(
+            &self.zmanim_sunrise()?,
+            &self.zmanim_sunset()?,
+            5.0,
+            |sunrise, sunset| sunrise + (zmanim_calculator::shaah_zmanis(sunrise, sunset) * 5),
+        )
     }
 
     /// Returns *mincha gedola* calculated as 6.5 *shaos zmaniyos* (solar hours)
     /// after sunrise, according to the GRA.
     #[must_use]
     pub fn mincha_gedola_gra(&self) -> Option<Zoned> {
-        Some(zmanim_calculator::mincha_gedola(
+        self.day_based_zman(
             &self.zmanim_sunrise()?,
             &self.zmanim_sunset()?,
-        ))
+            6.5,
+            zmanim_calculator::mincha_gedola,
+        )
     }
 
     /// Returns the later of
     /// [`mincha_gedola_gra`](ComplexZmanimCalendar::mincha_gedola_gra)
     /// and
-    /// [`mincha_gedola_30_minutes`](ComplexZmanimCalendar::mincha_gedola_30_minutes)
-    /// . In the winter when 1/2 of a
+    /// [`mincha_gedola_30_minutes`](ComplexZmanimCalendar::mincha_gedola_30_minutes).
+    /// In the winter when 1/2 of a
     /// [GRA *shaah zmanis*](ComplexZmanimCalendar::shaah_zmanis_gra)
     /// is less than 30 minutes
     /// [`mincha_gedola_30_minutes`](ComplexZmanimCalendar::mincha_gedola_30_minutes)
@@ -458,9 +1138,10 @@ impl ComplexZmanimCalendar {
     ///  will be returned
     #[must_use]
     pub fn mincha_gedola_gra_greater_than_30_minutes(&self) -> Option<Zoned> {
-        let mg_30 = self.mincha_gedola_30_minutes()?;
-        let mg_gra = self.mincha_gedola_gra()?;
-        Some(mg_30.max(mg_gra))
+        Some(zmanim_calculator::mincha_gedola_greater_than_30_minutes(
+            &self.mincha_gedola_gra()?,
+            &self.cached_solar_noon()?,
+        ))
     }
 
     /// Returns *samuch lemincha ketana* (near *mincha
@@ -472,27 +1153,36 @@ impl ComplexZmanimCalendar {
     /// *mincha*. See the *Mechaber* and *Mishna Berurah* 232 and 249:2.
     #[must_use]
     pub fn samuch_lemincha_ketana_gra(&self) -> Option<Zoned> {
-        Some(self.zmanim_sunrise()? + (self.shaah_zmanis_gra()? * 9))
+        self.day_based_zman(
+            &self.zmanim_sunrise()?,
+            &self.zmanim_sunset()?,
+            9.0,
+            |sunrise, sunset| sunrise + (zmanim_calculator::shaah_zmanis(sunrise, sunset) * 9),
+        )
     }
 
     /// Returns *mincha ketana* calculated as 9.5 *shaos zmaniyos* (solar hours)
     /// after sunrise, according to the GRA.
     #[must_use]
     pub fn mincha_ketana_gra(&self) -> Option<Zoned> {
-        Some(zmanim_calculator::mincha_ketana(
+        self.day_based_zman(
             &self.zmanim_sunrise()?,
             &self.zmanim_sunset()?,
-        ))
+            9.5,
+            zmanim_calculator::mincha_ketana,
+        )
     }
 
     /// Returns *plag hamincha* calculated as 10.75 *shaos zmaniyos* (solar
     /// hours) after sunrise, according to the GRA.
     #[must_use]
     pub fn plag_gra(&self) -> Option<Zoned> {
-        Some(zmanim_calculator::plag_hamincha(
+        self.day_based_zman(
             &self.zmanim_sunrise()?,
             &self.zmanim_sunset()?,
-        ))
+            10.75,
+            zmanim_calculator::plag_hamincha,
+        )
     }
 
     /// Returns a *shaah zmanis* according to the opinion of the GRA.
@@ -547,7 +1237,7 @@ impl ComplexZmanimCalendar {
     /// *Baal Hatanya*'s *zmanim*.
     #[must_use]
     pub fn hanetz_amiti_baal_hatanya(&self) -> Option<Zoned> {
-        self.alos(&Degrees(1.583))
+        self.cached_sunrise_offset_by_degrees(1.583)
     }
 
     /// **Note: *shkiah amiti* is used only for calculating certain other
@@ -565,7 +1255,25 @@ impl ComplexZmanimCalendar {
     /// degrees below the horizon.
     #[must_use]
     pub fn shkia_amiti_baal_hatanya(&self) -> Option<Zoned> {
-        self.tzeis(&Degrees(1.583))
+        self.cached_sunset_offset_by_degrees(1.583)
+    }
+
+    /// Returns the *zman* `hours` into the *Baal Hatanya*'s day, from [*hanetz
+    /// amiti*](ComplexZmanimCalendar::hanetz_amiti_baal_hatanya) to [*shkiah
+    /// amiti*](ComplexZmanimCalendar::shkia_amiti_baal_hatanya). These are
+    /// sunrise and sunset rather than twilight, so the [`TwilightFallback`] is
+    /// never used for them.
+    fn baal_hatanya_day_zman(
+        &self,
+        hours: f64,
+        zman: impl FnOnce(&Zoned, &Zoned) -> Zoned,
+    ) -> Option<Zoned> {
+        self.day_based_zman(
+            &self.hanetz_amiti_baal_hatanya()?,
+            &self.shkia_amiti_baal_hatanya()?,
+            hours,
+            zman,
+        )
     }
 
     /// Returns the *Baal Hatanya*'s *shaah zmanis* (temporal hour). This
@@ -582,7 +1290,10 @@ impl ComplexZmanimCalendar {
     /// zmanis*.
     #[must_use]
     pub fn shaah_zmanis_baal_hatanya(&self) -> Option<SignedDuration> {
-        self.shaah_zmanis_mga(&Degrees(1.583))
+        Some(zmanim_calculator::shaah_zmanis(
+            &self.hanetz_amiti_baal_hatanya()?,
+            &self.shkia_amiti_baal_hatanya()?,
+        ))
     }
 
     /// Returns the *Baal Hatanya*'s *sof zman krias shema*
@@ -594,7 +1305,7 @@ impl ComplexZmanimCalendar {
     /// calculated from sunrise to sunset.
     #[must_use]
     pub fn sof_zman_shema_baal_hatanya(&self) -> Option<Zoned> {
-        self.sof_zman_shema_mga(&Degrees(1.583))
+        self.baal_hatanya_day_zman(3.0, zmanim_calculator::sof_zman_shema)
     }
 
     /// Returns the *Baal Hatanya*'s *sof zman tefila* (latest
@@ -606,7 +1317,7 @@ impl ComplexZmanimCalendar {
     /// calculated from sunrise to sunset.
     #[must_use]
     pub fn sof_zman_tefila_baal_hatanya(&self) -> Option<Zoned> {
-        self.sof_zman_tefila_mga(&Degrees(1.583))
+        self.baal_hatanya_day_zman(4.0, zmanim_calculator::sof_zman_tefila)
     }
 
     /// Returns the latest time for burning *chametz* on *Erev
@@ -620,7 +1331,7 @@ impl ComplexZmanimCalendar {
     /// the year.
     #[must_use]
     pub fn sof_zman_biur_chametz_baal_hatanya(&self) -> Option<Zoned> {
-        self.sof_zman_biur_chametz_mga(&Degrees(1.583))
+        self.baal_hatanya_day_zman(5.0, zmanim_calculator::sof_zman_biur_chametz)
     }
 
     /// Returns the *Baal Hatanya*'s *mincha gedola*. *Mincha
@@ -635,14 +1346,14 @@ impl ComplexZmanimCalendar {
     /// the day is calculated from sunrise to sunset.
     #[must_use]
     pub fn mincha_gedola_baal_hatanya(&self) -> Option<Zoned> {
-        self.mincha_gedola_mga(&Degrees(1.583))
+        self.baal_hatanya_day_zman(6.5, zmanim_calculator::mincha_gedola)
     }
 
     /// Returns the later of
     /// [`mincha_gedola_baal_hatanya`](ComplexZmanimCalendar::mincha_gedola_baal_hatanya)
     /// and
-    /// [`mincha_gedola_30_minutes`](ComplexZmanimCalendar::mincha_gedola_30_minutes)
-    /// . In the winter when 1/2 of a
+    /// [`mincha_gedola_30_minutes`](ComplexZmanimCalendar::mincha_gedola_30_minutes).
+    /// In the winter when 1/2 of a
     /// [*Baal Hatanya shaah
     /// zmanis*](ComplexZmanimCalendar::shaah_zmanis_baal_hatanya)
     /// is less than 30 minutes
@@ -652,9 +1363,10 @@ impl ComplexZmanimCalendar {
     ///  will be returned
     #[must_use]
     pub fn mincha_gedola_baal_hatanya_greater_than_30_minutes(&self) -> Option<Zoned> {
-        let mg_30 = self.mincha_gedola_30_minutes()?;
-        let mg_bht = self.mincha_gedola_baal_hatanya()?;
-        Some(mg_30.max(mg_bht))
+        Some(zmanim_calculator::mincha_gedola_greater_than_30_minutes(
+            &self.mincha_gedola_baal_hatanya()?,
+            &self.cached_solar_noon()?,
+        ))
     }
 
     /// Returns the *Baal Hatanya*'s *mincha ketana*. This is the
@@ -668,7 +1380,7 @@ impl ComplexZmanimCalendar {
     /// sunset.
     #[must_use]
     pub fn mincha_ketana_baal_hatanya(&self) -> Option<Zoned> {
-        self.mincha_ketana_mga(&Degrees(1.583))
+        self.baal_hatanya_day_zman(9.5, zmanim_calculator::mincha_ketana)
     }
 
     /// Returns the *Baal Hatanya*'s *plag hamincha*. This is
@@ -679,7 +1391,7 @@ impl ComplexZmanimCalendar {
     /// sunrise to sunset.
     #[must_use]
     pub fn plag_baal_hatanya(&self) -> Option<Zoned> {
-        self.plag_mga(&Degrees(1.583))
+        self.baal_hatanya_day_zman(10.75, zmanim_calculator::plag_hamincha)
     }
 
     /// Returns *tzeis* (nightfall) when the sun is 6&deg; below
@@ -977,6 +1689,19 @@ impl ComplexZmanimCalendar {
         ))
     }
 
+    /// Returns the later of
+    /// [`mincha_gedola_ateret_torah`](ComplexZmanimCalendar::mincha_gedola_ateret_torah)
+    /// and
+    /// [`mincha_gedola_30_minutes`](ComplexZmanimCalendar::mincha_gedola_30_minutes).
+    /// See [`zmanim_calculator::mincha_gedola_greater_than_30_minutes`]
+    #[must_use]
+    pub fn mincha_gedola_ateret_torah_greater_than_30_minutes(&self) -> Option<Zoned> {
+        Some(zmanim_calculator::mincha_gedola_greater_than_30_minutes(
+            &self.mincha_gedola_ateret_torah()?,
+            &self.cached_solar_noon()?,
+        ))
+    }
+
     /// Returns the time of *mincha ketana* based on the calculation of
     /// *Chacham* Yosef Harari-Raful of Yeshivat Ateret Torah, that the day
     /// starts 1/10th of the day before sunrise and is usually calculated as
@@ -1159,6 +1884,7 @@ impl ComplexZmanimCalendar {
             sof_zman_tefila_mga_16_1_degrees => sof_zman_tefila_mga, szt_mga_degrees_doc!(16.1),
             sof_zman_biur_chametz_mga_16_1_degrees => sof_zman_biur_chametz_mga, sz_biur_chametz_mga_degrees_doc!(16.1),
             mincha_gedola_mga_16_1_degrees => mincha_gedola_mga, mg_mga_degrees_doc!(16.1),
+            mincha_gedola_mga_16_1_degrees_greater_than_30_minutes => mincha_gedola_mga_greater_than_30_minutes, mg_30_mga_degrees_doc!(16.1),
             samuch_lemincha_ketana_mga_16_1_degrees => samuch_lemincha_ketana_mga, slmk_mga_degrees_doc!(16.1),
             mincha_ketana_mga_16_1_degrees => mincha_ketana_mga, mk_mga_degrees_doc!(16.1),
             plag_mga_16_1_degrees => plag_mga, plag_mga_degrees_lechumra_doc!(16.1),
@@ -1313,6 +2039,7 @@ impl ComplexZmanimCalendar {
             sof_zman_tefila_mga_72_minutes => sof_zman_tefila_mga, szt_mga_minutes_doc!(72),
             sof_zman_biur_chametz_mga_72_minutes => sof_zman_biur_chametz_mga, sz_biur_chametz_mga_minutes_doc!(72),
             mincha_gedola_mga_72_minutes => mincha_gedola_mga, mg_mga_minutes_doc!(72),
+            mincha_gedola_mga_72_minutes_greater_than_30_minutes => mincha_gedola_mga_greater_than_30_minutes, mg_30_mga_minutes_doc!(72),
             samuch_lemincha_ketana_mga_72_minutes => samuch_lemincha_ketana_mga, slmk_mga_minutes_doc!(72),
             mincha_ketana_mga_72_minutes => mincha_ketana_mga, mk_mga_minutes_doc!(72),
             plag_mga_72_minutes => plag_mga, plag_mga_minutes_lechumra_doc!(72),
@@ -1838,6 +2565,113 @@ impl ComplexZmanimCalendar {
         self.tzeis(&Degrees(9.75))
     }
 
+    // Minhag profiles
+    /// Returns the daily schedule of `profile`: each [`ProfileItem`] in
+    /// order, calculated with the profile's [`UseElevation`] setting and
+    /// rounded according to its [`Rounding`](super::Rounding).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProfileError::UnknownZman`] if one of the profile's *zmanim*
+    /// is not the name of a time in the [registry](super::ALL_ZMANIM).
+    pub fn schedule(&self, profile: &MinhagProfile) -> Result<Vec<ScheduleEntry>, ProfileError> {
+        let mut czc = self.clone();
+        czc.set_use_elevation(profile.use_elevation);
+        ProfileItem::ALL
+            .into_iter()
+            .map(|item| {
+                let time = match profile.zman_name(item) {
+                    None => czc.candle_lighting(profile.candle_lighting_minutes),
+                    Some(name) => {
+                        let entry = find_zman(name)
+                            .filter(|entry| entry.kind == ZmanKind::Time)
+                            .ok_or_else(|| ProfileError::UnknownZman(item, name.to_string()))?;
+                        match (entry.compute)(&czc) {
+                            Some(ZmanValue::Time(time)) => Some(time),
+                            _ => None,
+                        }
+                    }
+                };
+                Ok(ScheduleEntry {
+                    item,
+                    time: time.map(|time| profile.rounding.round(&time, item.bound())),
+                })
+            })
+            .collect()
+    }
+
+    // Uncertainty intervals
+    /// Returns the range of times the *zman* calculated by `zman` could be,
+    /// given the `uncertainty` of its inputs, such as
+    /// `czc.zman_interval(&uncertainty, ComplexZmanimCalendar::hanetz)`.
+    ///
+    /// Each input is moved by its uncertainty in both directions in turn, and
+    /// the largest changes in each direction are added up, so the interval is
+    /// the worst case rather than a statistical estimate. Returns `None` if the
+    /// *zman* does not occur for the stated inputs or for any of the moved
+    /// ones.
+    #[must_use]
+    pub fn zman_interval(
+        &self,
+        uncertainty: &Uncertainty,
+        zman: impl Fn(&Self) -> Option<Zoned>,
+    ) -> Option<ZmanInterval> {
+        let nominal = zman(self)?;
+        let geo = &self.geo_location;
+        let mut perturbed = Vec::new();
+        for sign in [-1.0, 1.0] {
+            // a higher horizon has the same effect as less refraction
+            for degrees in [uncertainty.refraction, -uncertainty.horizon] {
+                perturbed.push(self.perturbed(geo.clone(), sign * degrees));
+            }
+            let elevation = (geo.elevation + sign * uncertainty.elevation).max(0.0);
+            let latitude = (geo.latitude + sign * uncertainty.coordinates).clamp(-90.0, 90.0);
+            let longitude =
+                (geo.longitude + sign * uncertainty.coordinates + 540.0).rem_euclid(360.0) - 180.0;
+            for (latitude, longitude, elevation) in [
+                (geo.latitude, geo.longitude, elevation),
+                (latitude, geo.longitude, geo.elevation),
+                (geo.latitude, longitude, geo.elevation),
+            ] {
+                let geo_location =
+                    GeoLocation::new(latitude, longitude, elevation, geo.timezone.clone()).ok()?;
+                perturbed.push(self.perturbed(geo_location, 0.0));
+            }
+        }
+
+        // the largest change in each direction, for each input
+        let inputs = perturbed.len() / 2;
+        let mut earlier = SignedDuration::ZERO;
+        let mut later = SignedDuration::ZERO;
+        for i in 0..inputs {
+            let mut earliest = SignedDuration::ZERO;
+            let mut latest = SignedDuration::ZERO;
+            for czc in [&perturbed[i], &perturbed[i + inputs]] {
+                let change = zman(czc)?.duration_since(&nominal);
+                earliest = earliest.min(change);
+                latest = latest.max(change);
+            }
+            earlier = earlier.checked_add(earliest)?;
+            later = later.checked_add(latest)?;
+        }
+        Some(ZmanInterval {
+            earliest: nominal.checked_add(earlier).ok()?,
+            latest: nominal.checked_add(later).ok()?,
+            nominal,
+        })
+    }
+
+    /// A copy of this calendar at `geo_location`, with `sunrise_zenith_offset`
+    /// degrees added to the zenith of sunrise and sunset
+    fn perturbed(&self, geo_location: GeoLocation, sunrise_zenith_offset: f64) -> Self {
+        Self {
+            geo_location,
+            sunrise_zenith_offset: self.sunrise_zenith_offset + sunrise_zenith_offset,
+            cache: ZmanCache::default(),
+            ..*self
+        }
+    }
+
     // Solar position / Polar regions
     /// Returns the solar azimuth (in degrees, measured clockwise from due
     /// north) of the sun at the given datetime for this location.
@@ -1854,6 +2688,26 @@ impl ComplexZmanimCalendar {
         astronomical_calculator::solar_elevation(instant, &self.geo_location)
     }
 
+    /// Returns every time on this date that the center of the sun crosses
+    /// `elevation` degrees (negative below the horizon), rising or setting.
+    /// See [`astronomical_calculator::times_at_elevation`] for details.
+    #[must_use]
+    pub fn times_at_elevation(
+        &self,
+        elevation: f64,
+    ) -> Vec<astronomical_calculator::SolarCrossing> {
+        astronomical_calculator::times_at_elevation(self.date, &self.geo_location, elevation)
+    }
+
+    /// Returns every time on this date that the sun is at `azimuth` degrees
+    /// (clockwise from due north), such as `180.0` for due south or the
+    /// direction of a synagogue's *mizrach* wall. See
+    /// [`astronomical_calculator::times_at_azimuth`] for details.
+    #[must_use]
+    pub fn times_at_azimuth(&self, azimuth: f64) -> Vec<astronomical_calculator::SolarCrossing> {
+        astronomical_calculator::times_at_azimuth(self.date, &self.geo_location, azimuth)
+    }
+
     /// Returns the percentage of a *shaah zmanis* after sunset (when `sunset`
     /// is `true`) or before sunrise (when `sunset` is `false`) for a given
     /// `degrees` offset below the horizon.
@@ -1887,11 +2741,68 @@ impl ComplexZmanimCalendar {
         };
         Some(rise_set_to_twilight / shaah_zmanis)
     }
+
+    /// Returns the degrees below the horizon equivalent to `offset` before sea
+    /// level sunrise (or after sea level sunset when `sunset` is `true`) at
+    /// this location and date: the sun's geometric depression at that time.
+    /// For a [`Degrees`] offset this is just its degrees.
+    ///
+    /// This is how the degree-based *zmanim* are derived. For example, 72
+    /// minutes before sunrise in Jerusalem around the equinox / equilux is
+    /// about 16.1&deg;, 90 minutes is about 19.8&deg;, and 120 minutes is about
+    /// 26&deg;. Calling this on a calendar for another location (and a date
+    /// around the equinox) gives the local equivalent. Pass a
+    /// [`MinutesZmaniyos`] offset with the day's
+    /// [*shaah zmanis*](ComplexZmanimCalendar::shaah_zmanis_gra) to calibrate
+    /// minutes *zmaniyos*.
+    #[must_use]
+    pub fn degrees_for_offset(&self, offset: &ZmanOffset, sunset: bool) -> Option<f64> {
+        let time = match offset {
+            Degrees(degrees) => return Some(*degrees),
+            _ if sunset => zmanim_calculator::offset_after_event(&self.sea_level_sunset()?, offset),
+            _ => zmanim_calculator::offset_before_event(&self.sea_level_sunrise()?, offset),
+        };
+        Some(-astronomical_calculator::solar_geometric_elevation(
+            &time,
+            &self.geo_location,
+        ))
+    }
+
+    /// Returns the clock minutes before sea level sunrise (or after sea level
+    /// sunset when `sunset` is `true`) at which the sun is `degrees` below the
+    /// horizon at this location and date. The reverse of
+    /// [`degrees_for_offset`](ComplexZmanimCalendar::degrees_for_offset).
+    #[must_use]
+    pub fn minutes_for_degrees(&self, degrees: f64, sunset: bool) -> Option<f64> {
+        let twilight = if sunset {
+            self.cached_sunset_offset_by_degrees(degrees)?
+                .duration_since(&self.sea_level_sunset()?)
+        } else {
+            self.sea_level_sunrise()?
+                .duration_since(&self.cached_sunrise_offset_by_degrees(degrees)?)
+        };
+        Some(twilight.as_secs_f64() / 60.0)
+    }
+
+    /// Returns the minutes *zmaniyos* (of a sea level sunrise to sunset day)
+    /// before sea level sunrise (or after sea level sunset when `sunset` is
+    /// `true`) at which the sun is `degrees` below the horizon at this
+    /// location and date. Like
+    /// [`percent_of_shaah_zmanis_from_degrees`](ComplexZmanimCalendar::percent_of_shaah_zmanis_from_degrees)
+    /// (times 60), but without the [`TwilightFallback`].
+    #[must_use]
+    pub fn minutes_zmaniyos_for_degrees(&self, degrees: f64, sunset: bool) -> Option<f64> {
+        let day = self
+            .sea_level_sunset()?
+            .duration_since(&self.sea_level_sunrise()?);
+        Some(self.minutes_for_degrees(degrees, sunset)? / (day.as_secs_f64() / 60.0 / 12.0) * 60.0)
+    }
 }
 
 /// When to use elevation for *zmanim* calculations. See
 /// [`crate`] for details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UseElevation {
     /// Never use elevation
     No,
@@ -1901,6 +2812,59 @@ pub enum UseElevation {
     All,
 }
 
+/// How [`ComplexZmanimCalendar`] calculates degree-based *alos* and *tzeis*
+/// (such as [`alos_16_1_degrees`](ComplexZmanimCalendar::alos_16_1_degrees) or
+/// [`tzeis_18_degrees`](ComplexZmanimCalendar::tzeis_18_degrees)) on dates when
+/// the sun never gets that far below the horizon, as in summer at high
+/// latitudes. Set with
+/// [`set_twilight_fallback`](ComplexZmanimCalendar::set_twilight_fallback).
+///
+/// The *Baal Hatanya*'s [*hanetz
+/// amiti*](ComplexZmanimCalendar::hanetz_amiti_baal_hatanya) and [*shkiah
+/// amiti*](ComplexZmanimCalendar::shkia_amiti_baal_hatanya), and the *zmanim*
+/// of his day, are sunrise and sunset rather than twilight, so they never use
+/// the fallback.
+///
+/// The fallback applies wherever a degree-based *alos* or *tzeis* is used, not
+/// only to the *alos* and *tzeis* accessors themselves: enabling it also
+/// changes the *Magen Avraham* *zmanim* whose day is measured from them (such
+/// as [`sof_zman_shema_mga_16_1_degrees`](ComplexZmanimCalendar::sof_zman_shema_mga_16_1_degrees),
+/// [`plag_mga_16_1_degrees`](ComplexZmanimCalendar::plag_mga_16_1_degrees) and
+/// [`shaah_zmanis_mga_16_1_degrees`](ComplexZmanimCalendar::shaah_zmanis_mga_16_1_degrees)),
+/// and [`DayDefinition`]s with a degree-based start or end, which otherwise
+/// return `None` on those dates.
+///
+/// The fallback is only used when the sun stays too high all night (see
+/// [`ZmanError::DepressionNotReached`]); in polar night, or when the time
+/// cannot be calculated for another reason, `None` is still returned. Where a
+/// fallback is measured from sunrise or sunset, it is measured from sea level
+/// or elevation-adjusted sunrise or sunset per [`UseElevation::to_bool`] with
+/// `hanetz_or_shkia` of `false`, like fixed-minute *alos* and *tzeis*.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TwilightFallback {
+    /// Return `None` (the default)
+    #[default]
+    Disabled,
+    /// Use [solar midnight](ComplexZmanimCalendar::chatzos_halayla), when the
+    /// sun is at its lowest: the previous night's for *alos*, and the
+    /// following night's for *tzeis*
+    SolarMidnight,
+    /// Use the same number of clock minutes before sunrise (or after sunset)
+    /// as on the most recent earlier date (up to a year back) that the sun did
+    /// reach the depression. The date found is cached, and carried on to the
+    /// next day by [`advance_day`](ComplexZmanimCalendar::advance_day), so a
+    /// [`ZmanimTable`](super::ZmanimTable) only searches for it once
+    LastDayReached,
+    /// Use 1/7 of the night (sunset to sunrise) before sunrise for *alos*, or
+    /// after sunset for *tzeis*
+    SeventhOfNight,
+    /// Use the same number of clock minutes before sunrise (or after sunset)
+    /// as on the March equinox of the same year at this location (the local
+    /// date during which the sun crosses the celestial equator northward)
+    EquinoxMinutes,
+}
+
 impl UseElevation {
     /// Convert the `UseElevation` into a `bool` for
     /// [`zmanim_calculator`](crate::zmanim_calculator) functions. The param
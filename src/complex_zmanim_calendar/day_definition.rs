@@ -0,0 +1,107 @@
+//! Definitions of the day that proportional *zmanim* are calculated from, for
+//! opinions without premade [`ComplexZmanimCalendar`](super::ComplexZmanimCalendar)
+//! methods.
+
+use crate::zmanim_calculator::ZmanOffset;
+
+/// The solar event a [`DayEvent`] is offset from
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DayAnchor {
+    /// Sunrise. The [offset](DayEvent::offset) is before sunrise, like
+    /// [*alos*](super::ComplexZmanimCalendar::alos)
+    Sunrise,
+    /// Sunset. The [offset](DayEvent::offset) is after sunset, like
+    /// [*tzeis*](super::ComplexZmanimCalendar::tzeis)
+    Sunset,
+    /// [Astronomical *chatzos*](super::ComplexZmanimCalendar::chatzos_hayom).
+    /// The [offset](DayEvent::offset) is after *chatzos*
+    Noon,
+    /// The first time on the date that the sun is at the contained azimuth
+    /// (see [`times_at_azimuth`](super::ComplexZmanimCalendar::times_at_azimuth)).
+    /// The [offset](DayEvent::offset) is after that time
+    Azimuth(f64),
+}
+
+/// The start or end of a [`DayDefinition`]: a [`DayAnchor`] and a
+/// [`ZmanOffset`] from it.
+///
+/// The offset is applied the way
+/// [*alos*](super::ComplexZmanimCalendar::alos) and
+/// [*tzeis*](super::ComplexZmanimCalendar::tzeis) apply it: before sunrise and
+/// after sunset (a negative offset moves the other way), so degree offsets are
+/// the sun's depression below the horizon. The offset from noon or an azimuth
+/// is after that time, and can't be in degrees.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DayEvent {
+    /// The event the offset is from
+    pub anchor: DayAnchor,
+    /// The offset from the anchor
+    pub offset: ZmanOffset,
+}
+
+impl DayEvent {
+    /// Returns a new `DayEvent` at `offset` from `anchor`
+    #[must_use]
+    pub const fn new(anchor: DayAnchor, offset: ZmanOffset) -> Self {
+        Self { anchor, offset }
+    }
+
+    /// Returns a new `DayEvent` at `anchor`, with no offset
+    #[must_use]
+    pub const fn at(anchor: DayAnchor) -> Self {
+        Self::new(anchor, ZmanOffset::Minutes(0.0))
+    }
+}
+
+/// A day from [`start`](DayDefinition::start) to [`end`](DayDefinition::end),
+/// which is divided into 12 *shaos zmaniyos* (temporal hours) to calculate
+/// proportional *zmanim* such as *sof zman krias shema* or *plag hamincha*
+/// (see [`ComplexZmanimCalendar::sof_zman_shema`](super::ComplexZmanimCalendar::sof_zman_shema)
+/// and the methods that follow it).
+///
+/// For example, the day from *alos* 16.1&deg; to *tzeis* 7.083&deg;:
+///
+/// ```rust
+/// # use rust_zmanim::prelude::*;
+/// let day = DayDefinition::new(
+///     DayEvent::new(DayAnchor::Sunrise, ZmanOffset::Degrees(16.1)),
+///     DayEvent::new(DayAnchor::Sunset, ZmanOffset::Degrees(7.083)),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DayDefinition {
+    /// The start of the day
+    pub start: DayEvent,
+    /// The end of the day
+    pub end: DayEvent,
+}
+
+impl DayDefinition {
+    /// Returns a new `DayDefinition` from `start` to `end`
+    #[must_use]
+    pub const fn new(start: DayEvent, end: DayEvent) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the day according to the GRA, from sunrise to sunset
+    #[must_use]
+    pub const fn gra() -> Self {
+        Self::new(
+            DayEvent::at(DayAnchor::Sunrise),
+            DayEvent::at(DayAnchor::Sunset),
+        )
+    }
+
+    /// Returns the day according to the *Magen Avraham* (MGA), from *alos* to
+    /// *tzeis* both at `offset` from sunrise and sunset
+    #[must_use]
+    pub fn mga(offset: ZmanOffset) -> Self {
+        Self::new(
+            DayEvent::new(DayAnchor::Sunrise, offset.clone()),
+            DayEvent::new(DayAnchor::Sunset, offset),
+        )
+    }
+}
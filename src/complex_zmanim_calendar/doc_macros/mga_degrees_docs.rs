@@ -115,6 +115,22 @@ macro_rules! mg_mga_degrees_doc {
     };
 }
 
+macro_rules! mg_30_mga_degrees_doc {
+    ($degrees:expr) => {
+        concat!(
+            "Returns the later of [*mincha gedola* according to the *Magen
+            Avraham*](ComplexZmanimCalendar::mincha_gedola_mga) based on *alos*
+            and *tzeis* being ",
+            stringify!($degrees),
+            "&deg; below the horizon, and
+            [`mincha_gedola_30_minutes`](ComplexZmanimCalendar::mincha_gedola_30_minutes).
+            In the winter when 1/2 of this *shaah zmanis* is less than 30
+            minutes, the 30 minute time will be returned. See
+            [`zmanim_calculator::mincha_gedola_greater_than_30_minutes`]."
+        )
+    };
+}
+
 macro_rules! slmk_mga_degrees_doc {
     ($degrees:expr) => {
         concat!(
@@ -0,0 +1,840 @@
+//! Custom *zmanim* defined as text, so that unusual opinions can be configured
+//! without recompiling, with [`CustomZmanim`].
+
+use std::{str::FromStr, sync::Arc};
+
+use jiff::{SignedDuration, Zoned};
+
+use super::{ComplexZmanimCalendar, ZmanEntry, ZmanKind, ZmanSpec, ZmanValue, find_zman};
+use crate::zmanim_calculator::ZmanOffset::{Degrees, Minutes};
+
+/// A parsed list of custom *zmanim* definitions.
+///
+/// A definition list is a series of `name = expression` definitions separated
+/// by `;`, with `#` starting a comment to the end of the line:
+///
+/// ```rust
+/// # use rust_zmanim::prelude::*;
+/// let custom = CustomZmanim::parse(
+///     "alos = sunrise - 16.1deg; # alos at 16.1 degrees
+///      szks = alos + 3 * shaah(alos, tzeis(8.5deg));",
+/// )
+/// .unwrap();
+/// ```
+///
+/// Expressions are made of:
+/// - numbers, and quantities with a unit: degrees below the horizon (`16.1deg`),
+///   clock minutes (`72min`), and minutes *zmaniyos* of a GRA *shaah zmanis*
+///   (`90zmanis`);
+/// - `sunrise` and `sunset`, which can be offset by degrees (`sunrise -
+///   16.1deg` is when the sun is 16.1&deg; below the horizon before sunrise,
+///   like [*alos*](ComplexZmanimCalendar::alos)), and `noon` and `midnight`
+///   (astronomical *chatzos*);
+/// - the names of earlier definitions, and of any *zman* in the
+///   [registry](super::ALL_ZMANIM), such as `sof_zman_shema_gra` or
+///   `shaah_zmanis_gra`;
+/// - the functions `alos(offset)` and `tzeis(offset)` (with an offset in
+///   degrees or minutes), `shaah(start, end)` (the *shaah zmanis* of a day
+///   from `start` to `end`), and `min(a, b)` and `max(a, b)`. A definition
+///   can have the same name as a function, as `alos` does above;
+/// - `+`, `-`, `*`, `/`, and parentheses: times can be offset by durations,
+///   subtracting times gives a duration, and durations can be scaled by
+///   numbers.
+///
+/// Sunrise and sunset (in expressions and in offsets from them) follow the
+/// calendar's [`UseElevation`](super::UseElevation) setting like *alos* and
+/// *tzeis*. Every definition must be a time or a duration. Errors such as
+/// adding two times or an unknown name are found when parsing, not when
+/// calculating, and so are expressions nested more than 200 deep (counting
+/// the definitions they use).
+///
+/// Custom *zmanim* can be used wherever a [`ZmanSpec`] can, such as in a
+/// [`ZmanimTable`](super::ZmanimTable), with [`parse_spec`](Self::parse_spec)
+/// and [`specs`](Self::specs):
+///
+/// ```rust
+/// # use rust_zmanim::prelude::*;
+/// let custom = CustomZmanim::parse("alos = sunrise - 16.1deg").unwrap();
+/// let alos = custom.parse_spec("alos").unwrap();
+/// let tzeis = custom.parse_spec("tzeis(8.5deg)").unwrap();
+/// assert_eq!(alos.kind(), ZmanKind::Time);
+/// assert_eq!(custom.specs().collect::<Vec<_>>(), [alos]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CustomZmanim {
+    // shared with the `ZmanSpec`s of the definitions
+    definitions: Arc<[Definition]>,
+}
+
+impl CustomZmanim {
+    /// Parses a list of definitions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExpressionError`] if the definitions are not valid.
+    pub fn parse(source: &str) -> Result<Self, ExpressionError> {
+        Parser::new(source)?.definitions()
+    }
+
+    /// The names of the custom *zmanim*, in the order they were defined
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.definitions
+            .iter()
+            .map(|definition| definition.name.as_str())
+    }
+
+    /// Whether the custom or [registry](super::ALL_ZMANIM) *zman* named `name`
+    /// is an instant or a duration, or [`None`] if there is no such *zman*
+    #[must_use]
+    pub fn kind(&self, name: &str) -> Option<ZmanKind> {
+        match self.position(name) {
+            Some(index) => Some(self.definitions[index].kind),
+            None => find_zman(name).map(|entry| entry.kind),
+        }
+    }
+
+    /// Computes the custom *zman* named `name` for `czc`. Names that are not
+    /// defined here are looked up in the [registry](super::ALL_ZMANIM), so
+    /// custom and built-in *zmanim* can be used in the same way.
+    ///
+    /// Returns [`None`] if there is no such *zman*, or if it does not occur on
+    /// this date (e.g. in polar regions).
+    #[must_use]
+    pub fn compute(&self, czc: &ComplexZmanimCalendar, name: &str) -> Option<ZmanValue> {
+        match self.position(name) {
+            Some(index) => self.compute_definition(czc, index),
+            None => (find_zman(name)?.compute)(czc),
+        }
+    }
+
+    /// Parses a [`ZmanSpec`] that can also name the custom *zmanim*: a custom
+    /// name is looked up here first, and anything else is
+    /// [parsed](ZmanSpec::parse) as a registry or parameterized *zman*.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExpressionError`] as [`ZmanSpec::parse`] does.
+    pub fn parse_spec(&self, source: &str) -> Result<ZmanSpec, ExpressionError> {
+        match self.position(source.trim()) {
+            Some(index) => Ok(ZmanSpec::custom(self.clone(), index)),
+            None => ZmanSpec::parse(source),
+        }
+    }
+
+    /// A [`ZmanSpec`] for each custom *zman*, in the order they were defined
+    pub fn specs(&self) -> impl Iterator<Item = ZmanSpec> + '_ {
+        (0..self.definitions.len()).map(|index| ZmanSpec::custom(self.clone(), index))
+    }
+
+    /// The name of the definition at `index`
+    pub(super) fn name_at(&self, index: usize) -> &str {
+        &self.definitions[index].name
+    }
+
+    /// The kind of the definition at `index`
+    pub(super) fn kind_at(&self, index: usize) -> ZmanKind {
+        self.definitions[index].kind
+    }
+
+    /// Computes the definition at `index` for `czc`
+    pub(super) fn compute_definition(
+        &self,
+        czc: &ComplexZmanimCalendar,
+        index: usize,
+    ) -> Option<ZmanValue> {
+        // definitions only use earlier ones, so each is calculated once, in
+        // order, however many times it is used
+        let mut values = Vec::with_capacity(index + 1);
+        for definition in &self.definitions[..=index] {
+            let value = evaluate(czc, &values, &definition.expr);
+            values.push(value);
+        }
+        match values.pop().flatten()? {
+            Value::Duration(duration) => Some(ZmanValue::Duration(duration)),
+            value => Some(ZmanValue::Time(value.into_time(czc)?)),
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.definitions
+            .iter()
+            .position(|definition| definition.name == name)
+    }
+}
+
+/// Calculates `expr` for `czc`, with the values of the definitions it can use
+/// (those before it) in `definitions`
+fn evaluate(
+    czc: &ComplexZmanimCalendar,
+    definitions: &[Option<Value>],
+    expr: &Expr,
+) -> Option<Value> {
+    Some(match expr {
+        Expr::Number(number) => Value::Number(*number),
+        Expr::Degrees(degrees) => Value::Degrees(*degrees),
+        Expr::Minutes(minutes) => {
+            Value::Duration(SignedDuration::try_from_secs_f64(minutes * 60.0).ok()?)
+        }
+        Expr::MinutesZmaniyos(minutes) => Value::Duration(SignedDuration::from_nanos(
+            (czc.shaah_zmanis_gra()?.as_nanos() as f64 * (minutes / 60.0)) as i64,
+        )),
+        Expr::Sunrise => Value::Sunrise,
+        Expr::Sunset => Value::Sunset,
+        Expr::Noon => Value::Time(czc.chatzos_hayom()?),
+        Expr::Midnight => Value::Time(czc.chatzos_halayla()?),
+        Expr::Definition(index) => definitions[*index].clone()?,
+        Expr::Registry(entry) => match (entry.compute)(czc)? {
+            ZmanValue::Time(time) => Value::Time(time),
+            ZmanValue::Duration(duration) => Value::Duration(duration),
+            ZmanValue::Number(number) => Value::Number(number),
+        },
+        Expr::Negate(expr) => match evaluate(czc, definitions, expr)? {
+            Value::Number(number) => Value::Number(-number),
+            Value::Degrees(degrees) => Value::Degrees(-degrees),
+            Value::Duration(duration) => Value::Duration(-duration),
+            _ => return None,
+        },
+        Expr::Binary(op, lhs, rhs) => {
+            let lhs = evaluate(czc, definitions, lhs)?;
+            let rhs = evaluate(czc, definitions, rhs)?;
+            binary(czc, *op, lhs, rhs)?
+        }
+        Expr::Call(function, args) => {
+            let mut args = args.iter().map(|arg| evaluate(czc, definitions, arg));
+            let a = args.next().flatten()?;
+            match function {
+                Function::Alos => Value::Time(match a {
+                    Value::Degrees(degrees) => czc.alos(&Degrees(degrees))?,
+                    Value::Duration(duration) => {
+                        czc.alos(&Minutes(0.0))?.checked_sub(duration).ok()?
+                    }
+                    _ => return None,
+                }),
+                Function::Tzeis => Value::Time(match a {
+                    Value::Degrees(degrees) => czc.tzeis(&Degrees(degrees))?,
+                    Value::Duration(duration) => {
+                        czc.tzeis(&Minutes(0.0))?.checked_add(duration).ok()?
+                    }
+                    _ => return None,
+                }),
+                Function::Shaah => {
+                    let b = args.next().flatten()?;
+                    Value::Duration(b.into_time(czc)?.duration_since(&a.into_time(czc)?) / 12)
+                }
+                Function::Min | Function::Max => {
+                    let b = args.next().flatten()?;
+                    let pick_first = match (a.comparable(czc)?, b.comparable(czc)?) {
+                        (Value::Time(a), Value::Time(b)) => a <= b,
+                        (Value::Duration(a), Value::Duration(b)) => a <= b,
+                        (Value::Number(a), Value::Number(b)) => a <= b,
+                        _ => return None,
+                    } == (*function == Function::Min);
+                    if pick_first { a } else { b }
+                }
+            }
+        }
+    })
+}
+
+impl FromStr for CustomZmanim {
+    type Err = ExpressionError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+/// An invalid custom *zman* definition, returned by [`CustomZmanim::parse`],
+/// or an invalid [`ZmanSpec`](super::ZmanSpec). Positions are byte offsets into
+/// the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExpressionError {
+    /// The text at the contained position could not be parsed; the message
+    /// describes what was expected
+    Syntax(usize, &'static str),
+    /// The contained name is not a definition, function, or *zman*
+    UnknownName(usize, String),
+    /// The contained name is defined twice, or is the name of a built-in
+    /// value (such as `sunrise`) or registry *zman*
+    Redefined(usize, String),
+    /// The operation at the contained position is not valid for the types of
+    /// its values (such as adding two times); the message describes it
+    TypeMismatch(usize, &'static str),
+}
+
+impl core::fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Syntax(position, message) => write!(f, "at {position}: {message}"),
+            Self::UnknownName(position, name) => write!(f, "at {position}: unknown name {name}"),
+            Self::Redefined(position, name) => {
+                write!(f, "at {position}: {name} is already defined")
+            }
+            Self::TypeMismatch(position, message) => write!(f, "at {position}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ExpressionError {}
+
+#[derive(Debug, Clone)]
+struct Definition {
+    name: String,
+    kind: ZmanKind,
+    expr: Expr,
+    /// The height of `expr`, counting the definitions it uses
+    height: usize,
+}
+
+/// The greatest [height](Parsed::height) of an expression, so that
+/// calculating (and dropping) one doesn't overflow the stack
+const MAX_HEIGHT: usize = 200;
+
+/// A parsed expression and its type
+struct Parsed {
+    expr: Expr,
+    ty: Type,
+    /// The depth of the nesting of `expr`, and so of the recursion to
+    /// calculate it
+    height: usize,
+}
+
+impl Parsed {
+    const fn leaf(expr: Expr, ty: Type) -> Self {
+        Self {
+            expr,
+            ty,
+            height: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Number(f64),
+    Degrees(f64),
+    Minutes(f64),
+    MinutesZmaniyos(f64),
+    Sunrise,
+    Sunset,
+    Noon,
+    Midnight,
+    Definition(usize),
+    Registry(&'static ZmanEntry),
+    Negate(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+    Call(Function, Vec<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Function {
+    Alos,
+    Tzeis,
+    Shaah,
+    Min,
+    Max,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "alos" => Some(Self::Alos),
+            "tzeis" => Some(Self::Tzeis),
+            "shaah" => Some(Self::Shaah),
+            "min" => Some(Self::Min),
+            "max" => Some(Self::Max),
+            _ => None,
+        }
+    }
+}
+
+/// The names of the built-in values, which can't be redefined
+const BUILT_IN_VALUES: [&str; 4] = ["sunrise", "sunset", "noon", "midnight"];
+
+/// The type of an expression, checked when parsing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Type {
+    /// Sunrise or sunset, which can be offset by degrees
+    Event,
+    Time,
+    Duration,
+    Number,
+    Degrees,
+}
+
+impl Type {
+    const fn is_time(self) -> bool {
+        matches!(self, Self::Event | Self::Time)
+    }
+}
+
+/// The result of [`binary`] for operands of types `lhs` and `rhs`, or a
+/// description of why the operation is invalid
+fn binary_type(op: Op, lhs: Type, rhs: Type) -> Result<Type, &'static str> {
+    use Type::{Degrees, Duration, Event, Number, Time};
+    match (op, lhs, rhs) {
+        (Op::Add | Op::Subtract, Event, Degrees) => Ok(Time),
+        (Op::Add | Op::Subtract, _, Degrees) | (Op::Add | Op::Subtract, Degrees, _)
+            if lhs != rhs =>
+        {
+            Err("degrees can only be added to or subtracted from sunrise or sunset")
+        }
+        (Op::Add | Op::Subtract, Event | Time, Duration) | (Op::Add, Duration, Event | Time) => {
+            Ok(Time)
+        }
+        (Op::Subtract, Event | Time, Event | Time) => Ok(Duration),
+        (Op::Add | Op::Subtract, _, _) if lhs == rhs && !lhs.is_time() => Ok(lhs),
+        (Op::Add | Op::Subtract, _, _) => Err("cannot add or subtract these values"),
+        (Op::Multiply, Number, other) | (Op::Multiply, other, Number) if !other.is_time() => {
+            Ok(other)
+        }
+        (Op::Multiply, _, _) => Err("can only multiply by a number"),
+        (Op::Divide, Duration, Duration) => Ok(Number),
+        (Op::Divide, Number | Duration | Degrees, Number) => Ok(lhs),
+        (Op::Divide, _, _) => Err("cannot divide these values"),
+    }
+}
+
+/// A value while calculating an expression
+#[derive(Debug, Clone)]
+enum Value {
+    Sunrise,
+    Sunset,
+    Time(Zoned),
+    Duration(SignedDuration),
+    Number(f64),
+    Degrees(f64),
+}
+
+impl Value {
+    fn into_time(self, czc: &ComplexZmanimCalendar) -> Option<Zoned> {
+        match self {
+            Self::Sunrise => czc.alos(&Minutes(0.0)),
+            Self::Sunset => czc.tzeis(&Minutes(0.0)),
+            Self::Time(time) => Some(time),
+            _ => None,
+        }
+    }
+
+    /// Converts sunrise and sunset to times, for comparisons
+    fn comparable(&self, czc: &ComplexZmanimCalendar) -> Option<Self> {
+        match self {
+            Self::Sunrise | Self::Sunset => Some(Self::Time(self.clone().into_time(czc)?)),
+            value => Some(value.clone()),
+        }
+    }
+}
+
+fn binary(czc: &ComplexZmanimCalendar, op: Op, lhs: Value, rhs: Value) -> Option<Value> {
+    use Value::{Degrees as Deg, Duration, Number, Sunrise, Sunset, Time};
+    Some(match (op, lhs, rhs) {
+        (Op::Subtract, Sunrise, Deg(degrees)) => Time(czc.alos(&Degrees(degrees))?),
+        (Op::Add, Sunrise, Deg(degrees)) => Time(czc.alos(&Degrees(-degrees))?),
+        (Op::Add, Sunset, Deg(degrees)) => Time(czc.tzeis(&Degrees(degrees))?),
+        (Op::Subtract, Sunset, Deg(degrees)) => Time(czc.tzeis(&Degrees(-degrees))?),
+        (Op::Add, Number(a), Number(b)) => Number(a + b),
+        (Op::Subtract, Number(a), Number(b)) => Number(a - b),
+        (Op::Add, Deg(a), Deg(b)) => Deg(a + b),
+        (Op::Subtract, Deg(a), Deg(b)) => Deg(a - b),
+        (Op::Add, Duration(a), Duration(b)) => Duration(a.checked_add(b)?),
+        (Op::Subtract, Duration(a), Duration(b)) => Duration(a.checked_sub(b)?),
+        (Op::Add, Duration(duration), time) | (Op::Add, time, Duration(duration)) => {
+            Time(time.into_time(czc)?.checked_add(duration).ok()?)
+        }
+        (Op::Subtract, time, Duration(duration)) => {
+            Time(time.into_time(czc)?.checked_sub(duration).ok()?)
+        }
+        (Op::Subtract, a, b) => Duration(a.into_time(czc)?.duration_since(&b.into_time(czc)?)),
+        (Op::Multiply, Number(a), Number(b)) => Number(a * b),
+        (Op::Multiply, Number(a), Duration(b)) | (Op::Multiply, Duration(b), Number(a)) => {
+            Duration(scale(b, a)?)
+        }
+        (Op::Multiply, Number(a), Deg(b)) | (Op::Multiply, Deg(b), Number(a)) => Deg(a * b),
+        (Op::Divide, Number(a), Number(b)) => Number(a / b),
+        (Op::Divide, Duration(a), Number(b)) => Duration(scale(a, 1.0 / b)?),
+        (Op::Divide, Duration(a), Duration(b)) => Number(a.as_secs_f64() / b.as_secs_f64()),
+        (Op::Divide, Deg(a), Number(b)) => Deg(a / b),
+        _ => return None,
+    })
+}
+
+/// Returns `duration` multiplied by `factor`, or [`None`] if the result is
+/// not a valid duration (such as after dividing by zero)
+fn scale(duration: SignedDuration, factor: f64) -> Option<SignedDuration> {
+    SignedDuration::try_from_secs_f64(duration.as_secs_f64() * factor).ok()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Token {
+    Number(f64),
+    Quantity(f64, Unit),
+    Name(String),
+    Symbol(char),
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Unit {
+    Degrees,
+    Minutes,
+    MinutesZmaniyos,
+}
+
+/// Splits `source` into tokens, each with its byte offset
+pub(super) fn tokenize(source: &str) -> Result<Vec<(usize, Token)>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '#' {
+            while chars.next_if(|&(_, c)| c != '\n').is_some() {}
+        } else if source[start..].starts_with("..") {
+            // the `..` between the start and end of a day
+            chars.nth(1);
+            tokens.push((start, Token::Symbol('.')));
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut end = start;
+            while let Some((i, c)) = chars.next_if(|&(_, c)| c.is_ascii_digit() || c == '.') {
+                end = i + c.len_utf8();
+            }
+            let number = source[start..end]
+                .parse()
+                .map_err(|_| ExpressionError::Syntax(start, "invalid number"))?;
+            let unit_start = end;
+            while let Some((i, c)) = chars.next_if(|&(_, c)| c.is_ascii_alphabetic()) {
+                end = i + c.len_utf8();
+            }
+            tokens.push((
+                start,
+                match &source[unit_start..end] {
+                    "" => Token::Number(number),
+                    "deg" => Token::Quantity(number, Unit::Degrees),
+                    "min" => Token::Quantity(number, Unit::Minutes),
+                    "zmanis" => Token::Quantity(number, Unit::MinutesZmaniyos),
+                    _ => {
+                        return Err(ExpressionError::Syntax(
+                            unit_start,
+                            "expected a unit of deg, min, or zmanis",
+                        ));
+                    }
+                },
+            ));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let mut end = start;
+            while let Some((i, c)) = chars.next_if(|&(_, c)| c.is_ascii_alphanumeric() || c == '_')
+            {
+                end = i + c.len_utf8();
+            }
+            tokens.push((start, Token::Name(source[start..end].to_string())));
+        } else if "+-*/(),=;".contains(c) {
+            chars.next();
+            tokens.push((start, Token::Symbol(c)));
+        } else {
+            return Err(ExpressionError::Syntax(start, "unexpected character"));
+        }
+    }
+    tokens.push((source.len(), Token::End));
+    Ok(tokens)
+}
+
+/// A recursive descent parser, which checks types as it goes
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+    definitions: Vec<Definition>,
+    /// How many calls to [`factor`](Self::factor) are in progress
+    depth: usize,
+}
+
+impl Parser {
+    fn new(source: &str) -> Result<Self, ExpressionError> {
+        Ok(Self {
+            tokens: tokenize(source)?,
+            next: 0,
+            definitions: Vec::new(),
+            depth: 0,
+        })
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.next].1
+    }
+
+    fn position(&self) -> usize {
+        self.tokens[self.next].0
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.next].1.clone();
+        if token != Token::End {
+            self.next += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        let found = *self.peek() == Token::Symbol(symbol);
+        if found {
+            self.next += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, symbol: char, message: &'static str) -> Result<(), ExpressionError> {
+        if self.eat(symbol) {
+            Ok(())
+        } else {
+            Err(ExpressionError::Syntax(self.position(), message))
+        }
+    }
+
+    fn definitions(mut self) -> Result<CustomZmanim, ExpressionError> {
+        while *self.peek() != Token::End {
+            if self.eat(';') {
+                continue;
+            }
+            let position = self.position();
+            let Token::Name(name) = self.advance() else {
+                return Err(ExpressionError::Syntax(position, "expected a name"));
+            };
+            if self.definitions.iter().any(|d| d.name == name)
+                || BUILT_IN_VALUES.contains(&name.as_str())
+                || find_zman(&name).is_some()
+            {
+                return Err(ExpressionError::Redefined(position, name));
+            }
+            self.expect('=', "expected =")?;
+            let expr_position = self.position();
+            let Parsed { expr, ty, height } = self.expression()?;
+            let kind = match ty {
+                Type::Event | Type::Time => ZmanKind::Time,
+                Type::Duration => ZmanKind::Duration,
+                Type::Number | Type::Degrees => {
+                    return Err(ExpressionError::TypeMismatch(
+                        expr_position,
+                        "a zman must be a time or a duration",
+                    ));
+                }
+            };
+            self.definitions.push(Definition {
+                name,
+                kind,
+                expr,
+                height,
+            });
+            if *self.peek() != Token::End {
+                self.expect(';', "expected ; or an operator")?;
+            }
+        }
+        Ok(CustomZmanim {
+            definitions: self.definitions.into(),
+        })
+    }
+
+    /// Returns an error if an expression of `height` at `position` is nested
+    /// too deeply
+    fn check_height(position: usize, height: usize) -> Result<usize, ExpressionError> {
+        if height > MAX_HEIGHT {
+            return Err(ExpressionError::Syntax(
+                position,
+                "the expression is nested too deeply",
+            ));
+        }
+        Ok(height)
+    }
+
+    /// Combines `lhs` and `rhs` with `op` at `position`
+    fn binary(
+        position: usize,
+        op: Op,
+        lhs: Parsed,
+        rhs: Parsed,
+    ) -> Result<Parsed, ExpressionError> {
+        let ty = binary_type(op, lhs.ty, rhs.ty)
+            .map_err(|message| ExpressionError::TypeMismatch(position, message))?;
+        Ok(Parsed {
+            height: Self::check_height(position, lhs.height.max(rhs.height) + 1)?,
+            expr: Expr::Binary(op, Box::new(lhs.expr), Box::new(rhs.expr)),
+            ty,
+        })
+    }
+
+    fn expression(&mut self) -> Result<Parsed, ExpressionError> {
+        let mut lhs = self.term()?;
+        loop {
+            let position = self.position();
+            let op = if self.eat('+') {
+                Op::Add
+            } else if self.eat('-') {
+                Op::Subtract
+            } else {
+                return Ok(lhs);
+            };
+            let rhs = self.term()?;
+            lhs = Self::binary(position, op, lhs, rhs)?;
+        }
+    }
+
+    fn term(&mut self) -> Result<Parsed, ExpressionError> {
+        let mut lhs = self.factor()?;
+        loop {
+            let position = self.position();
+            let op = if self.eat('*') {
+                Op::Multiply
+            } else if self.eat('/') {
+                Op::Divide
+            } else {
+                return Ok(lhs);
+            };
+            let rhs = self.factor()?;
+            lhs = Self::binary(position, op, lhs, rhs)?;
+        }
+    }
+
+    fn factor(&mut self) -> Result<Parsed, ExpressionError> {
+        let position = self.position();
+        // the parser recurses once per unclosed parenthesis, negation, or
+        // call, so stop before the stack overflows
+        self.depth += 1;
+        Self::check_height(position, self.depth)?;
+        let parsed = self.factor_inner(position);
+        self.depth -= 1;
+        parsed
+    }
+
+    fn factor_inner(&mut self, position: usize) -> Result<Parsed, ExpressionError> {
+        match self.advance() {
+            Token::Number(number) => Ok(Parsed::leaf(Expr::Number(number), Type::Number)),
+            Token::Quantity(number, Unit::Degrees) => {
+                Ok(Parsed::leaf(Expr::Degrees(number), Type::Degrees))
+            }
+            Token::Quantity(number, Unit::Minutes) => {
+                Ok(Parsed::leaf(Expr::Minutes(number), Type::Duration))
+            }
+            Token::Quantity(number, Unit::MinutesZmaniyos) => {
+                Ok(Parsed::leaf(Expr::MinutesZmaniyos(number), Type::Duration))
+            }
+            Token::Symbol('-') => {
+                let inner = self.factor()?;
+                if inner.ty.is_time() {
+                    return Err(ExpressionError::TypeMismatch(
+                        position,
+                        "cannot negate a time",
+                    ));
+                }
+                Ok(Parsed {
+                    height: Self::check_height(position, inner.height + 1)?,
+                    expr: Expr::Negate(Box::new(inner.expr)),
+                    ty: inner.ty,
+                })
+            }
+            Token::Symbol('(') => {
+                let inner = self.expression()?;
+                self.expect(')', "expected )")?;
+                Ok(inner)
+            }
+            Token::Name(name) => {
+                if let Some(function) = Function::from_name(&name)
+                    && *self.peek() == Token::Symbol('(')
+                {
+                    return self.call(position, function);
+                }
+                self.name(position, name)
+            }
+            _ => Err(ExpressionError::Syntax(position, "expected a value")),
+        }
+    }
+
+    fn name(&self, position: usize, name: String) -> Result<Parsed, ExpressionError> {
+        if let Some(index) = self.definitions.iter().position(|d| d.name == name) {
+            let definition = &self.definitions[index];
+            let ty = match definition.kind {
+                ZmanKind::Time => Type::Time,
+                ZmanKind::Duration => Type::Duration,
+                ZmanKind::Number => Type::Number,
+            };
+            return Ok(Parsed {
+                expr: Expr::Definition(index),
+                ty,
+                height: Self::check_height(position, definition.height + 1)?,
+            });
+        }
+        match name.as_str() {
+            "sunrise" => Ok(Parsed::leaf(Expr::Sunrise, Type::Event)),
+            "sunset" => Ok(Parsed::leaf(Expr::Sunset, Type::Event)),
+            "noon" => Ok(Parsed::leaf(Expr::Noon, Type::Time)),
+            "midnight" => Ok(Parsed::leaf(Expr::Midnight, Type::Time)),
+            _ => match find_zman(&name) {
+                Some(entry) => Ok(Parsed::leaf(
+                    Expr::Registry(entry),
+                    match entry.kind {
+                        ZmanKind::Time => Type::Time,
+                        ZmanKind::Duration => Type::Duration,
+                        ZmanKind::Number => Type::Number,
+                    },
+                )),
+                None => Err(ExpressionError::UnknownName(position, name)),
+            },
+        }
+    }
+
+    fn call(&mut self, position: usize, function: Function) -> Result<Parsed, ExpressionError> {
+        self.expect('(', "expected (")?;
+        let mut args = Vec::new();
+        let mut types = Vec::new();
+        let mut height = 0;
+        loop {
+            let arg = self.expression()?;
+            args.push(arg.expr);
+            types.push(arg.ty);
+            height = height.max(arg.height);
+            if !self.eat(',') {
+                break;
+            }
+        }
+        self.expect(')', "expected , or )")?;
+        let ty = match (function, types.as_slice()) {
+            (Function::Alos | Function::Tzeis, [Type::Degrees | Type::Duration]) => Type::Time,
+            (Function::Shaah, [a, b]) if a.is_time() && b.is_time() => Type::Duration,
+            (Function::Min | Function::Max, [a, b]) if a.is_time() && b.is_time() => Type::Time,
+            (Function::Min | Function::Max, [a, b])
+                if a == b && matches!(a, Type::Duration | Type::Number) =>
+            {
+                *a
+            }
+            (Function::Alos | Function::Tzeis, _) => {
+                return Err(ExpressionError::TypeMismatch(
+                    position,
+                    "expected one offset in degrees or minutes",
+                ));
+            }
+            (Function::Shaah, _) => {
+                return Err(ExpressionError::TypeMismatch(
+                    position,
+                    "expected the start and end of the day",
+                ));
+            }
+            (Function::Min | Function::Max, _) => {
+                return Err(ExpressionError::TypeMismatch(
+                    position,
+                    "expected two times, durations, or numbers",
+                ));
+            }
+        };
+        Ok(Parsed {
+            expr: Expr::Call(function, args),
+            ty,
+            height: Self::check_height(position, height + 1)?,
+        })
+    }
+}
@@ -10,7 +10,21 @@ mod doc_macros;
 
 mod cache;
 mod czc_struct;
+mod day_definition;
+mod expression;
+mod parameterized;
+mod profile;
 mod registry;
+mod rounding;
+mod table;
+mod uncertainty;
 
 pub use czc_struct::*;
+pub use day_definition::*;
+pub use expression::*;
+pub use parameterized::*;
+pub use profile::*;
 pub use registry::*;
+pub use rounding::*;
+pub use table::*;
+pub use uncertainty::*;
@@ -0,0 +1,644 @@
+//! *Zmanim* that take arguments, such as [*alos*](super::ComplexZmanimCalendar::alos)
+//! with an offset, in [`PARAMETERIZED_ZMANIM`], and [`ZmanSpec`] for naming
+//! any registry *zman* as text, such as `tzeis(8.5deg)`.
+
+use core::fmt;
+use std::str::FromStr;
+
+use super::{
+    ComplexZmanimCalendar, CustomZmanim, DayDefinition, EntryDay, EntryOffset, ExpressionError,
+    ZmanCategory, ZmanEntry, ZmanKind, ZmanValue,
+    expression::{Token, Unit, tokenize},
+    find_zman, registry_field,
+};
+use crate::zmanim_calculator::ZmanOffset;
+
+/// The type of a parameter of a [`ParameterizedZmanEntry`], and how its
+/// argument is written in a [`ZmanSpec`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParamKind {
+    /// An offset from sunrise or sunset, as an [`EntryOffset`]: degrees below
+    /// the horizon (`16.1deg`), clock minutes (`72min`), or minutes *zmaniyos*
+    /// of the GRA's *shaah zmanis* (`90zmanis`)
+    Offset,
+    /// Degrees below the horizon (`16.1deg`, or just `16.1`)
+    Degrees,
+    /// Clock minutes (`18min`, or just `18`)
+    Minutes,
+    /// A number, such as a number of *shaos zmaniyos* (`3`)
+    Number,
+    /// Whether to calculate from sunset rather than sunrise, written `sunrise`
+    /// or `sunset`
+    SunriseOrSunset,
+    /// A [`DayDefinition`] from an offset before sunrise to an offset after
+    /// sunset, as an [`EntryDay`], written `alos(16.1deg)..tzeis(7.083deg)`,
+    /// where either end can also be `sunrise` or `sunset`
+    Day,
+}
+
+/// A parameter of a [`ParameterizedZmanEntry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZmanParam {
+    /// The name of the method's parameter, e.g. `"offset"`
+    pub name: &'static str,
+    /// The type of the parameter
+    pub kind: ParamKind,
+}
+
+/// An argument of a [`ParameterizedZmanEntry`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParamValue {
+    /// The argument of a [`ParamKind::Offset`] parameter
+    Offset(EntryOffset),
+    /// The argument of a [`ParamKind::Degrees`], [`ParamKind::Minutes`], or
+    /// [`ParamKind::Number`] parameter
+    Number(f64),
+    /// The argument of a [`ParamKind::SunriseOrSunset`] parameter: `true` for
+    /// sunset
+    Sunset(bool),
+    /// The argument of a [`ParamKind::Day`] parameter
+    Day(EntryDay),
+}
+
+impl ParamValue {
+    fn offset(&self, czc: &ComplexZmanimCalendar) -> Option<ZmanOffset> {
+        match self {
+            Self::Offset(offset) => offset.zman_offset(czc),
+            _ => None,
+        }
+    }
+
+    fn number(&self) -> Option<f64> {
+        match self {
+            Self::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    fn sunset(&self) -> Option<bool> {
+        match self {
+            Self::Sunset(sunset) => Some(*sunset),
+            _ => None,
+        }
+    }
+
+    fn day(&self, czc: &ComplexZmanimCalendar) -> Option<DayDefinition> {
+        match self {
+            Self::Day(day) => day.day_definition(czc),
+            _ => None,
+        }
+    }
+
+    /// Writes the argument the way [`ZmanSpec::parse`] reads it as a `kind`
+    fn write(&self, kind: ParamKind, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self, kind) {
+            (Self::Offset(EntryOffset::Degrees(degrees)), _)
+            | (Self::Number(degrees), ParamKind::Degrees) => write!(f, "{degrees}deg"),
+            (Self::Offset(EntryOffset::Minutes(minutes)), _)
+            | (Self::Number(minutes), ParamKind::Minutes) => write!(f, "{minutes}min"),
+            (Self::Offset(EntryOffset::MinutesZmaniyos(minutes)), _) => {
+                write!(f, "{minutes}zmanis")
+            }
+            (Self::Number(number), _) => write!(f, "{number}"),
+            (Self::Sunset(true), _) => f.write_str("sunset"),
+            (Self::Sunset(false), _) => f.write_str("sunrise"),
+            (Self::Day(day), _) => {
+                write_day_end(f, "alos", "sunrise", day.start)?;
+                f.write_str("..")?;
+                write_day_end(f, "tzeis", "sunset", day.end)
+            }
+        }
+    }
+}
+
+/// Writes the start or end of a [`ParamValue::Day`] as `event_name` if it is
+/// at sunrise or sunset, and otherwise as `offset_name(offset)`
+fn write_day_end(
+    f: &mut fmt::Formatter<'_>,
+    offset_name: &str,
+    event_name: &str,
+    offset: EntryOffset,
+) -> fmt::Result {
+    if offset == EntryOffset::Minutes(0.0) {
+        return f.write_str(event_name);
+    }
+    write!(f, "{offset_name}(")?;
+    ParamValue::Offset(offset).write(ParamKind::Offset, f)?;
+    f.write_str(")")
+}
+
+/// A single entry in the [`PARAMETERIZED_ZMANIM`] registry, describing one
+/// *zman* accessor that takes arguments, like [`ZmanEntry`] does for those
+/// that don't.
+#[derive(Debug, Clone, Copy)]
+pub struct ParameterizedZmanEntry {
+    /// The name of the accessor method, e.g. `"tzeis"`.
+    pub name: &'static str,
+    /// Whether this *zman* is an instant, a duration, or a number.
+    pub kind: ZmanKind,
+    /// The parameters of the method, in order.
+    pub params: &'static [ZmanParam],
+    /// Computes the *zman* for the given calendar and arguments, returning
+    /// [`None`] when the underlying solar event does not occur (e.g. in polar
+    /// regions) or when the arguments don't match [`params`](Self::params).
+    pub compute: fn(&ComplexZmanimCalendar, &[ParamValue]) -> Option<ZmanValue>,
+    /// The group of *zmanim* this one belongs to, or [`None`] for candle
+    /// lighting, the times a number of *shaos zmaniyos* into the day or night,
+    /// and numbers.
+    pub category: Option<ZmanCategory>,
+    /// The first sentence of the accessor's documentation, in Markdown.
+    pub summary: &'static str,
+}
+
+/// Looks up a [`ParameterizedZmanEntry`] by its exact method name.
+///
+/// Returns [`None`] if no parameterized *zman* has that name.
+#[must_use]
+pub fn find_parameterized_zman(name: &str) -> Option<&'static ParameterizedZmanEntry> {
+    PARAMETERIZED_ZMANIM.iter().find(|entry| entry.name == name)
+}
+
+/// Converts an argument to the type of a method parameter of `$kind`
+macro_rules! param_arg {
+    (Offset, $czc:ident, $value:ident) => {
+        &$value.offset($czc)?
+    };
+    (SunriseOrSunset, $czc:ident, $value:ident) => {
+        $value.sunset()?
+    };
+    (Day, $czc:ident, $value:ident) => {
+        &$value.day($czc)?
+    };
+    ($kind:ident, $czc:ident, $value:ident) => {
+        $value.number()?
+    };
+}
+
+/// Expands a list of method signatures into the [`PARAMETERIZED_ZMANIM`]
+/// table, like `zman_registry!`. Each `compute` function calls the method of
+/// the same name with the arguments converted to its parameter types.
+macro_rules! parameterized_zmanim {
+    ( $(
+        $(#[doc = $doc:literal])+
+        $name:ident($($param:ident: $param_kind:ident),+) -> $kind:ident
+        $(, category = $category:ident)?;
+    )* ) => {
+        /// Every *zman* accessor on [`ComplexZmanimCalendar`] that takes
+        /// offsets, degrees, minutes, numbers, or [`DayDefinition`]s, in the
+        /// order of the accessor definitions.
+        pub const PARAMETERIZED_ZMANIM: &[ParameterizedZmanEntry] = &[
+            $(
+                ParameterizedZmanEntry {
+                    name: stringify!($name),
+                    kind: ZmanKind::$kind,
+                    params: &[$(ZmanParam {
+                        name: stringify!($param),
+                        kind: ParamKind::$param_kind,
+                    }),+],
+                    compute: |czc, args| {
+                        let [$($param),+] = args else {
+                            return None;
+                        };
+                        czc.$name($(param_arg!($param_kind, czc, $param)),+)
+                            .map(ZmanValue::$kind)
+                    },
+                    category: registry_field!(None $(, Some(ZmanCategory::$category))?),
+                    summary: concat!($($doc),+).trim_ascii_start(),
+                },
+            )*
+        ];
+    };
+}
+
+parameterized_zmanim! {
+    /// Returns *alos hashachar* (dawn) based on either declination of the sun
+    /// below the horizon, a fixed time offset, or a minutes *zmaniyos*
+    /// (temporal minutes) offset before sunrise
+    alos(offset: Offset) -> Time, category = Alos;
+    /// Returns candle lighting time, `minutes` before sea level sunset
+    candle_lighting(minutes: Minutes) -> Time;
+    /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+    /// morning) according to the opinion of the *Magen Avraham* (MGA) based on
+    /// *alos* and *tzeis* being given offset from sunrise and sunset,
+    /// respectively
+    sof_zman_shema_mga(offset: Offset) -> Time, category = Shema;
+    /// Returns the latest *zman tefila* (time to recite *shacharis* in the
+    /// morning) according to the opinion of the *Magen Avraham* (MGA) based on
+    /// *alos* and *tzeis* being the given offset from sunrise and sunset,
+    /// respectively
+    sof_zman_tefila_mga(offset: Offset) -> Time, category = Tefila;
+    /// Returns the latest *zman biur chametz* (the latest time for burning
+    /// *chametz* on *Erev Pesach*) according to the opinion of the *Magen
+    /// Avraham* (MGA) based on *alos* and *tzeis* being the given offset from
+    /// sunrise and sunset, respectively
+    sof_zman_biur_chametz_mga(offset: Offset) -> Time, category = BiurChametz;
+    /// Returns *chatzos halayla* at the end of the day calculated as halfway
+    /// between *tzeis* and the next day's *alos*, both using the given offset
+    chatzos_halayla_tzeis_to_alos(offset: Offset) -> Time, category = Chatzos;
+    /// Returns a nighttime *shaah zmanis* (temporal hour) at the end of the
+    /// day, based on a night from *tzeis* to the next day's *alos* using the
+    /// given offset
+    shaah_zmanis_halayla_tzeis_to_alos(offset: Offset) -> Duration, category = ShaahZmanis;
+    /// Returns the time `shaos` nighttime *shaos zmaniyos* after sunset, in
+    /// the night at the end of the day
+    shaos_into_night(shaos: Number) -> Time;
+    /// Returns the time `shaos` nighttime *shaos zmaniyos* after *tzeis*, in
+    /// the night from *tzeis* to the next day's *alos* using the given offset
+    /// at the end of the day
+    shaos_into_night_tzeis_to_alos(offset: Offset, shaos: Number) -> Time;
+    /// Returns *mincha gedola* according to the opinion of the *Magen Avraham*
+    /// (MGA) based on *alos* and *tzeis* being the given offset from sunrise
+    /// and sunset, respectively
+    mincha_gedola_mga(offset: Offset) -> Time, category = Mincha;
+    /// Returns the later of *mincha gedola* according to the MGA (based on
+    /// *alos* and *tzeis* being the given offset from sunrise and sunset,
+    /// respectively) and `mincha_gedola_30_minutes`
+    mincha_gedola_mga_greater_than_30_minutes(offset: Offset) -> Time, category = Mincha;
+    /// Returns *samuch lemincha ketana* according to the opinion of the *Magen
+    /// Avraham* (MGA) based on *alos* and *tzeis* being the given offset from
+    /// sunrise and sunset, respectively
+    samuch_lemincha_ketana_mga(offset: Offset) -> Time, category = Mincha;
+    /// Returns *mincha ketana* according to the opinion of the *Magen Avraham*
+    /// (MGA) based on *alos* and *tzeis* being the given offset from sunrise
+    /// and sunset, respectively
+    mincha_ketana_mga(offset: Offset) -> Time, category = Mincha;
+    /// Returns *plag hamincha* according to the opinion of the *Magen Avraham*
+    /// (MGA) based on *alos* and *tzeis* being the given offset from sunrise
+    /// and sunset, respectively
+    plag_mga(offset: Offset) -> Time, category = Plag;
+    /// Returns *tzeis* (nightfall) based on either declination of the sun
+    /// below the horizon, a fixed time offset, or a minutes *zmaniyos*
+    /// (temporal minutes) offset after sunset
+    tzeis(offset: Offset) -> Time, category = Tzeis;
+    /// Returns *shaah zmanis* (temporal hour) according to the opinion of the
+    /// *Magen Avraham* (MGA) based on *alos* and *tzeis* being the given offset
+    /// from sunrise and sunset, respectively
+    shaah_zmanis_mga(offset: Offset) -> Duration, category = ShaahZmanis;
+    /// Returns a *shaah zmanis* (temporal hour) of `day`, which is split into
+    /// 12 equal parts with each one being a *shaah zmanis*
+    shaah_zmanis(day: Day) -> Duration, category = ShaahZmanis;
+    /// Returns the time `shaos` *shaos zmaniyos* after the start of `day`
+    shaos_into_day(day: Day, shaos: Number) -> Time;
+    /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+    /// morning), 3 *shaos zmaniyos* after the start of `day`
+    sof_zman_shema(day: Day) -> Time, category = Shema;
+    /// Returns the latest *zman tefila* (time to recite *shacharis* in the
+    /// morning), 4 *shaos zmaniyos* after the start of `day`
+    sof_zman_tefila(day: Day) -> Time, category = Tefila;
+    /// Returns the latest time for burning *chametz* on *Erev Pesach*, 5
+    /// *shaos zmaniyos* after the start of `day`
+    sof_zman_biur_chametz(day: Day) -> Time, category = BiurChametz;
+    /// Returns *chatzos* (midday) calculated as halfway through `day`
+    chatzos_as_half_day(day: Day) -> Time, category = Chatzos;
+    /// Returns *mincha gedola*, 6.5 *shaos zmaniyos* after the start of `day`
+    mincha_gedola(day: Day) -> Time, category = Mincha;
+    /// Returns *samuch lemincha ketana*, 9 *shaos zmaniyos* after the start of
+    /// `day`
+    samuch_lemincha_ketana(day: Day) -> Time, category = Mincha;
+    /// Returns *mincha ketana*, 9.5 *shaos zmaniyos* after the start of `day`
+    mincha_ketana(day: Day) -> Time, category = Mincha;
+    /// Returns *plag hamincha*, 10.75 *shaos zmaniyos* after the start of
+    /// `day`
+    plag(day: Day) -> Time, category = Plag;
+    /// Returns the percentage of a *shaah zmanis* after sunset (when `sunset`
+    /// is true) or before sunrise (when `sunset` is false) for a given degrees
+    /// offset below the horizon
+    percent_of_shaah_zmanis_from_degrees(degrees: Degrees, sunset: SunriseOrSunset) -> Number;
+    /// Returns the degrees below the horizon equivalent to `offset` before sea
+    /// level sunrise (or after sea level sunset when `sunset` is true) at this
+    /// location and date: the sun's geometric depression at that time
+    degrees_for_offset(offset: Offset, sunset: SunriseOrSunset) -> Number;
+    /// Returns the clock minutes before sea level sunrise (or after sea level
+    /// sunset when `sunset` is true) at which the sun is `degrees` below the
+    /// horizon at this location and date
+    minutes_for_degrees(degrees: Degrees, sunset: SunriseOrSunset) -> Number;
+    /// Returns the minutes *zmaniyos* (of a sea level sunrise to sunset day)
+    /// before sea level sunrise (or after sea level sunset when `sunset` is
+    /// true) at which the sun is `degrees` below the horizon at this location
+    /// and date
+    minutes_zmaniyos_for_degrees(degrees: Degrees, sunset: SunriseOrSunset) -> Number;
+}
+
+/// Any *zman* in the [registry](super::ALL_ZMANIM), or a
+/// [parameterized](PARAMETERIZED_ZMANIM) *zman* with its arguments, named as
+/// text, such as in a command line argument or a configuration file.
+///
+/// A *zman* is written as its method name, with the arguments of a
+/// parameterized *zman* in parentheses. Offsets are in degrees (`16.1deg`),
+/// clock minutes (`72min`), or minutes *zmaniyos* of the GRA's *shaah
+/// zmanis* (`90zmanis`), *zmanim* that can be calculated from sunrise or
+/// sunset take `sunrise` or `sunset`, and the [`DayDefinition`] *zmanim* take a
+/// day such as `alos(16.1deg)..tzeis(7.083deg)` (see [`ParamKind`]):
+///
+/// ```rust
+/// # use rust_zmanim::prelude::*;
+/// let tzeis: ZmanSpec = "tzeis(8.5deg)".parse().unwrap();
+/// assert_eq!(tzeis.name(), "tzeis");
+/// assert_eq!(tzeis.kind(), ZmanKind::Time);
+///
+/// let plag = ZmanSpec::parse("plag_mga(90zmanis)").unwrap();
+/// let shema = ZmanSpec::parse("sof_zman_shema(alos(16.1deg)..tzeis(7.083deg))").unwrap();
+/// let noon = ZmanSpec::parse("chatzos_as_half_day(sunrise..sunset)").unwrap();
+/// let shema = ZmanSpec::parse("sof_zman_shema_gra").unwrap();
+/// let percent = ZmanSpec::parse("percent_of_shaah_zmanis_from_degrees(16.1deg, sunset)");
+/// assert_eq!(percent.unwrap().kind(), ZmanKind::Number);
+///
+/// assert!(ZmanSpec::parse("tzeis(3)").is_err());
+/// ```
+///
+/// It is [displayed](fmt::Display) the same way. With the `serde` feature, it
+/// is serialized as this text.
+///
+/// A spec can also name a [custom *zman*](CustomZmanim), parsed with
+/// [`CustomZmanim::parse_spec`]. It is displayed (and serialized) as its name,
+/// which [`ZmanSpec::parse`] doesn't know, so read it back with
+/// `parse_spec`.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub struct ZmanSpec {
+    entry: SpecEntry,
+    args: Vec<ParamValue>,
+}
+
+#[derive(Debug, Clone)]
+enum SpecEntry {
+    Registry(&'static ZmanEntry),
+    Parameterized(&'static ParameterizedZmanEntry),
+    /// The definition at the index in the custom *zmanim*
+    Custom(CustomZmanim, usize),
+}
+
+impl ZmanSpec {
+    /// Parses a *zman* name, with arguments if it is a parameterized *zman*.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpressionError::UnknownName`] if there is no such *zman*,
+    /// [`ExpressionError::TypeMismatch`] if an argument is not of the type of
+    /// its parameter, and [`ExpressionError::Syntax`] for any other error,
+    /// including the wrong number of arguments.
+    pub fn parse(source: &str) -> Result<Self, ExpressionError> {
+        let mut tokens = tokenize(source)?.into_iter().peekable();
+        let Some((position, Token::Name(name))) = tokens.next() else {
+            return Err(ExpressionError::Syntax(0, "expected the name of a zman"));
+        };
+        let spec = match tokens.next_if(|(_, token)| *token == Token::Symbol('(')) {
+            None => match (find_zman(&name), find_parameterized_zman(&name)) {
+                (Some(entry), _) => Self::from(entry),
+                (None, Some(_)) => {
+                    return Err(ExpressionError::Syntax(source.len(), "expected arguments"));
+                }
+                (None, None) => return Err(ExpressionError::UnknownName(position, name)),
+            },
+            Some((open, _)) => {
+                let Some(entry) = find_parameterized_zman(&name) else {
+                    return Err(match find_zman(&name) {
+                        Some(_) => ExpressionError::Syntax(open, "expected no arguments"),
+                        None => ExpressionError::UnknownName(position, name),
+                    });
+                };
+                let mut args = Vec::with_capacity(entry.params.len());
+                for (index, param) in entry.params.iter().enumerate() {
+                    if index > 0 {
+                        expect(&mut tokens, ',', "wrong number of arguments")?;
+                    }
+                    args.push(argument(&mut tokens, param.kind)?);
+                }
+                expect(&mut tokens, ')', "wrong number of arguments")?;
+                Self {
+                    entry: SpecEntry::Parameterized(entry),
+                    args,
+                }
+            }
+        };
+        match tokens.next() {
+            Some((_, Token::End)) | None => Ok(spec),
+            Some((position, _)) => Err(ExpressionError::Syntax(position, "expected the end")),
+        }
+    }
+
+    /// A spec for the custom *zman* defined at `index`
+    pub(super) fn custom(custom: CustomZmanim, index: usize) -> Self {
+        Self {
+            entry: SpecEntry::Custom(custom, index),
+            args: Vec::new(),
+        }
+    }
+
+    /// The name of the accessor method or custom *zman*, e.g. `"tzeis"`
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match &self.entry {
+            SpecEntry::Registry(entry) => entry.name,
+            SpecEntry::Parameterized(entry) => entry.name,
+            SpecEntry::Custom(custom, index) => custom.name_at(*index),
+        }
+    }
+
+    /// Whether this *zman* is an instant, a duration, or a number
+    #[must_use]
+    pub fn kind(&self) -> ZmanKind {
+        match &self.entry {
+            SpecEntry::Registry(entry) => entry.kind,
+            SpecEntry::Parameterized(entry) => entry.kind,
+            SpecEntry::Custom(custom, index) => custom.kind_at(*index),
+        }
+    }
+
+    /// The group of *zmanim* this one belongs to, if any. Custom *zmanim* have
+    /// none
+    #[must_use]
+    pub fn category(&self) -> Option<ZmanCategory> {
+        match &self.entry {
+            SpecEntry::Registry(entry) => Some(entry.category),
+            SpecEntry::Parameterized(entry) => entry.category,
+            SpecEntry::Custom(..) => None,
+        }
+    }
+
+    /// The arguments of a parameterized *zman*, or nothing for a registry or
+    /// custom *zman*
+    #[must_use]
+    pub fn args(&self) -> &[ParamValue] {
+        &self.args
+    }
+
+    /// Computes the *zman* for `czc`, returning [`None`] when the underlying
+    /// solar event does not occur (e.g. in polar regions)
+    #[must_use]
+    pub fn compute(&self, czc: &ComplexZmanimCalendar) -> Option<ZmanValue> {
+        match &self.entry {
+            SpecEntry::Registry(entry) => (entry.compute)(czc),
+            SpecEntry::Parameterized(entry) => (entry.compute)(czc, &self.args),
+            SpecEntry::Custom(custom, index) => custom.compute_definition(czc, *index),
+        }
+    }
+}
+
+type Tokens = core::iter::Peekable<std::vec::IntoIter<(usize, Token)>>;
+
+fn expect(tokens: &mut Tokens, symbol: char, message: &'static str) -> Result<(), ExpressionError> {
+    match tokens.next() {
+        Some((_, Token::Symbol(c))) if c == symbol => Ok(()),
+        Some((position, _)) => Err(ExpressionError::Syntax(position, message)),
+        None => Err(ExpressionError::Syntax(0, message)),
+    }
+}
+
+/// Parses an argument for a parameter of `kind`
+fn argument(tokens: &mut Tokens, kind: ParamKind) -> Result<ParamValue, ExpressionError> {
+    let negate = tokens
+        .next_if(|(_, token)| *token == Token::Symbol('-'))
+        .is_some();
+    let sign = if negate { -1.0 } else { 1.0 };
+    let (position, token) = tokens.next().unwrap_or((0, Token::End));
+    let value = match (kind, token) {
+        (ParamKind::Offset, Token::Quantity(number, unit)) => ParamValue::Offset(match unit {
+            Unit::Degrees => EntryOffset::Degrees(sign * number),
+            Unit::Minutes => EntryOffset::Minutes(sign * number),
+            Unit::MinutesZmaniyos => EntryOffset::MinutesZmaniyos(sign * number),
+        }),
+        (ParamKind::Degrees, Token::Quantity(number, Unit::Degrees) | Token::Number(number))
+        | (ParamKind::Minutes, Token::Quantity(number, Unit::Minutes) | Token::Number(number))
+        | (ParamKind::Number, Token::Number(number)) => ParamValue::Number(sign * number),
+        (ParamKind::SunriseOrSunset, Token::Name(name)) if !negate && name == "sunrise" => {
+            ParamValue::Sunset(false)
+        }
+        (ParamKind::SunriseOrSunset, Token::Name(name)) if !negate && name == "sunset" => {
+            ParamValue::Sunset(true)
+        }
+        (ParamKind::Day, Token::Name(name)) if !negate => {
+            let start = day_end(tokens, position, &name, "alos", "sunrise")?;
+            expect(
+                tokens,
+                '.',
+                "expected .. between the start and end of the day",
+            )?;
+            let (position, end) = match tokens.next() {
+                Some((position, Token::Name(name))) => (position, name),
+                Some((position, _)) => (position, String::new()),
+                None => (0, String::new()),
+            };
+            let end = day_end(tokens, position, &end, "tzeis", "sunset")?;
+            ParamValue::Day(EntryDay { start, end })
+        }
+        (_, Token::Symbol(',' | ')') | Token::End) => {
+            return Err(ExpressionError::Syntax(
+                position,
+                "wrong number of arguments",
+            ));
+        }
+        (kind, _) => {
+            return Err(ExpressionError::TypeMismatch(
+                position,
+                match kind {
+                    ParamKind::Offset => "expected an offset in deg, min, or zmanis",
+                    ParamKind::Degrees => "expected degrees",
+                    ParamKind::Minutes => "expected minutes",
+                    ParamKind::Number => "expected a number",
+                    ParamKind::SunriseOrSunset => "expected sunrise or sunset",
+                    ParamKind::Day => "expected a day such as alos(16.1deg)..tzeis(8.5deg)",
+                },
+            ));
+        }
+    };
+    Ok(value)
+}
+
+/// Parses the rest of the start (`alos`, `sunrise`) or end (`tzeis`,
+/// `sunset`) of a day, the `name` at `position` having been read
+fn day_end(
+    tokens: &mut Tokens,
+    position: usize,
+    name: &str,
+    offset_name: &str,
+    event_name: &str,
+) -> Result<EntryOffset, ExpressionError> {
+    if name == event_name {
+        return Ok(EntryOffset::Minutes(0.0));
+    }
+    if name != offset_name {
+        return Err(ExpressionError::TypeMismatch(
+            position,
+            if offset_name == "alos" {
+                "expected alos(offset) or sunrise"
+            } else {
+                "expected tzeis(offset) or sunset"
+            },
+        ));
+    }
+    expect(tokens, '(', "expected an offset in parentheses")?;
+    let offset = match argument(tokens, ParamKind::Offset)? {
+        ParamValue::Offset(offset) => offset,
+        _ => {
+            return Err(ExpressionError::TypeMismatch(
+                position,
+                "expected an offset",
+            ));
+        }
+    };
+    expect(tokens, ')', "expected an offset in parentheses")?;
+    Ok(offset)
+}
+
+impl From<&'static ZmanEntry> for ZmanSpec {
+    fn from(entry: &'static ZmanEntry) -> Self {
+        Self {
+            entry: SpecEntry::Registry(entry),
+            args: Vec::new(),
+        }
+    }
+}
+
+impl FromStr for ZmanSpec {
+    type Err = ExpressionError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+impl TryFrom<String> for ZmanSpec {
+    type Error = ExpressionError;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        Self::parse(&source)
+    }
+}
+
+impl From<ZmanSpec> for String {
+    fn from(spec: ZmanSpec) -> Self {
+        spec.to_string()
+    }
+}
+
+/// Formats the way it is [parsed](ZmanSpec::parse), such as `tzeis(8.5deg)`
+impl fmt::Display for ZmanSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())?;
+        let SpecEntry::Parameterized(entry) = self.entry else {
+            return Ok(());
+        };
+        f.write_str("(")?;
+        for (index, (arg, param)) in self.args.iter().zip(entry.params).enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            arg.write(param.kind, f)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Two specs are equal if they name the same *zman* with the same arguments
+impl PartialEq for ZmanSpec {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.args == other.args
+    }
+}
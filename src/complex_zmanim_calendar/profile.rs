@@ -0,0 +1,302 @@
+//! Minhag profiles: a community's choice of *zmanim*, by [registry](super::ALL_ZMANIM)
+//! name, for producing its daily schedule with
+//! [`ComplexZmanimCalendar::schedule`](super::ComplexZmanimCalendar::schedule).
+//!
+//! The built-in profiles ([`MinhagProfile::BUILT_IN`]) are common choices for
+//! each community, as a starting point. **Check every choice with your
+//! community's *rav* before publishing *zmanim* from a profile.**
+
+use std::borrow::Cow;
+
+use jiff::Zoned;
+
+use super::{Rounding, UseElevation, ZmanBound};
+
+/// One of the *zmanim* in a [`MinhagProfile`]'s daily schedule, in the order
+/// they occur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProfileItem {
+    /// *Alos hashachar* (dawn)
+    Alos,
+    /// *Misheyakir*, the earliest time for *tallis* and *tefillin*
+    Misheyakir,
+    /// *Hanetz* (sunrise)
+    Hanetz,
+    /// *Sof zman krias shema*
+    SofZmanShema,
+    /// *Sof zman tefila*
+    SofZmanTefila,
+    /// *Chatzos* (midday)
+    Chatzos,
+    /// *Mincha gedola*
+    MinchaGedola,
+    /// *Mincha ketana*
+    MinchaKetana,
+    /// *Plag hamincha*
+    Plag,
+    /// Candle lighting, [`candle_lighting_minutes`](MinhagProfile::candle_lighting_minutes)
+    /// before sea level sunset
+    CandleLighting,
+    /// *Shkia* (sunset)
+    Shkia,
+    /// *Tzeis* (nightfall)
+    Tzeis,
+}
+
+impl ProfileItem {
+    /// Every item, in the order they occur
+    pub const ALL: [Self; 12] = [
+        Self::Alos,
+        Self::Misheyakir,
+        Self::Hanetz,
+        Self::SofZmanShema,
+        Self::SofZmanTefila,
+        Self::Chatzos,
+        Self::MinchaGedola,
+        Self::MinchaKetana,
+        Self::Plag,
+        Self::CandleLighting,
+        Self::Shkia,
+        Self::Tzeis,
+    ];
+
+    /// Whether the item is the earliest or latest time for something, for
+    /// [rounding](Rounding), or [`None`] for *chatzos* and *plag hamincha*,
+    /// which are used both ways
+    #[must_use]
+    pub const fn bound(self) -> Option<ZmanBound> {
+        match self {
+            Self::Alos
+            | Self::Misheyakir
+            | Self::Hanetz
+            | Self::MinchaGedola
+            | Self::MinchaKetana
+            | Self::Tzeis => Some(ZmanBound::Earliest),
+            Self::SofZmanShema | Self::SofZmanTefila | Self::CandleLighting | Self::Shkia => {
+                Some(ZmanBound::Latest)
+            }
+            Self::Chatzos | Self::Plag => None,
+        }
+    }
+
+    /// A short `snake_case` name for the item, such as `"sof_zman_shema"`
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Alos => "alos",
+            Self::Misheyakir => "misheyakir",
+            Self::Hanetz => "hanetz",
+            Self::SofZmanShema => "sof_zman_shema",
+            Self::SofZmanTefila => "sof_zman_tefila",
+            Self::Chatzos => "chatzos",
+            Self::MinchaGedola => "mincha_gedola",
+            Self::MinchaKetana => "mincha_ketana",
+            Self::Plag => "plag",
+            Self::CandleLighting => "candle_lighting",
+            Self::Shkia => "shkia",
+            Self::Tzeis => "tzeis",
+        }
+    }
+}
+
+/// A community's choice of *zmanim*: which [registry](super::ALL_ZMANIM) entry
+/// to use for each [`ProfileItem`], how many minutes before sunset to light
+/// candles, when to use elevation, and how to round.
+///
+/// The *zmanim* are registry names (such as `"sof_zman_shema_mga_72_minutes"`)
+/// rather than methods, so a profile can be stored and loaded as data.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinhagProfile {
+    /// The name of the profile, such as `"lakewood_ashkenaz"`
+    pub name: Cow<'static, str>,
+    /// *Alos hashachar* (dawn)
+    pub alos: Cow<'static, str>,
+    /// *Misheyakir*
+    pub misheyakir: Cow<'static, str>,
+    /// *Hanetz* (sunrise)
+    pub hanetz: Cow<'static, str>,
+    /// *Sof zman krias shema*
+    pub sof_zman_shema: Cow<'static, str>,
+    /// *Sof zman tefila*
+    pub sof_zman_tefila: Cow<'static, str>,
+    /// *Chatzos* (midday)
+    pub chatzos: Cow<'static, str>,
+    /// *Mincha gedola*
+    pub mincha_gedola: Cow<'static, str>,
+    /// *Mincha ketana*
+    pub mincha_ketana: Cow<'static, str>,
+    /// *Plag hamincha*
+    pub plag: Cow<'static, str>,
+    /// Minutes before sea level sunset to light candles
+    pub candle_lighting_minutes: f64,
+    /// *Shkia* (sunset)
+    pub shkia: Cow<'static, str>,
+    /// *Tzeis* (nightfall)
+    pub tzeis: Cow<'static, str>,
+    /// When to use elevation; the schedule is calculated with this setting,
+    /// whatever the calendar's own setting is
+    pub use_elevation: UseElevation,
+    /// How the times in the schedule are rounded
+    pub rounding: Rounding,
+}
+
+impl MinhagProfile {
+    /// A common Lakewood, NJ Ashkenazi schedule: *alos* 72 minutes, *sof zman
+    /// krias shema* of the MGA, *tefila* and *mincha* of the GRA, and *tzeis*
+    /// 8.5&deg;
+    pub const LAKEWOOD_ASHKENAZ: Self = Self {
+        name: Cow::Borrowed("lakewood_ashkenaz"),
+        alos: Cow::Borrowed("alos_72_minutes"),
+        misheyakir: Cow::Borrowed("misheyakir_11_5_degrees"),
+        hanetz: Cow::Borrowed("hanetz"),
+        sof_zman_shema: Cow::Borrowed("sof_zman_shema_mga_72_minutes"),
+        sof_zman_tefila: Cow::Borrowed("sof_zman_tefila_gra"),
+        chatzos: Cow::Borrowed("chatzos_hayom"),
+        mincha_gedola: Cow::Borrowed("mincha_gedola_gra"),
+        mincha_ketana: Cow::Borrowed("mincha_ketana_gra"),
+        plag: Cow::Borrowed("plag_gra"),
+        candle_lighting_minutes: 18.0,
+        shkia: Cow::Borrowed("shkia"),
+        tzeis: Cow::Borrowed("tzeis_geonim_8_5_degrees"),
+        use_elevation: UseElevation::No,
+        rounding: Rounding::LECHUMRA,
+    };
+
+    /// *Chabad*, following the *Baal Hatanya*'s *zmanim* (see
+    /// [`alos_baal_hatanya`](super::ComplexZmanimCalendar::alos_baal_hatanya))
+    pub const CHABAD: Self = Self {
+        name: Cow::Borrowed("chabad"),
+        alos: Cow::Borrowed("alos_baal_hatanya"),
+        misheyakir: Cow::Borrowed("misheyakir_10_2_degrees"),
+        hanetz: Cow::Borrowed("hanetz"),
+        sof_zman_shema: Cow::Borrowed("sof_zman_shema_baal_hatanya"),
+        sof_zman_tefila: Cow::Borrowed("sof_zman_tefila_baal_hatanya"),
+        chatzos: Cow::Borrowed("chatzos_hayom"),
+        mincha_gedola: Cow::Borrowed("mincha_gedola_baal_hatanya"),
+        mincha_ketana: Cow::Borrowed("mincha_ketana_baal_hatanya"),
+        plag: Cow::Borrowed("plag_baal_hatanya"),
+        candle_lighting_minutes: 18.0,
+        shkia: Cow::Borrowed("shkia"),
+        tzeis: Cow::Borrowed("tzeis_baal_hatanya"),
+        use_elevation: UseElevation::No,
+        rounding: Rounding::LECHUMRA,
+    };
+
+    /// A common Jerusalem schedule: *alos* 16.1&deg;, visible (elevation
+    /// adjusted) sunrise and sunset, and candle lighting 40 minutes before
+    /// sunset
+    pub const YERUSHALMI: Self = Self {
+        name: Cow::Borrowed("yerushalmi"),
+        alos: Cow::Borrowed("alos_16_1_degrees"),
+        misheyakir: Cow::Borrowed("misheyakir_11_degrees"),
+        hanetz: Cow::Borrowed("hanetz"),
+        sof_zman_shema: Cow::Borrowed("sof_zman_shema_mga_16_1_degrees"),
+        sof_zman_tefila: Cow::Borrowed("sof_zman_tefila_gra"),
+        chatzos: Cow::Borrowed("chatzos_hayom"),
+        mincha_gedola: Cow::Borrowed("mincha_gedola_gra"),
+        mincha_ketana: Cow::Borrowed("mincha_ketana_gra"),
+        plag: Cow::Borrowed("plag_gra"),
+        candle_lighting_minutes: 40.0,
+        shkia: Cow::Borrowed("shkia"),
+        tzeis: Cow::Borrowed("tzeis_geonim_4_8_degrees"),
+        use_elevation: UseElevation::HanetzShkia,
+        rounding: Rounding::LECHUMRA,
+    };
+
+    /// *Sephardi*, following Rav Ovadia Yosef: *alos* 72 minutes *zmaniyos*,
+    /// *sof zman krias shema* of the MGA with the same *alos*, *misheyakir*
+    /// 9.5&deg; as in the *Edot Hamizrach* calendars, and *tzeis* when the sun
+    /// is 3.7&deg; below the horizon (13.5 clock minutes after sunset in
+    /// Jerusalem around the equinox)
+    pub const SEPHARDI_RAV_OVADIA: Self = Self {
+        name: Cow::Borrowed("sephardi_rav_ovadia"),
+        alos: Cow::Borrowed("alos_72_minutes_zmanis"),
+        misheyakir: Cow::Borrowed("misheyakir_9_5_degrees"),
+        hanetz: Cow::Borrowed("hanetz"),
+        sof_zman_shema: Cow::Borrowed("sof_zman_shema_mga_72_minutes_zmanis"),
+        sof_zman_tefila: Cow::Borrowed("sof_zman_tefila_gra"),
+        chatzos: Cow::Borrowed("chatzos_hayom"),
+        mincha_gedola: Cow::Borrowed("mincha_gedola_gra"),
+        mincha_ketana: Cow::Borrowed("mincha_ketana_gra"),
+        plag: Cow::Borrowed("plag_gra"),
+        candle_lighting_minutes: 20.0,
+        shkia: Cow::Borrowed("shkia"),
+        tzeis: Cow::Borrowed("tzeis_geonim_3_7_degrees"),
+        use_elevation: UseElevation::No,
+        rounding: Rounding::LECHUMRA,
+    };
+
+    /// Every built-in profile
+    pub const BUILT_IN: &[Self] = &[
+        Self::LAKEWOOD_ASHKENAZ,
+        Self::CHABAD,
+        Self::YERUSHALMI,
+        Self::SEPHARDI_RAV_OVADIA,
+    ];
+
+    /// Looks up a built-in profile by its exact name.
+    ///
+    /// Returns [`None`] if no built-in profile has that name.
+    #[must_use]
+    pub fn find(name: &str) -> Option<&'static Self> {
+        Self::BUILT_IN.iter().find(|profile| profile.name == name)
+    }
+
+    /// Returns the registry name of the *zman* this profile uses for `item`,
+    /// or [`None`] for [`ProfileItem::CandleLighting`], which is calculated
+    /// from [`candle_lighting_minutes`](MinhagProfile::candle_lighting_minutes)
+    #[must_use]
+    pub fn zman_name(&self, item: ProfileItem) -> Option<&str> {
+        let name = match item {
+            ProfileItem::Alos => &self.alos,
+            ProfileItem::Misheyakir => &self.misheyakir,
+            ProfileItem::Hanetz => &self.hanetz,
+            ProfileItem::SofZmanShema => &self.sof_zman_shema,
+            ProfileItem::SofZmanTefila => &self.sof_zman_tefila,
+            ProfileItem::Chatzos => &self.chatzos,
+            ProfileItem::MinchaGedola => &self.mincha_gedola,
+            ProfileItem::MinchaKetana => &self.mincha_ketana,
+            ProfileItem::Plag => &self.plag,
+            ProfileItem::CandleLighting => return None,
+            ProfileItem::Shkia => &self.shkia,
+            ProfileItem::Tzeis => &self.tzeis,
+        };
+        Some(name)
+    }
+}
+
+/// One *zman* of a [`MinhagProfile`]'s daily schedule, returned by
+/// [`ComplexZmanimCalendar::schedule`](super::ComplexZmanimCalendar::schedule)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleEntry {
+    /// Which *zman* of the schedule this is
+    pub item: ProfileItem,
+    /// The rounded time, or [`None`] if the *zman* does not occur on this day
+    /// (e.g. in polar regions)
+    pub time: Option<Zoned>,
+}
+
+/// An invalid [`MinhagProfile`], returned by
+/// [`ComplexZmanimCalendar::schedule`](super::ComplexZmanimCalendar::schedule)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProfileError {
+    /// The contained name, used for the contained item, is not the name of a
+    /// time *zman* in the [registry](super::ALL_ZMANIM)
+    UnknownZman(ProfileItem, String),
+}
+
+impl core::fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownZman(item, name) => {
+                write!(f, "{name} (for {}) is not a known zman", item.name())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {}
@@ -8,29 +8,176 @@
 //! methods, a renamed or removed method is a compile error. A companion test
 //! (`tests/registry_completeness.rs`) guards against *forgetting* to list a
 //! method here.
+//!
+//! Each entry also carries metadata for grouping and filtering *zmanim*
+//! without parsing their names: a [`ZmanCategory`], the opinion it follows,
+//! the offset or day it is calculated from, whether it should only be used
+//! *lechumra*, and a one-sentence summary of its documentation.
 
-use super::ComplexZmanimCalendar;
+use super::{ComplexZmanimCalendar, DayAnchor, DayDefinition, DayEvent};
+use crate::zmanim_calculator::ZmanOffset;
 use jiff::{SignedDuration, Zoned};
 
-/// Whether a *zman* is an instant in time or a duration.
+/// Whether a *zman* is an instant in time, a duration, or a number.
 ///
 /// Most *zmanim* are instants ([`Time`](ZmanKind::Time)). The *shaah zmanis*
 /// ("temporal hour") *zmanim* are durations ([`Duration`](ZmanKind::Duration)).
+/// Only [parameterized](super::PARAMETERIZED_ZMANIM) entries such as
+/// `percent_of_shaah_zmanis_from_degrees` are numbers
+/// ([`Number`](ZmanKind::Number)).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ZmanKind {
     /// An instant in time, returned as a [`Zoned`].
     Time,
     /// A length of time, returned as a [`SignedDuration`].
     Duration,
+    /// A number, returned as an [`f64`].
+    Number,
 }
 
-/// The computed value of a *zman*: either an instant or a duration.
+/// The computed value of a *zman*: an instant, a duration, or a number.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ZmanValue {
     /// An instant in time (e.g. sunrise, *tzeis*).
     Time(Zoned),
     /// A length of time (a *shaah zmanis*).
     Duration(SignedDuration),
+    /// A number (such as a percentage of a *shaah zmanis*).
+    Number(f64),
+}
+
+/// The group of *zmanim* that a [`ZmanEntry`] belongs to, in the order they
+/// occur during the day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZmanCategory {
+    /// *Alos hashachar* (dawn)
+    Alos,
+    /// *Misheyakir*, the earliest time for *tallis* and *tefillin*
+    Misheyakir,
+    /// *Hanetz* (sunrise)
+    Hanetz,
+    /// *Sof zman krias shema*
+    Shema,
+    /// *Sof zman tefila*
+    Tefila,
+    /// *Sof zman biur chametz*, the latest time for burning *chametz* on
+    /// *Erev Pesach*
+    BiurChametz,
+    /// *Chatzos* (midday and midnight)
+    Chatzos,
+    /// *Mincha gedola*, *samuch lemincha ketana* and *mincha ketana*
+    Mincha,
+    /// *Plag hamincha*
+    Plag,
+    /// *Shkia* (sunset)
+    Shkia,
+    /// *Bein hashmashos* (twilight)
+    BeinHashmashos,
+    /// *Tzeis* (nightfall)
+    Tzeis,
+    /// A *shaah zmanis* (temporal hour), the only [durations](ZmanKind::Duration)
+    ShaahZmanis,
+}
+
+impl ZmanCategory {
+    /// Every category, in the order they occur
+    pub const ALL: [Self; 13] = [
+        Self::Alos,
+        Self::Misheyakir,
+        Self::Hanetz,
+        Self::Shema,
+        Self::Tefila,
+        Self::BiurChametz,
+        Self::Chatzos,
+        Self::Mincha,
+        Self::Plag,
+        Self::Shkia,
+        Self::BeinHashmashos,
+        Self::Tzeis,
+        Self::ShaahZmanis,
+    ];
+
+    /// A short `snake_case` name for the category, such as `"bein_hashmashos"`
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Alos => "alos",
+            Self::Misheyakir => "misheyakir",
+            Self::Hanetz => "hanetz",
+            Self::Shema => "shema",
+            Self::Tefila => "tefila",
+            Self::BiurChametz => "biur_chametz",
+            Self::Chatzos => "chatzos",
+            Self::Mincha => "mincha",
+            Self::Plag => "plag",
+            Self::Shkia => "shkia",
+            Self::BeinHashmashos => "bein_hashmashos",
+            Self::Tzeis => "tzeis",
+            Self::ShaahZmanis => "shaah_zmanis",
+        }
+    }
+}
+
+/// An offset from sunrise or sunset in a [`ZmanEntry`]'s metadata, or an
+/// argument of a [parameterized](super::PARAMETERIZED_ZMANIM) *zman*.
+///
+/// Like a [`ZmanOffset`], it is before
+/// sunrise and after sunset (a negative offset moves the other way), but
+/// minutes *zmaniyos* are of the GRA's *shaah zmanis* rather than of a given
+/// length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntryOffset {
+    /// Degrees below the horizon
+    Degrees(f64),
+    /// Fixed clock minutes
+    Minutes(f64),
+    /// Minutes *zmaniyos* of the GRA's *shaah zmanis*
+    MinutesZmaniyos(f64),
+}
+
+impl EntryOffset {
+    /// Returns the [`ZmanOffset`] for `czc`, with minutes *zmaniyos* of its
+    /// [GRA *shaah zmanis*](ComplexZmanimCalendar::shaah_zmanis_gra), or
+    /// [`None`] if there is no *shaah zmanis* on this date
+    #[must_use]
+    pub fn zman_offset(self, czc: &ComplexZmanimCalendar) -> Option<ZmanOffset> {
+        Some(match self {
+            Self::Degrees(degrees) => ZmanOffset::Degrees(degrees),
+            Self::Minutes(minutes) => ZmanOffset::Minutes(minutes),
+            Self::MinutesZmaniyos(minutes_zmaniyos) => ZmanOffset::MinutesZmaniyos {
+                minutes_zmaniyos,
+                shaah_zmanis: czc.shaah_zmanis_gra()?,
+            },
+        })
+    }
+}
+
+/// The day that a [`ZmanEntry`]'s *shaos zmaniyos* are calculated from, from
+/// [`start`](EntryDay::start) before sunrise to [`end`](EntryDay::end) after
+/// sunset, such as 72 minutes to 72 minutes for the *Magen Avraham*
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntryDay {
+    /// The start of the day, before sunrise
+    pub start: EntryOffset,
+    /// The end of the day, after sunset
+    pub end: EntryOffset,
+}
+
+impl EntryDay {
+    /// The [`DayDefinition`] of this day for `czc`'s date, or [`None`] if
+    /// there is no *shaah zmanis* for its minutes *zmaniyos* on this date
+    #[must_use]
+    pub fn day_definition(self, czc: &ComplexZmanimCalendar) -> Option<DayDefinition> {
+        Some(DayDefinition::new(
+            DayEvent::new(DayAnchor::Sunrise, self.start.zman_offset(czc)?),
+            DayEvent::new(DayAnchor::Sunset, self.end.zman_offset(czc)?),
+        ))
+    }
 }
 
 /// A single entry in the [`ALL_ZMANIM`] registry, describing one *zman*
@@ -44,6 +191,24 @@ pub struct ZmanEntry {
     /// Computes the *zman* for the given calendar, returning [`None`] when the
     /// underlying solar event does not occur (e.g. in polar regions).
     pub compute: fn(&ComplexZmanimCalendar) -> Option<ZmanValue>,
+    /// The group of *zmanim* this one belongs to.
+    pub category: ZmanCategory,
+    /// The *posek* or opinion this *zman* follows, such as `"GRA"`, `"MGA"`
+    /// or `"Baal Hatanya"`, or [`None`] if it is not attributed to one.
+    pub opinion: Option<&'static str>,
+    /// For *zmanim* at an offset from sunrise or sunset, such as *alos*,
+    /// *misheyakir*, *bein hashmashos* and *tzeis*, the offset.
+    pub offset: Option<EntryOffset>,
+    /// For *zmanim* calculated from *shaos zmaniyos*, the day they are
+    /// calculated from. [`None`] for *zmanim* calculated from half a day, such
+    /// as those that end at fixed local *chatzos*.
+    pub day: Option<EntryDay>,
+    /// Whether the documentation says this *zman* should only be used
+    /// *lechumra* (stringently), such as a *plag hamincha* that can be after
+    /// sunset.
+    pub lechumra: bool,
+    /// The first sentence of the accessor's documentation, in Markdown.
+    pub summary: &'static str,
 }
 
 /// Looks up a [`ZmanEntry`] by its exact method name.
@@ -54,215 +219,954 @@ pub fn find_zman(name: &str) -> Option<&'static ZmanEntry> {
     ALL_ZMANIM.iter().find(|entry| entry.name == name)
 }
 
-/// Expands a grouped list of method names into the [`ALL_ZMANIM`] table. Each
-/// name becomes a [`ZmanEntry`] whose `compute` closure calls the method of the
-/// same name, so a stale name fails to compile.
+/// Returns the value of an optional field of a `zman_registry!` entry, or
+/// `$default` if it was left out.
+macro_rules! registry_field {
+    ($default:expr) => {
+        $default
+    };
+    ($default:expr, $value:expr) => {
+        $value
+    };
+}
+
+pub(super) use registry_field;
+
+/// Expands a grouped list of method names and their metadata into the
+/// [`ALL_ZMANIM`] table. Each name becomes a [`ZmanEntry`] whose `compute`
+/// closure calls the method of the same name, so a stale name fails to
+/// compile. The doc comment of each name is its
+/// [`summary`](ZmanEntry::summary), and the variant names of [`ZmanKind`] and
+/// [`ZmanValue`] are shared.
 macro_rules! zman_registry {
-    ( times: [ $($t:ident),* $(,)? ], durations: [ $($d:ident),* $(,)? ] $(,)? ) => {
+    ( $(
+        $kind:ident: [ $(
+            $(#[doc = $doc:literal])+
+            $name:ident: $category:ident
+            $(, opinion = $opinion:literal)?
+            $(, offset = $offset:ident($offset_value:literal))?
+            $(, day = $start:ident($start_value:literal) to $end:ident($end_value:literal))?
+            $(, lechumra = $lechumra:literal)?;
+        )* ]
+    ),* $(,)? ) => {
         /// Every zero-argument *zman* accessor on [`ComplexZmanimCalendar`].
         ///
         /// Time *zmanim* come first, then duration *zmanim*. Within each group
         /// the order follows the source order of the accessor definitions.
         pub const ALL_ZMANIM: &[ZmanEntry] = &[
-            $(
+            $($(
                 ZmanEntry {
-                    name: stringify!($t),
-                    kind: ZmanKind::Time,
-                    compute: |czc| czc.$t().map(ZmanValue::Time),
+                    name: stringify!($name),
+                    kind: ZmanKind::$kind,
+                    compute: |czc| czc.$name().map(ZmanValue::$kind),
+                    category: ZmanCategory::$category,
+                    opinion: registry_field!(None $(, Some($opinion))?),
+                    offset: registry_field!(None $(, Some(EntryOffset::$offset($offset_value)))?),
+                    day: registry_field!(None $(, Some(EntryDay {
+                        start: EntryOffset::$start($start_value),
+                        end: EntryOffset::$end($end_value),
+                    }))?),
+                    lechumra: registry_field!(false $(, $lechumra)?),
+                    summary: concat!($($doc),+).trim_ascii_start(),
                 },
-            )*
-            $(
-                ZmanEntry {
-                    name: stringify!($d),
-                    kind: ZmanKind::Duration,
-                    compute: |czc| czc.$d().map(ZmanValue::Duration),
-                },
-            )*
+            )*)*
         ];
     };
 }
 
 zman_registry! {
-    times: [
+    Time: [
         // hand-written (Option<Zoned>)
-        sea_level_sunrise,
-        sea_level_sunset,
-        elevation_sunrise,
-        elevation_sunset,
-        hanetz,
-        shkia,
-        chatzos_hayom,
-        chatzos_hayom_as_half_day,
-        chatzos_halayla,
-        mincha_gedola_30_minutes,
-        sof_zman_shema_gra,
-        sof_zman_tefila_gra,
-        sof_zman_biur_chametz_gra,
-        mincha_gedola_gra,
-        mincha_gedola_gra_greater_than_30_minutes,
-        samuch_lemincha_ketana_gra,
-        mincha_ketana_gra,
-        plag_gra,
-        alos_baal_hatanya,
-        hanetz_amiti_baal_hatanya,
-        shkia_amiti_baal_hatanya,
-        sof_zman_shema_baal_hatanya,
-        sof_zman_tefila_baal_hatanya,
-        sof_zman_biur_chametz_baal_hatanya,
-        mincha_gedola_baal_hatanya,
-        mincha_gedola_baal_hatanya_greater_than_30_minutes,
-        mincha_ketana_baal_hatanya,
-        plag_baal_hatanya,
-        tzeis_baal_hatanya,
-        fixed_local_chatzos_hayom,
-        sof_zman_shema_mga_alos_18_to_fixed_local_chatzos,
-        sof_zman_shema_mga_alos_16_1_to_fixed_local_chatzos,
-        sof_zman_shema_mga_90_minutes_to_fixed_local_chatzos,
-        sof_zman_shema_mga_72_minutes_to_fixed_local_chatzos,
-        sof_zman_shema_gra_sunrise_to_fixed_local_chatzos,
-        sof_zman_tefila_gra_sunrise_to_fixed_local_chatzos,
-        mincha_gedola_gra_fixed_local_chatzos_30_minutes,
-        mincha_ketana_gra_fixed_local_chatzos_to_sunset,
-        plag_gra_fixed_local_chatzos_to_sunset,
-        tzeis_50_minutes,
-        mincha_gedola_ahavat_shalom,
-        mincha_ketana_ahavat_shalom,
-        plag_ahavat_shalom,
-        sof_zman_shema_ateret_torah,
-        sof_zman_tefila_ateret_torah,
-        mincha_gedola_ateret_torah,
-        mincha_ketana_ateret_torah,
-        plag_ateret_torah,
-        tzeis_ateret_torah,
-        polar_sunrise_ben_ish_chai,
-        polar_plag_ben_ish_chai,
-        polar_sunset_ben_ish_chai,
-        sof_zman_shema_3_hrs_before_chatzos,
-        sof_zman_tefila_2_hrs_before_chatzos,
-        sof_zman_shema_alos_16_1_to_sunset,
-        plag_alos_16_1_to_sunset,
-        sof_zman_shema_alos_16_1_to_tzeis_7_083,
-        plag_alos_16_1_to_tzeis_7_083,
-        alos_19_degrees,
-        misheyakir_12_85_degrees,
-        misheyakir_11_5_degrees,
-        misheyakir_11_degrees,
-        misheyakir_10_2_degrees,
-        misheyakir_9_5_degrees,
-        misheyakir_7_65_degrees,
-        bein_hashmashos_yereim_18_minutes,
-        bein_hashmashos_yereim_3_05_degrees,
-        bein_hashmashos_yereim_16_875_minutes,
-        bein_hashmashos_yereim_2_8_degrees,
-        bein_hashmashos_yereim_13_5_minutes,
-        bein_hashmashos_yereim_2_1_degrees,
-        bein_hashmashos_rt_13_24_degrees,
-        bein_hashmashos_rt_58_5_minutes,
-        bein_hashmashos_rt_13_5_minutes_before_7_083_degrees,
-        bein_hashmashos_rt_2_stars,
-        tzeis_geonim_3_7_degrees,
-        tzeis_geonim_3_8_degrees,
-        tzeis_geonim_4_42_degrees,
-        tzeis_geonim_4_66_degrees,
-        tzeis_geonim_4_8_degrees,
-        tzeis_geonim_5_95_degrees,
-        tzeis_geonim_6_45_degrees,
-        tzeis_geonim_7_083_degrees,
-        tzeis_geonim_7_67_degrees,
-        tzeis_geonim_8_5_degrees,
-        tzeis_geonim_9_3_degrees,
-        tzeis_geonim_9_75_degrees,
+        /// Returns sea level sunrise
+        sea_level_sunrise: Hanetz;
+        /// Returns sea level sunset
+        sea_level_sunset: Shkia;
+        /// Returns elevation-adjusted sunrise
+        elevation_sunrise: Hanetz;
+        /// Returns elevation-adjusted sunset
+        elevation_sunset: Shkia;
+        /// Returns *hanetz*, or sunrise
+        hanetz: Hanetz;
+        /// Returns *shkia*, or sunset
+        shkia: Shkia;
+        /// Returns Astronomical *chatzos* (noon)
+        chatzos_hayom: Chatzos;
+        /// Returns *chatzos hayom* calculated as halfway between sea level
+        /// sunrise and sea level sunset
+        chatzos_hayom_as_half_day: Chatzos;
+        /// Returns Astronomical *chatzos halayla* **at the end of the day**
+        /// (the last zman of the day returned by the calendar, that may
+        /// actually be after midnight of the day it is being calculated for)
+        chatzos_halayla: Chatzos;
+        /// Returns *chatzos halayla* **at the end of the day** calculated as
+        /// halfway between sunset and the next day's sunrise, as opposed to
+        /// astronomical midnight
+        chatzos_halayla_as_half_night: Chatzos;
+        /// Returns *mincha gedola* calculated as 30 minutes after *chatzos* and
+        /// not 1/2 of a *shaah zmanis* after *chatzos* as calculated by
+        /// `zmanim_calculator::mincha_gedola`
+        mincha_gedola_30_minutes: Mincha;
+        /// Returns the latest *Zman Shema* (time to recite *Shema* in the
+        /// morning) that is 3 *shaos zmaniyos* (solar hours) after sunrise
+        /// according the GRA
+        sof_zman_shema_gra: Shema, opinion = "GRA", day = Minutes(0.0) to Minutes(0.0);
+        /// Returns the latest *zman tefila* (time to recite *shacharis* in the
+        /// morning) that is 4 *shaos zmaniyos* (solar hours) after sunrise
+        /// according GRA
+        sof_zman_tefila_gra: Tefila, opinion = "GRA", day = Minutes(0.0) to Minutes(0.0);
+        /// Returns the latest time for burning *chametz* on *Erev Pesach*
+        /// according to the opinion of the GRA
+        sof_zman_biur_chametz_gra: BiurChametz, opinion = "GRA", day = Minutes(0.0) to Minutes(0.0);
+        /// Returns *mincha gedola* calculated as 6.5 *shaos zmaniyos* (solar
+        /// hours) after sunrise, according to the GRA
+        mincha_gedola_gra: Mincha, opinion = "GRA", day = Minutes(0.0) to Minutes(0.0);
+        /// Returns the later of `mincha_gedola_gra` and
+        /// `mincha_gedola_30_minutes`
+        mincha_gedola_gra_greater_than_30_minutes: Mincha,
+            opinion = "GRA",
+            day = Minutes(0.0) to Minutes(0.0);
+        /// Returns *samuch lemincha ketana* (near *mincha ketana*), that is
+        /// half an hour before *mincha ketana* or is 9 *shaos zmaniyos* (solar
+        /// hours) after sunrise, calculated according to the GRA using a day
+        /// starting at sunrise and ending at sunset
+        samuch_lemincha_ketana_gra: Mincha, opinion = "GRA", day = Minutes(0.0) to Minutes(0.0);
+        /// Returns *mincha ketana* calculated as 9.5 *shaos zmaniyos* (solar
+        /// hours) after sunrise, according to the GRA
+        mincha_ketana_gra: Mincha, opinion = "GRA", day = Minutes(0.0) to Minutes(0.0);
+        /// Returns *plag hamincha* calculated as 10.75 *shaos zmaniyos* (solar
+        /// hours) after sunrise, according to the GRA
+        plag_gra: Plag, opinion = "GRA", day = Minutes(0.0) to Minutes(0.0);
+        /// Returns the *Baal Hatanya*'s *alos* (dawn) calculated as the time
+        /// when the sun is 16.9° below the eastern geometric horizon before
+        /// sunrise
+        alos_baal_hatanya: Alos, opinion = "Baal Hatanya", offset = Degrees(16.9);
+        /// Returns the *Baal Hatanya*'s *hanetz amiti* (sunrise)
+        hanetz_amiti_baal_hatanya: Hanetz, opinion = "Baal Hatanya", offset = Degrees(1.583);
+        /// Returns the *Baal Hatanya*'s *shkiah amiti* (sunset)
+        shkia_amiti_baal_hatanya: Shkia, opinion = "Baal Hatanya", offset = Degrees(1.583);
+        /// Returns the *Baal Hatanya*'s *sof zman krias shema* (latest time to
+        /// recite *Shema* in the morning)
+        sof_zman_shema_baal_hatanya: Shema,
+            opinion = "Baal Hatanya",
+            day = Degrees(1.583) to Degrees(1.583);
+        /// Returns the *Baal Hatanya*'s *sof zman tefila* (latest time to
+        /// recite the morning prayers)
+        sof_zman_tefila_baal_hatanya: Tefila,
+            opinion = "Baal Hatanya",
+            day = Degrees(1.583) to Degrees(1.583);
+        /// Returns the latest time for burning *chametz* on *Erev Pesach*
+        /// according to the opinion of the Baal Hatanya
+        sof_zman_biur_chametz_baal_hatanya: BiurChametz,
+            opinion = "Baal Hatanya",
+            day = Degrees(1.583) to Degrees(1.583);
+        /// Returns the *Baal Hatanya*'s *mincha gedola*
+        mincha_gedola_baal_hatanya: Mincha,
+            opinion = "Baal Hatanya",
+            day = Degrees(1.583) to Degrees(1.583);
+        /// Returns the later of `mincha_gedola_baal_hatanya` and
+        /// `mincha_gedola_30_minutes`
+        mincha_gedola_baal_hatanya_greater_than_30_minutes: Mincha,
+            opinion = "Baal Hatanya",
+            day = Degrees(1.583) to Degrees(1.583);
+        /// Returns the *Baal Hatanya*'s *mincha ketana*
+        mincha_ketana_baal_hatanya: Mincha,
+            opinion = "Baal Hatanya",
+            day = Degrees(1.583) to Degrees(1.583);
+        /// Returns the *Baal Hatanya*'s *plag hamincha*
+        plag_baal_hatanya: Plag, opinion = "Baal Hatanya", day = Degrees(1.583) to Degrees(1.583);
+        /// Returns *tzeis* (nightfall) when the sun is 6° below the western
+        /// geometric horizon (90°) after sunset
+        tzeis_baal_hatanya: Tzeis, opinion = "Baal Hatanya", offset = Degrees(6.0);
+        /// Returns fixed local *chatzos*
+        fixed_local_chatzos_hayom: Chatzos;
+        /// Returns Rav Moshe Feinstein's opinion of the calculation of *sof
+        /// zman krias shema* (latest time to recite *Shema* in the morning)
+        /// according to the opinion of the *Magen Avraham* (MGA) that the day
+        /// is calculated from dawn to nightfall, but calculated using the first
+        /// half of the day only
+        sof_zman_shema_mga_alos_18_to_fixed_local_chatzos: Shema, opinion = "Rav Moshe Feinstein";
+        /// Returns Rav Moshe Feinstein's opinion of the calculation of *sof
+        /// zman krias shema* (latest time to recite *Shema* in the morning)
+        /// according to the opinion of the *Magen Avraham* (MGA) that the day
+        /// is calculated from dawn to nightfall, but calculated using the first
+        /// half of the day only
+        sof_zman_shema_mga_alos_16_1_to_fixed_local_chatzos: Shema, opinion = "Rav Moshe Feinstein";
+        /// Returns Rav Moshe Feinstein's opinion of the calculation of *sof
+        /// zman krias shema* (latest time to recite *Shema* in the morning)
+        /// according to the opinion of the *Magen Avraham* (MGA) that the day
+        /// is calculated from dawn to nightfall, but calculated using the first
+        /// half of the day only
+        sof_zman_shema_mga_90_minutes_to_fixed_local_chatzos: Shema,
+            opinion = "Rav Moshe Feinstein";
+        /// Returns Rav Moshe Feinstein's opinion of the calculation of *sof
+        /// zman krias shema* (latest time to recite *Shema* in the morning)
+        /// according to the opinion of the *Magen Avraham* (MGA) that the day
+        /// is calculated from dawn to nightfall, but calculated using the first
+        /// half of the day only
+        sof_zman_shema_mga_72_minutes_to_fixed_local_chatzos: Shema,
+            opinion = "Rav Moshe Feinstein";
+        /// Returns Rav Moshe Feinstein's opinion of the calculation of *sof
+        /// zman krias shema* (latest time to recite *Shema* in the morning)
+        /// according to the opinion of the GRA that the day is calculated from
+        /// sunrise to sunset, but calculated using the first half of the day
+        /// only
+        sof_zman_shema_gra_sunrise_to_fixed_local_chatzos: Shema, opinion = "Rav Moshe Feinstein";
+        /// Returns Rav Moshe Feinstein's opinion of the calculation of *sof
+        /// zman tefila* (the latest time to recite the morning prayers)
+        /// according to the opinion of the GRA that the day is calculated from
+        /// sunrise to sunset, but calculated using the first half of the day
+        /// only
+        sof_zman_tefila_gra_sunrise_to_fixed_local_chatzos: Tefila, opinion = "Rav Moshe Feinstein";
+        /// Returns Rav Moshe Feinstein's opinion of the calculation of mincha
+        /// gedola, the earliest time one can pray mincha that is 30 minutes
+        /// after fixed local *chatzos*
+        mincha_gedola_gra_fixed_local_chatzos_30_minutes: Mincha, opinion = "Rav Moshe Feinstein";
+        /// Returns Rav Moshe Feinstein's opinion of the calculation of mincha
+        /// ketana (the preferred time to recite the mincha prayers according to
+        /// the opinion of the Rambam and others) calculated according to the
+        /// GRA that is 3.5 *shaos zmaniyos* (solar hours) after fixed local
+        /// *chatzos*
+        mincha_ketana_gra_fixed_local_chatzos_to_sunset: Mincha, opinion = "Rav Moshe Feinstein";
+        /// Returns Rav Moshe Feinstein's opinion of the calculation of plag
+        /// hamincha
+        plag_gra_fixed_local_chatzos_to_sunset: Plag, opinion = "Rav Moshe Feinstein";
+        /// Method to return *tzeis* (dusk) calculated as 50 minutes after
+        /// sunset
+        tzeis_50_minutes: Tzeis, offset = Minutes(50.0);
+        /// Returns the time of *mincha gedola* based on the opinion of Rabbi
+        /// Yaakov Moshe Hillel as published in the luach of the Beis Horaah of
+        /// Yeshivat Chevrat Ahavat Shalom that *mincha gedola* is calculated as
+        /// half a *shaah zmanis* after *chatzos* with *shaos zmaniyos*
+        /// calculated based on a day starting 72 minutes before sunrise (alos
+        /// 16.1°) and ending 13.5 minutes after sunset (*tzeis* 3.7°)
+        mincha_gedola_ahavat_shalom: Mincha,
+            opinion = "Ahavat Shalom",
+            day = Degrees(16.1) to Degrees(3.7);
+        /// Returns the time of *mincha ketana* based on the opinion of Rabbi
+        /// Yaakov Moshe Hillel as published in the luach of the Beis Horaah of
+        /// Yeshivat Chevrat Ahavat Shalom that *mincha ketana* is calculated as
+        /// 2.5 *shaos zmaniyos* before *tzeis* 3.8° with *shaos zmaniyos*
+        /// calculated based on a day starting at *alos* 16.1° and ending at
+        /// *tzeis* 3.8°
+        mincha_ketana_ahavat_shalom: Mincha,
+            opinion = "Ahavat Shalom",
+            day = Degrees(16.1) to Degrees(3.8);
+        /// Returns the time of *plag hamincha* based on the opinion of Rabbi
+        /// Yaakov Moshe Hillel as published in the luach of the Beis Horaah of
+        /// Yeshivat Chevrat Ahavat Shalom that *plag hamincha* is calculated as
+        /// 1.25 *shaos zmaniyos* before *tzeis* 3.8° with *shaos zmaniyos*
+        /// calculated based on a day starting at *alos* 16.1° and ending at
+        /// *tzeis* 3.8°
+        plag_ahavat_shalom: Plag, opinion = "Ahavat Shalom", day = Degrees(16.1) to Degrees(3.8);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) based on the calculation of *Chacham* Yosef Harari-Raful of
+        /// Yeshivat Ateret Torah, that the day starts 1/10th of the day before
+        /// sunrise and is usually calculated as ending 40 minutes after sunset
+        sof_zman_shema_ateret_torah: Shema,
+            opinion = "Ateret Torah",
+            day = MinutesZmaniyos(72.0) to Minutes(40.0);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) based on the calculation of *Chacham* Yosef Harari-Raful of
+        /// Yeshivat Ateret Torah, that the day starts 1/10th of the day before
+        /// sunrise and is usually calculated as ending 40 minutes after sunset
+        sof_zman_tefila_ateret_torah: Tefila,
+            opinion = "Ateret Torah",
+            day = MinutesZmaniyos(72.0) to Minutes(40.0);
+        /// Returns the time of *mincha gedola* based on the calculation of
+        /// *Chacham* Yosef Harari-Raful of Yeshivat Ateret Torah, that the day
+        /// starts 1/10th of the day before sunrise and is usually calculated as
+        /// ending 40 minutes after sunset
+        mincha_gedola_ateret_torah: Mincha,
+            opinion = "Ateret Torah",
+            day = MinutesZmaniyos(72.0) to Minutes(40.0);
+        /// Returns the later of `mincha_gedola_ateret_torah` and
+        /// `mincha_gedola_30_minutes`
+        mincha_gedola_ateret_torah_greater_than_30_minutes: Mincha,
+            opinion = "Ateret Torah",
+            day = MinutesZmaniyos(72.0) to Minutes(40.0);
+        /// Returns the time of *mincha ketana* based on the calculation of
+        /// *Chacham* Yosef Harari-Raful of Yeshivat Ateret Torah, that the day
+        /// starts 1/10th of the day before sunrise and is usually calculated as
+        /// ending 40 minutes after sunset
+        mincha_ketana_ateret_torah: Mincha,
+            opinion = "Ateret Torah",
+            day = MinutesZmaniyos(72.0) to Minutes(40.0);
+        /// Returns the time of *plag hamincha* based on the calculation of
+        /// *Chacham* Yosef Harari-Raful of Yeshivat Ateret Torah, that the day
+        /// starts 1/10th of the day before sunrise and is usually calculated as
+        /// ending 40 minutes after sunset
+        plag_ateret_torah: Plag,
+            opinion = "Ateret Torah",
+            day = MinutesZmaniyos(72.0) to Minutes(40.0);
+        /// Returns *tzeis* calculated as 40 minutes after sunset
+        tzeis_ateret_torah: Tzeis, opinion = "Ateret Torah", offset = Minutes(40.0);
+        /// Returns sunrise calculated as the time when the sun is directly due
+        /// east (azimuth 90°) in Polar regions on days that there is no
+        /// *hanetz*
+        polar_sunrise_ben_ish_chai: Hanetz, opinion = "Ben Ish Chai";
+        /// Returns *Plag Hamincha* in Polar regions on days that there are no
+        /// *hanetz* and *shkia* , calculated 10.75 *shaaos zmaniyos* of a day
+        /// calculated starting at `polar_sunrise_ben_ish_chai` and ending at
+        /// `polar_sunset_ben_ish_chai`
+        polar_plag_ben_ish_chai: Plag, opinion = "Ben Ish Chai";
+        /// Returns sunset calculated as the time when the sun is directly due
+        /// west (azimuth 270°) in Polar regions on days that there is no
+        /// *shkia*
+        polar_sunset_ben_ish_chai: Shkia, opinion = "Ben Ish Chai";
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) calculated as 3 hours (regular clock hours and not *shaos
+        /// zmaniyos*) before *chatzos*
+        sof_zman_shema_3_hrs_before_chatzos: Shema;
+        /// Returns the latest *zman* tefila (time to recite the morning
+        /// prayers) calculated as 2 hours before *chatzos*
+        sof_zman_tefila_2_hrs_before_chatzos: Tefila;
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) based on the opinion that the day starts at *alos* 16.1°
+        /// and ends at sunset
+        sof_zman_shema_alos_16_1_to_sunset: Shema, day = Degrees(16.1) to Minutes(0.0);
+        /// This method should be used *lechumra* only and returns the time of
+        /// *plag hamincha* based on the opinion that the day starts at *alos*
+        /// 16.1° and ends at sunset
+        plag_alos_16_1_to_sunset: Plag, day = Degrees(16.1) to Minutes(0.0), lechumra = true;
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) based on the opinion that the day starts at *alos* 16.1°
+        /// and ends at *tzeis* 7.083°
+        sof_zman_shema_alos_16_1_to_tzeis_7_083: Shema, day = Degrees(16.1) to Degrees(7.083);
+        /// Returns the time of *plag hamincha* based on the opinion that the
+        /// day starts at *alos* 16.1° and ends at *tzeis* 7.083°
+        plag_alos_16_1_to_tzeis_7_083: Plag, day = Degrees(16.1) to Degrees(7.083);
+        /// Returns *alos* (dawn) calculated when the sun is 19° below the
+        /// eastern geometric horizon before sunrise
+        alos_19_degrees: Alos, offset = Degrees(19.0);
+        /// Returns *misheyakir* based on the position of the sun 12.85° below
+        /// geometric zenith (90°)
+        misheyakir_12_85_degrees: Misheyakir, offset = Degrees(12.85);
+        /// Returns *misheyakir* based on the position of the sun when it is
+        /// 11.5° below geometric zenith (90°)
+        misheyakir_11_5_degrees: Misheyakir, offset = Degrees(11.5);
+        /// Returns *misheyakir* based on the position of the sun when it is 11°
+        /// below geometric zenith (90°)
+        misheyakir_11_degrees: Misheyakir, offset = Degrees(11.0);
+        /// Returns *misheyakir* based on the position of the sun when it is
+        /// 10.2° below geometric zenith (90°)
+        misheyakir_10_2_degrees: Misheyakir, offset = Degrees(10.2);
+        /// Returns *misheyakir* based on the position of the sun when it is
+        /// 9.5° below geometric zenith (90°)
+        misheyakir_9_5_degrees: Misheyakir, offset = Degrees(9.5);
+        /// Returns *misheyakir* based on the position of the sun when it is
+        /// 7.65° below geometric zenith (90°)
+        misheyakir_7_65_degrees: Misheyakir, offset = Degrees(7.65);
+        /// Returns the beginning of *bein hashmashos* (twilight) according to
+        /// the Yereim (Rabbi Eliezer of Metz) calculated as 18 minutes or 3/4
+        /// of a 24-minute mil before sunset
+        bein_hashmashos_yereim_18_minutes: BeinHashmashos,
+            opinion = "Yereim",
+            offset = Minutes(-18.0);
+        /// Returns the beginning of *bein hashmashos* (twilight) according to
+        /// the Yereim (Rabbi Eliezer of Metz) calculated as the sun's position
+        /// 3.05° above the horizon around the equinox / equilux, its position
+        /// 18 minutes or 3/4 of an 24-minute mil before sunset
+        bein_hashmashos_yereim_3_05_degrees: BeinHashmashos,
+            opinion = "Yereim",
+            offset = Degrees(-3.05);
+        /// Returns the beginning of *bein hashmashos* (twilight) according to
+        /// the Yereim (Rabbi Eliezer of Metz) calculated as 16.875 minutes or
+        /// 3/4 of a 22.5-minute mil before sunset
+        bein_hashmashos_yereim_16_875_minutes: BeinHashmashos,
+            opinion = "Yereim",
+            offset = Minutes(-16.875);
+        /// Returns the beginning of *bein hashmashos* (twilight) according to
+        /// the Yereim (Rabbi Eliezer of Metz) calculated as the sun's position
+        /// 2.8° above the horizon around the equinox / equilux, its position
+        /// 16.875 minutes or 3/4 of an 18-minute mil before sunset
+        bein_hashmashos_yereim_2_8_degrees: BeinHashmashos,
+            opinion = "Yereim",
+            offset = Degrees(-2.8);
+        /// Returns the beginning of *bein hashmashos* (twilight) according to
+        /// the Yereim (Rabbi Eliezer of Metz) calculated as 13.5 minutes or 3/4
+        /// of an 18-minute mil before sunset
+        bein_hashmashos_yereim_13_5_minutes: BeinHashmashos,
+            opinion = "Yereim",
+            offset = Minutes(-13.5);
+        /// Returns the beginning of *bein hashmashos* according to the Yereim
+        /// (Rabbi Eliezer of Metz) calculated as the sun's position 2.1° above
+        /// the horizon around the equinox / equilux in Yerushalayim, its
+        /// position 13.5 minutes or 3/4 of an 18-minute mil before sunset
+        bein_hashmashos_yereim_2_1_degrees: BeinHashmashos,
+            opinion = "Yereim",
+            offset = Degrees(-2.1);
+        /// Method to return the beginning of *bein hashmashos* of Rabbeinu Tam
+        /// calculated when the sun is 13.24° below the western geometric
+        /// horizon (90°) after sunset
+        bein_hashmashos_rt_13_24_degrees: BeinHashmashos,
+            opinion = "Rabbeinu Tam",
+            offset = Degrees(13.24);
+        /// Returns the beginning of *bein hashmashos* of Rabbeinu Tam
+        /// calculated as a 58.5-minute offset after sunset
+        bein_hashmashos_rt_58_5_minutes: BeinHashmashos,
+            opinion = "Rabbeinu Tam",
+            offset = Minutes(58.5);
+        /// Returns the beginning of *bein hashmashos* based on the calculation
+        /// of 13.5 minutes (3/4 of an 18-minute mil) before *shkiah* calculated
+        /// as 7.083°
+        bein_hashmashos_rt_13_5_minutes_before_7_083_degrees: BeinHashmashos,
+            opinion = "Rabbeinu Tam";
+        /// Returns the beginning of *bein hashmashos* of Rabbeinu Tam
+        /// calculated according to the opinion of the Divrei Yosef (see Yisrael
+        /// Vehazmanim) calculated 5/18th (27.77%) of the time between alos
+        /// (calculated as 19.8° before sunrise) and sunrise
+        bein_hashmashos_rt_2_stars: BeinHashmashos, opinion = "Rabbeinu Tam";
+        /// Returns the *tzeis hakochavim* (nightfall) based on the opinion of
+        /// the *Geonim* calculated at the sun's position at 3.7° below the
+        /// western horizon
+        tzeis_geonim_3_7_degrees: Tzeis, opinion = "Geonim", offset = Degrees(3.7);
+        /// Returns the *tzeis hakochavim* (nightfall) based on the opinion of
+        /// the *Geonim* calculated at the sun's position at 3.8° below the
+        /// western horizon
+        tzeis_geonim_3_8_degrees: Tzeis, opinion = "Geonim", offset = Degrees(3.8);
+        /// Returns the *tzeis hakochavim* (nightfall) based on the opinion of
+        /// the *Geonim* calculated as 3/4 of a *mil*, based on a 22.5-minute
+        /// *mil*, or 16 7/8 minutes
+        tzeis_geonim_4_42_degrees: Tzeis, opinion = "Geonim", offset = Degrees(4.42);
+        /// Returns the *tzeis hakochavim* (nightfall) based on the opinion of
+        /// the *Geonim* calculated as 3/4 of a *mil* based on a 24-minute
+        /// *mil*, or 18 minutes
+        tzeis_geonim_4_66_degrees: Tzeis, opinion = "Geonim", offset = Degrees(4.66);
+        /// Returns the *tzeis* (nightfall) based on the opinion of the *Geonim*
+        /// calculated as 3/4 of a *mil* based on the sun's position at 4.8°
+        /// below the western horizon
+        tzeis_geonim_4_8_degrees: Tzeis, opinion = "Geonim", offset = Degrees(4.8);
+        /// Returns the *tzeis* (nightfall) based on the opinion of the *Geonim*
+        /// calculated at the sun's position at 5.95° below below geometric
+        /// zenith (90°), calculated as the position of the sun 24 minutes after
+        /// sunset in Jerusalem around the equinox / equilux
+        tzeis_geonim_5_95_degrees: Tzeis, opinion = "Geonim", offset = Degrees(5.95);
+        /// Returns the *tzeis* (nightfall) based on the opinion of the *Geonim*
+        /// as calculated by Rabbi Yechiel Michel Tucazinsky
+        tzeis_geonim_6_45_degrees: Tzeis, opinion = "Geonim", offset = Degrees(6.45);
+        /// Returns the *tzeis hakochavim* (nightfall) based on the opinion of
+        /// the *Geonim* calculated when the sun's position 7.083° (or 7° 5′)
+        /// below the western horizon
+        tzeis_geonim_7_083_degrees: Tzeis, opinion = "Geonim", offset = Degrees(7.083);
+        /// Returns *tzeis* (nightfall) based on the opinion of the Geonim
+        /// calculated as 45 minutes after sunset during the summer solstice in
+        /// New York, when the *neshef* (twilight) is the longest
+        tzeis_geonim_7_67_degrees: Tzeis, opinion = "Geonim", offset = Degrees(7.67);
+        /// Returns *tzeis* (nightfall) when the sun is 8.5° below the geometric
+        /// horizon (90°) after sunset, a time that Rabbi Meir Posen in his the
+        /// *Ohr Meir* calculated that 3 small stars are visible, which is later
+        /// than the required 3 medium stars
+        tzeis_geonim_8_5_degrees: Tzeis, opinion = "Geonim", offset = Degrees(8.5);
+        /// Returns the *tzeis* (nightfall) based on the calculations used in
+        /// the *Luach Itim Lebinah* as the stringent time for *tzeis*
+        tzeis_geonim_9_3_degrees: Tzeis, opinion = "Geonim", offset = Degrees(9.3);
+        /// Returns the *tzeis* (nightfall) based on the opinion of the *Geonim*
+        /// calculated as 60 minutes after sunset around the equinox / equilux,
+        /// the day that a solar hour is 60 minutes in New York
+        tzeis_geonim_9_75_degrees: Tzeis, opinion = "Geonim", offset = Degrees(9.75);
         // macro-generated (zmanim_for_offset!)
-        alos_16_1_degrees,
-        tzeis_16_1_degrees,
-        sof_zman_shema_mga_16_1_degrees,
-        sof_zman_tefila_mga_16_1_degrees,
-        sof_zman_biur_chametz_mga_16_1_degrees,
-        mincha_gedola_mga_16_1_degrees,
-        samuch_lemincha_ketana_mga_16_1_degrees,
-        mincha_ketana_mga_16_1_degrees,
-        plag_mga_16_1_degrees,
-        alos_18_degrees,
-        tzeis_18_degrees,
-        sof_zman_shema_mga_18_degrees,
-        sof_zman_tefila_mga_18_degrees,
-        plag_mga_18_degrees,
-        alos_19_8_degrees,
-        tzeis_19_8_degrees,
-        sof_zman_shema_mga_19_8_degrees,
-        sof_zman_tefila_mga_19_8_degrees,
-        plag_mga_19_8_degrees,
-        alos_26_degrees,
-        tzeis_26_degrees,
-        plag_mga_26_degrees,
-        alos_60_minutes,
-        tzeis_60_minutes,
-        plag_mga_60_minutes,
-        alos_72_minutes,
-        tzeis_72_minutes,
-        sof_zman_shema_mga_72_minutes,
-        sof_zman_tefila_mga_72_minutes,
-        sof_zman_biur_chametz_mga_72_minutes,
-        mincha_gedola_mga_72_minutes,
-        samuch_lemincha_ketana_mga_72_minutes,
-        mincha_ketana_mga_72_minutes,
-        plag_mga_72_minutes,
-        alos_72_minutes_zmanis,
-        tzeis_72_minutes_zmanis,
-        sof_zman_shema_mga_72_minutes_zmanis,
-        sof_zman_tefila_mga_72_minutes_zmanis,
-        sof_zman_biur_chametz_mga_72_minutes_zmanis,
-        plag_mga_72_minutes_zmanis,
-        alos_90_minutes,
-        tzeis_90_minutes,
-        sof_zman_shema_mga_90_minutes,
-        sof_zman_tefila_mga_90_minutes,
-        plag_mga_90_minutes,
-        alos_90_minutes_zmanis,
-        tzeis_90_minutes_zmanis,
-        sof_zman_shema_mga_90_minutes_zmanis,
-        sof_zman_tefila_mga_90_minutes_zmanis,
-        plag_mga_90_minutes_zmanis,
-        alos_96_minutes,
-        tzeis_96_minutes,
-        sof_zman_shema_mga_96_minutes,
-        sof_zman_tefila_mga_96_minutes,
-        plag_mga_96_minutes,
-        alos_96_minutes_zmanis,
-        tzeis_96_minutes_zmanis,
-        sof_zman_shema_mga_96_minutes_zmanis,
-        sof_zman_tefila_mga_96_minutes_zmanis,
-        plag_mga_96_minutes_zmanis,
-        alos_120_minutes,
-        tzeis_120_minutes,
-        sof_zman_shema_mga_120_minutes,
-        sof_zman_tefila_mga_120_minutes,
-        plag_mga_120_minutes,
-        alos_120_minutes_zmanis,
-        tzeis_120_minutes_zmanis,
-        plag_mga_120_minutes_zmanis,
+        /// Returns *alos* (dawn) calculated when the sun is 16.1° below the
+        /// eastern geometric horizon before sunrise
+        alos_16_1_degrees: Alos, offset = Degrees(16.1);
+        /// Returns *tzeis* (dusk) calculated when the sun is 16.1° below the
+        /// western geometric horizon after sunset
+        tzeis_16_1_degrees: Tzeis, offset = Degrees(16.1);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 16.1° below the horizon
+        sof_zman_shema_mga_16_1_degrees: Shema,
+            opinion = "MGA",
+            day = Degrees(16.1) to Degrees(16.1);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 16.1° below the horizon
+        sof_zman_tefila_mga_16_1_degrees: Tefila,
+            opinion = "MGA",
+            day = Degrees(16.1) to Degrees(16.1);
+        /// Returns the latest time for burning *chametz* on *Erev Pesach*
+        /// according to the opinion of the *Magen Avraham* (MGA) based on
+        /// *alos* and *tzeis* being 16.1° below the horizon
+        sof_zman_biur_chametz_mga_16_1_degrees: BiurChametz,
+            opinion = "MGA",
+            day = Degrees(16.1) to Degrees(16.1);
+        /// Returns the time of *mincha gedola* (the earliest time to pray
+        /// *mincha* in the afternoon) according to the *Magen Avraham* based on
+        /// *alos* and *tzeis* being 16.1° below the horizon
+        mincha_gedola_mga_16_1_degrees: Mincha,
+            opinion = "MGA",
+            day = Degrees(16.1) to Degrees(16.1);
+        /// Returns the later of *mincha gedola* according to the *Magen
+        /// Avraham* based on *alos* and *tzeis* being 16.1° below the horizon,
+        /// and `mincha_gedola_30_minutes`
+        mincha_gedola_mga_16_1_degrees_greater_than_30_minutes: Mincha,
+            opinion = "MGA",
+            day = Degrees(16.1) to Degrees(16.1);
+        /// Returns the time of *samuch lemincha ketana* (the time that eating
+        /// or other activity can't begin prior to praying *mincha*) according
+        /// to the *Magen Avraham* based on *alos* and *tzeis* being 16.1° below
+        /// the horizon
+        samuch_lemincha_ketana_mga_16_1_degrees: Mincha,
+            opinion = "MGA",
+            day = Degrees(16.1) to Degrees(16.1);
+        /// Returns the time of *mincha ketana* according to the *Magen Avraham*
+        /// based on *alos* and *tzeis* being 16.1° below the horizon
+        mincha_ketana_mga_16_1_degrees: Mincha,
+            opinion = "MGA",
+            day = Degrees(16.1) to Degrees(16.1);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the *Magen Avraham* based on *alos* and
+        /// *tzeis* being 16.1° below the horizon
+        plag_mga_16_1_degrees: Plag,
+            opinion = "MGA",
+            day = Degrees(16.1) to Degrees(16.1),
+            lechumra = true;
+        /// Returns *alos* (dawn) calculated when the sun is 18° below the
+        /// eastern geometric horizon before sunrise
+        alos_18_degrees: Alos, offset = Degrees(18.0);
+        /// Returns *tzeis* (dusk) calculated when the sun is 18° below the
+        /// western geometric horizon after sunset
+        tzeis_18_degrees: Tzeis, offset = Degrees(18.0);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 18° below the horizon
+        sof_zman_shema_mga_18_degrees: Shema, opinion = "MGA", day = Degrees(18.0) to Degrees(18.0);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 18° below the horizon
+        sof_zman_tefila_mga_18_degrees: Tefila,
+            opinion = "MGA",
+            day = Degrees(18.0) to Degrees(18.0);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the *Magen Avraham* based on *alos* and
+        /// *tzeis* being 18° below the horizon
+        plag_mga_18_degrees: Plag,
+            opinion = "MGA",
+            day = Degrees(18.0) to Degrees(18.0),
+            lechumra = true;
+        /// Returns *alos* (dawn) calculated when the sun is 19.8° below the
+        /// eastern geometric horizon before sunrise
+        alos_19_8_degrees: Alos, offset = Degrees(19.8);
+        /// Returns *tzeis* (dusk) calculated when the sun is 19.8° below the
+        /// western geometric horizon after sunset
+        tzeis_19_8_degrees: Tzeis, offset = Degrees(19.8);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 19.8° below the horizon
+        sof_zman_shema_mga_19_8_degrees: Shema,
+            opinion = "MGA",
+            day = Degrees(19.8) to Degrees(19.8);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 19.8° below the horizon
+        sof_zman_tefila_mga_19_8_degrees: Tefila,
+            opinion = "MGA",
+            day = Degrees(19.8) to Degrees(19.8);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the *Magen Avraham* based on *alos* and
+        /// *tzeis* being 19.8° below the horizon
+        plag_mga_19_8_degrees: Plag,
+            opinion = "MGA",
+            day = Degrees(19.8) to Degrees(19.8),
+            lechumra = true;
+        /// This method should only be used *lechumra* and returns *alos* (dawn)
+        /// calculated when the sun is 26° below the eastern geometric horizon
+        /// before sunrise
+        alos_26_degrees: Alos, offset = Degrees(26.0), lechumra = true;
+        /// Returns *tzeis* (dusk) calculated when the sun is 26° below the
+        /// western geometric horizon after sunset
+        tzeis_26_degrees: Tzeis, offset = Degrees(26.0);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the *Magen Avraham* based on *alos* and
+        /// *tzeis* being 26° below the horizon
+        plag_mga_26_degrees: Plag,
+            opinion = "MGA",
+            day = Degrees(26.0) to Degrees(26.0),
+            lechumra = true;
+        /// Returns *alos* (dawn) calculated as 60 minutes before sunrise
+        alos_60_minutes: Alos, offset = Minutes(60.0);
+        /// Returns *tzeis hakochavim* (nightfall) based on the opinion of the
+        /// *Chavas Yair* and *Divrei Malkiel* that the time to walk the
+        /// distance of a mil is 15 minutes, for a total of 60 minutes for 4
+        /// *mil* after sunset
+        tzeis_60_minutes: Tzeis, opinion = "Chavas Yair", offset = Minutes(60.0);
+        /// Returns the time of *plag hamincha* according to the *Magen Avraham*
+        /// with the day starting and ending 60 minutes before and after
+        /// *hanetz* and *shkia*, respectively
+        plag_mga_60_minutes: Plag, opinion = "MGA", day = Minutes(60.0) to Minutes(60.0);
+        /// Returns *alos* (dawn) calculated as 72 minutes before sunrise
+        alos_72_minutes: Alos, offset = Minutes(72.0);
+        /// Returns *tzeis hakochavim* (nightfall) based on the opinion of
+        /// *Rabbeinu Tam* that *tzeis hakochavim* is calculated as 72 minutes
+        /// after sunset, the time it takes to walk 4 *mil* at 18 minutes a mil
+        tzeis_72_minutes: Tzeis, opinion = "Rabbeinu Tam", offset = Minutes(72.0);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 72 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_shema_mga_72_minutes: Shema, opinion = "MGA", day = Minutes(72.0) to Minutes(72.0);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 72 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_tefila_mga_72_minutes: Tefila,
+            opinion = "MGA",
+            day = Minutes(72.0) to Minutes(72.0);
+        /// Returns the latest time for burning *chametz* on *Erev Pesach*
+        /// according to the opinion of the *Magen Avraham* (MGA) based on
+        /// *alos* and *tzeis* being 72 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_biur_chametz_mga_72_minutes: BiurChametz,
+            opinion = "MGA",
+            day = Minutes(72.0) to Minutes(72.0);
+        /// Returns the time of *mincha gedola* (the earliest time to pray
+        /// *mincha* in the afternoon) according to the *Magen Avraham* with the
+        /// day starting and ending 72 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        mincha_gedola_mga_72_minutes: Mincha, opinion = "MGA", day = Minutes(72.0) to Minutes(72.0);
+        /// Returns the later of *mincha gedola* according to the *Magen
+        /// Avraham* with the day starting and ending 72 minutes before and
+        /// after *hanetz* and *shkia*, respectively, and
+        /// `mincha_gedola_30_minutes`
+        mincha_gedola_mga_72_minutes_greater_than_30_minutes: Mincha,
+            opinion = "MGA",
+            day = Minutes(72.0) to Minutes(72.0);
+        /// Returns the time of *samuch lemincha ketana* (the time that eating
+        /// or other activity can't begin prior to praying *mincha*) according
+        /// to the *Magen Avraham* with the day starting and ending 72 minutes
+        /// before and after *hanetz* and *shkia*, respectively
+        samuch_lemincha_ketana_mga_72_minutes: Mincha,
+            opinion = "MGA",
+            day = Minutes(72.0) to Minutes(72.0);
+        /// Returns the time of *mincha ketana* according to the *Magen Avraham*
+        /// with the day starting and ending 72 minutes before and after
+        /// *hanetz* and *shkia*, respectively
+        mincha_ketana_mga_72_minutes: Mincha, opinion = "MGA", day = Minutes(72.0) to Minutes(72.0);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the *Magen Avraham* with the day
+        /// starting and ending 72 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        plag_mga_72_minutes: Plag,
+            opinion = "MGA",
+            day = Minutes(72.0) to Minutes(72.0),
+            lechumra = true;
+        /// Returns *alos* (dawn) calculated as 72 minutes of the GRA's *shaos
+        /// zmaniyos*, or 1/10th of the day before sunrise
+        alos_72_minutes_zmanis: Alos, offset = MinutesZmaniyos(72.0);
+        /// Returns *tzeis hakochavim* (nightfall) calculated as 72 minutes
+        /// *zmaniyos*, or 1/10th of the day after sunset
+        tzeis_72_minutes_zmanis: Tzeis, offset = MinutesZmaniyos(72.0);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 72 minutes of the GRA's *shaos
+        /// zmaniyos*, or 1/10th of the day before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_shema_mga_72_minutes_zmanis: Shema,
+            opinion = "MGA",
+            day = MinutesZmaniyos(72.0) to MinutesZmaniyos(72.0);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 72 minutes of the GRA's *shaos
+        /// zmaniyos*, or 1/10th of the day before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_tefila_mga_72_minutes_zmanis: Tefila,
+            opinion = "MGA",
+            day = MinutesZmaniyos(72.0) to MinutesZmaniyos(72.0);
+        /// Returns the latest time for burning *chametz* on *Erev Pesach*
+        /// according to the opinion of the *Magen Avraham* (MGA) based on
+        /// *alos* and *tzeis* being 72 minutes of the GRA's *shaos zmaniyos*,
+        /// or 1/10th of the day before and after *hanetz* and *shkia*,
+        /// respectively
+        sof_zman_biur_chametz_mga_72_minutes_zmanis: BiurChametz,
+            opinion = "MGA",
+            day = MinutesZmaniyos(72.0) to MinutesZmaniyos(72.0);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the opinion of the *Magen Avraham*
+        /// (MGA) based on *alos* and *tzeis* being 72 minutes of the GRA's
+        /// *shaos zmaniyos*, or 1/10th of the day before and after *hanetz* and
+        /// *shkia*, respectively
+        plag_mga_72_minutes_zmanis: Plag,
+            opinion = "MGA",
+            day = MinutesZmaniyos(72.0) to MinutesZmaniyos(72.0),
+            lechumra = true;
+        /// Returns *alos* (dawn) calculated as 90 minutes before sunrise
+        alos_90_minutes: Alos, offset = Minutes(90.0);
+        /// Returns *tzeis hakochavim* (dusk) calculated as 90 minutes after
+        /// sunset
+        tzeis_90_minutes: Tzeis, offset = Minutes(90.0);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 90 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_shema_mga_90_minutes: Shema, opinion = "MGA", day = Minutes(90.0) to Minutes(90.0);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 90 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_tefila_mga_90_minutes: Tefila,
+            opinion = "MGA",
+            day = Minutes(90.0) to Minutes(90.0);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the *Magen Avraham* with the day
+        /// starting and ending 90 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        plag_mga_90_minutes: Plag,
+            opinion = "MGA",
+            day = Minutes(90.0) to Minutes(90.0),
+            lechumra = true;
+        /// Returns *alos* (dawn) calculated as 90 minutes of the GRA's *shaos
+        /// zmaniyos*, or 1/8th of the day before sunrise
+        alos_90_minutes_zmanis: Alos, offset = MinutesZmaniyos(90.0);
+        /// Returns *tzeis hakochavim* (dusk) calculated as 90 minutes of the
+        /// GRA's *shaos zmaniyos*, or 1/8th of the day after sunset
+        tzeis_90_minutes_zmanis: Tzeis, offset = MinutesZmaniyos(90.0);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 90 minutes of the GRA's *shaos
+        /// zmaniyos*, or 1/8th of the day before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_shema_mga_90_minutes_zmanis: Shema,
+            opinion = "MGA",
+            day = MinutesZmaniyos(90.0) to MinutesZmaniyos(90.0);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 90 minutes of the GRA's *shaos
+        /// zmaniyos*, or 1/8th of the day before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_tefila_mga_90_minutes_zmanis: Tefila,
+            opinion = "MGA",
+            day = MinutesZmaniyos(90.0) to MinutesZmaniyos(90.0);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the opinion of the *Magen Avraham*
+        /// (MGA) based on *alos* and *tzeis* being 90 minutes of the GRA's
+        /// *shaos zmaniyos*, or 1/8th of the day before and after *hanetz* and
+        /// *shkia*, respectively
+        plag_mga_90_minutes_zmanis: Plag,
+            opinion = "MGA",
+            day = MinutesZmaniyos(90.0) to MinutesZmaniyos(90.0),
+            lechumra = true;
+        /// Returns *alos* (dawn) calculated as 96 minutes before sunrise
+        alos_96_minutes: Alos, offset = Minutes(96.0);
+        /// Returns *tzeis* (dusk) calculated as 96 minutes after sunset
+        tzeis_96_minutes: Tzeis, offset = Minutes(96.0);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 96 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_shema_mga_96_minutes: Shema, opinion = "MGA", day = Minutes(96.0) to Minutes(96.0);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 96 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_tefila_mga_96_minutes: Tefila,
+            opinion = "MGA",
+            day = Minutes(96.0) to Minutes(96.0);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the *Magen Avraham* with the day
+        /// starting and ending 96 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        plag_mga_96_minutes: Plag,
+            opinion = "MGA",
+            day = Minutes(96.0) to Minutes(96.0),
+            lechumra = true;
+        /// Returns *alos* (dawn) calculated as 96 minutes of the GRA's *shaos
+        /// zmaniyos*, or 1/7.5th of the day before sunrise
+        alos_96_minutes_zmanis: Alos, offset = MinutesZmaniyos(96.0);
+        /// Returns *tzeis hakochavim* (dusk) calculated as 96 minutes of the
+        /// GRA's *shaos zmaniyos*, or 1/7.5th of the day after sunset
+        tzeis_96_minutes_zmanis: Tzeis, offset = MinutesZmaniyos(96.0);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 96 minutes of the GRA's *shaos
+        /// zmaniyos*, or 1/7.5th of the day before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_shema_mga_96_minutes_zmanis: Shema,
+            opinion = "MGA",
+            day = MinutesZmaniyos(96.0) to MinutesZmaniyos(96.0);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 96 minutes of the GRA's *shaos
+        /// zmaniyos*, or 1/7.5th of the day before and after *hanetz* and
+        /// *shkia*, respectively
+        sof_zman_tefila_mga_96_minutes_zmanis: Tefila,
+            opinion = "MGA",
+            day = MinutesZmaniyos(96.0) to MinutesZmaniyos(96.0);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the opinion of the *Magen Avraham*
+        /// (MGA) based on *alos* and *tzeis* being 96 minutes of the GRA's
+        /// *shaos zmaniyos*, or 1/7.5th of the day before and after *hanetz*
+        /// and *shkia*, respectively
+        plag_mga_96_minutes_zmanis: Plag,
+            opinion = "MGA",
+            day = MinutesZmaniyos(96.0) to MinutesZmaniyos(96.0),
+            lechumra = true;
+        /// This method should be used *lechumra* only and returns *alos* (dawn)
+        /// calculated using 120 minutes before sunrise
+        alos_120_minutes: Alos, offset = Minutes(120.0), lechumra = true;
+        /// Returns *tzeis hakochavim* (dusk) calculated as 120 minutes after
+        /// sunset
+        tzeis_120_minutes: Tzeis, offset = Minutes(120.0);
+        /// Returns the latest *zman krias shema* (time to recite *Shema* in the
+        /// morning) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 120 minutes before and after *hanetz*
+        /// and *shkia*, respectively
+        sof_zman_shema_mga_120_minutes: Shema,
+            opinion = "MGA",
+            day = Minutes(120.0) to Minutes(120.0);
+        /// Returns the latest *zman tefila* (time to recite the morning
+        /// prayers) according to the opinion of the *Magen Avraham* (MGA) based
+        /// on *alos* and *tzeis* being 120 minutes before and after *hanetz*
+        /// and *shkia*, respectively
+        sof_zman_tefila_mga_120_minutes: Tefila,
+            opinion = "MGA",
+            day = Minutes(120.0) to Minutes(120.0);
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the *Magen Avraham* with the day
+        /// starting and ending 120 minutes before and after *hanetz* and
+        /// *shkia*, respectively
+        plag_mga_120_minutes: Plag,
+            opinion = "MGA",
+            day = Minutes(120.0) to Minutes(120.0),
+            lechumra = true;
+        /// This method should be used *lechumra* only and returns *alos* (dawn)
+        /// calculated using 120 minutes *zmaniyos* or 1/6th of the day before
+        /// sunrise
+        alos_120_minutes_zmanis: Alos, offset = MinutesZmaniyos(120.0), lechumra = true;
+        /// This method should be used *lechumra* only and returns *tzeis*
+        /// (dusk) calculated using 120 minutes *zmaniyos* after sunset
+        tzeis_120_minutes_zmanis: Tzeis, offset = MinutesZmaniyos(120.0), lechumra = true;
+        /// This method should only be used *lechumra* and returns the time of
+        /// *plag hamincha* according to the opinion of the *Magen Avraham*
+        /// (MGA) based on *alos* and *tzeis* being 120 minutes of the GRA's
+        /// *shaos zmaniyos*, or 1/6th of the day before and after *hanetz* and
+        /// *shkia*, respectively
+        plag_mga_120_minutes_zmanis: Plag,
+            opinion = "MGA",
+            day = MinutesZmaniyos(120.0) to MinutesZmaniyos(120.0),
+            lechumra = true;
     ],
-    durations: [
+    Duration: [
         // hand-written (Option<SignedDuration>)
-        shaah_zmanis_gra,
-        shaah_zmanis_baal_hatanya,
-        shaah_zmanis_alos_16_1_to_tzeis_3_8,
-        shaah_zmanis_alos_16_1_to_tzeis_3_7,
-        shaah_zmanis_ateret_torah,
-        shaah_zmanis_alos_16_1_to_tzeis_7_083,
+        /// Returns a nighttime *shaah zmanis* (temporal hour) **at the end of
+        /// the day**
+        shaah_zmanis_halayla: ShaahZmanis;
+        /// Returns a *shaah zmanis* according to the opinion of the GRA
+        shaah_zmanis_gra: ShaahZmanis, opinion = "GRA", day = Minutes(0.0) to Minutes(0.0);
+        /// Returns the *Baal Hatanya*'s *shaah zmanis* (temporal hour)
+        shaah_zmanis_baal_hatanya: ShaahZmanis,
+            opinion = "Baal Hatanya",
+            day = Degrees(1.583) to Degrees(1.583);
+        /// Returns a *shaah zmanis* (temporal hour) used by some *zmanim*
+        /// according to the opinion of Rabbi Yaakov Moshe Hillel as published
+        /// in the luach of the Beis Horaah of Yeshivat Chevrat Ahavat Shalom
+        /// that is based on a day starting 72 minutes before sunrise in degrees
+        /// (*alos* 16.1°) and ending 14 minutes after sunset in degrees
+        /// (*tzeis* 3.8°)
+        shaah_zmanis_alos_16_1_to_tzeis_3_8: ShaahZmanis,
+            opinion = "Ahavat Shalom",
+            day = Degrees(16.1) to Degrees(3.8);
+        /// Returns a *shaah zmanis* (temporal hour) used by some *zmanim*
+        /// according to the opinion of Rabbi Yaakov Moshe Hillel as published
+        /// in the luach of the Beis Horaah of Yeshivat Chevrat Ahavat Shalom
+        /// that is based on a day starting 72 minutes before sunrise in degrees
+        /// (*alos* 16.1°) and ending 13.5 minutes after sunset in degrees
+        /// (*tzeis* 3.7°)
+        shaah_zmanis_alos_16_1_to_tzeis_3_7: ShaahZmanis,
+            opinion = "Ahavat Shalom",
+            day = Degrees(16.1) to Degrees(3.7);
+        /// Returns a shaah zmanis (temporal hour) according to the opinion of
+        /// the *Chacham* Yosef Harari-Raful of Yeshivat Ateret Torah calculated
+        /// with *alos* being 1/10th of sunrise to sunset day, or 72 minutes
+        /// *zmaniyos* of such a day before sunrise, and *tzeis* is usually
+        /// calculated as 40 minutes after sunset
+        shaah_zmanis_ateret_torah: ShaahZmanis,
+            opinion = "Ateret Torah",
+            day = MinutesZmaniyos(72.0) to Minutes(40.0);
+        /// Returns a *shaah zmanis* (temporal hour) used by some *zmanim*
+        /// according to some opinions that is based on a day starting at alos
+        /// 16.1° and ending tzais 7.083°
+        shaah_zmanis_alos_16_1_to_tzeis_7_083: ShaahZmanis, day = Degrees(16.1) to Degrees(7.083);
         // macro-generated (zmanim_for_offset!)
-        shaah_zmanis_mga_16_1_degrees,
-        shaah_zmanis_mga_18_degrees,
-        shaah_zmanis_mga_19_8_degrees,
-        shaah_zmanis_mga_26_degrees,
-        shaah_zmanis_mga_60_minutes,
-        shaah_zmanis_mga_72_minutes,
-        shaah_zmanis_mga_72_minutes_zmanis,
-        shaah_zmanis_mga_90_minutes,
-        shaah_zmanis_mga_90_minutes_zmanis,
-        shaah_zmanis_mga_96_minutes,
-        shaah_zmanis_mga_96_minutes_zmanis,
-        shaah_zmanis_mga_120_minutes,
-        shaah_zmanis_mga_120_minutes_zmanis,
-    ]
+        /// Returns a *shaah zmanis* (solar hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 16.1°
+        /// below the horizon
+        shaah_zmanis_mga_16_1_degrees: ShaahZmanis,
+            opinion = "MGA",
+            day = Degrees(16.1) to Degrees(16.1);
+        /// Returns a *shaah zmanis* (solar hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 18°
+        /// below the horizon
+        shaah_zmanis_mga_18_degrees: ShaahZmanis,
+            opinion = "MGA",
+            day = Degrees(18.0) to Degrees(18.0);
+        /// Returns a *shaah zmanis* (solar hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 19.8°
+        /// below the horizon
+        shaah_zmanis_mga_19_8_degrees: ShaahZmanis,
+            opinion = "MGA",
+            day = Degrees(19.8) to Degrees(19.8);
+        /// Returns a *shaah zmanis* (solar hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 26°
+        /// below the horizon
+        shaah_zmanis_mga_26_degrees: ShaahZmanis,
+            opinion = "MGA",
+            day = Degrees(26.0) to Degrees(26.0),
+            lechumra = true;
+        /// Returns a *shaah zmanis* (solar hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 60
+        /// minutes before and after *hanetz* and *shkia*, respectively
+        shaah_zmanis_mga_60_minutes: ShaahZmanis,
+            opinion = "MGA",
+            day = Minutes(60.0) to Minutes(60.0);
+        /// Returns a *shaah zmanis* (solar hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 72
+        /// minutes before and after *hanetz* and *shkia*, respectively
+        shaah_zmanis_mga_72_minutes: ShaahZmanis,
+            opinion = "MGA",
+            day = Minutes(72.0) to Minutes(72.0);
+        /// Returns a *shaah zmanis* (temporal hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 72
+        /// minutes of the GRA's *shaos zmaniyos*, or 1/10th of the day before
+        /// and after *hanetz* and *shkia*, respectively
+        shaah_zmanis_mga_72_minutes_zmanis: ShaahZmanis,
+            opinion = "MGA",
+            day = MinutesZmaniyos(72.0) to MinutesZmaniyos(72.0);
+        /// Returns a *shaah zmanis* (solar hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 90
+        /// minutes before and after *hanetz* and *shkia*, respectively
+        shaah_zmanis_mga_90_minutes: ShaahZmanis,
+            opinion = "MGA",
+            day = Minutes(90.0) to Minutes(90.0);
+        /// Returns a *shaah zmanis* (temporal hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 90
+        /// minutes of the GRA's *shaos zmaniyos*, or 1/8th of the day before
+        /// and after *hanetz* and *shkia*, respectively
+        shaah_zmanis_mga_90_minutes_zmanis: ShaahZmanis,
+            opinion = "MGA",
+            day = MinutesZmaniyos(90.0) to MinutesZmaniyos(90.0);
+        /// Returns a *shaah zmanis* (solar hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 96
+        /// minutes before and after *hanetz* and *shkia*, respectively
+        shaah_zmanis_mga_96_minutes: ShaahZmanis,
+            opinion = "MGA",
+            day = Minutes(96.0) to Minutes(96.0);
+        /// Returns a *shaah zmanis* (temporal hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 96
+        /// minutes of the GRA's *shaos zmaniyos*, or 1/7.5th of the day before
+        /// and after *hanetz* and *shkia*, respectively
+        shaah_zmanis_mga_96_minutes_zmanis: ShaahZmanis,
+            opinion = "MGA",
+            day = MinutesZmaniyos(96.0) to MinutesZmaniyos(96.0);
+        /// Returns a *shaah zmanis* (solar hour) according to the opinion of
+        /// the *Magen Avraham* (MGA) based on *alos* and *tzeis* being 120
+        /// minutes before and after *hanetz* and *shkia*, respectively
+        shaah_zmanis_mga_120_minutes: ShaahZmanis,
+            opinion = "MGA",
+            day = Minutes(120.0) to Minutes(120.0);
+        /// Returns a *shaah zmanis* (temporal hour) calculated using a dip of
+        /// 120 minutes
+        shaah_zmanis_mga_120_minutes_zmanis: ShaahZmanis,
+            opinion = "MGA",
+            day = MinutesZmaniyos(120.0) to MinutesZmaniyos(120.0),
+            lechumra = true;
+    ],
 }
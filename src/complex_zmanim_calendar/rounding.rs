@@ -0,0 +1,132 @@
+//! Rounding *zmanim* for display.
+//!
+//! Printing a [`Zoned`] with `strftime("%H:%M")` truncates the seconds, and
+//! rounding to the nearest minute can move a time up to 30 seconds either way.
+//! Either can show a *tzeis* at 19:52:59 as 19:52, or a *sof zman shema* at
+//! 9:41:40 as 9:42, both of which are too lenient. Which way is safe depends
+//! on the *zman*: the earliest time something may be done (such as *tzeis* or
+//! *mincha gedola*) should be rounded later, and the latest time something may
+//! be done (such as *sof zman shema* or candle lighting) should be rounded
+//! earlier. [`Rounding::Lechumra`] does this, with an optional safety buffer,
+//! using [`ZmanBound`]s from [`ZMAN_BOUNDS`].
+
+use jiff::{RoundMode, SignedDuration, Unit, Zoned, ZonedRound};
+
+/// Whether a *zman* is the earliest or the latest time for something, which
+/// decides the safe direction to round it in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZmanBound {
+    /// The earliest time something may be done (such as *tzeis* or *mincha
+    /// gedola*), rounded later
+    Earliest,
+    /// The latest time something may be done (such as *sof zman shema* or
+    /// candle lighting), rounded earlier
+    Latest,
+}
+
+/// The [`ZmanBound`] of each [registry](super::ALL_ZMANIM) entry, by name
+/// prefix. The first matching prefix applies, so longer prefixes come before
+/// shorter ones that they start with.
+///
+/// *Chatzos* and *plag hamincha* are [`None`], since they are used both as the
+/// earliest and the latest time for different things. The bound of every
+/// registry time is tested against its [`ZmanCategory`](super::ZmanCategory),
+/// so a new entry has to be named under a prefix with the intended bound.
+pub const ZMAN_BOUNDS: &[(&str, Option<ZmanBound>)] = &[
+    ("alos", Some(ZmanBound::Earliest)),
+    ("misheyakir", Some(ZmanBound::Earliest)),
+    ("hanetz", Some(ZmanBound::Earliest)),
+    ("sea_level_sunrise", Some(ZmanBound::Earliest)),
+    ("elevation_sunrise", Some(ZmanBound::Earliest)),
+    ("polar_sunrise", Some(ZmanBound::Earliest)),
+    ("sof_zman", Some(ZmanBound::Latest)),
+    ("chatzos_halayla", Some(ZmanBound::Latest)),
+    ("chatzos", None),
+    ("fixed_local_chatzos", None),
+    ("mincha_gedola", Some(ZmanBound::Earliest)),
+    ("samuch_lemincha_ketana", Some(ZmanBound::Latest)),
+    ("mincha_ketana", Some(ZmanBound::Earliest)),
+    ("plag", None),
+    ("polar_plag", None),
+    ("candle_lighting", Some(ZmanBound::Latest)),
+    ("shkia", Some(ZmanBound::Latest)),
+    ("sea_level_sunset", Some(ZmanBound::Latest)),
+    ("elevation_sunset", Some(ZmanBound::Latest)),
+    ("polar_sunset", Some(ZmanBound::Latest)),
+    ("bein_hashmashos", Some(ZmanBound::Latest)),
+    ("tzeis", Some(ZmanBound::Earliest)),
+];
+
+/// Returns the [`ZmanBound`] of the *zman* named `name` from [`ZMAN_BOUNDS`],
+/// or [`None`] if it is used both ways or is not in the table
+#[must_use]
+pub fn zman_bound(name: &str) -> Option<ZmanBound> {
+    ZMAN_BOUNDS
+        .iter()
+        .find(|(prefix, _)| name.starts_with(prefix))
+        .and_then(|&(_, bound)| bound)
+}
+
+/// How *zmanim* are rounded for display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rounding {
+    /// Don't round (the default)
+    #[default]
+    Exact,
+    /// Round to the nearest minute
+    NearestMinute,
+    /// Round [`Earliest`](ZmanBound::Earliest) *zmanim* up to the next minute
+    /// after adding `buffer`, and [`Latest`](ZmanBound::Latest) *zmanim* down
+    /// to the previous minute after subtracting `buffer`. *Zmanim* that are
+    /// neither are rounded to the nearest minute.
+    Lechumra {
+        /// A safety margin added to the earliest times and subtracted from the
+        /// latest times before rounding
+        buffer: SignedDuration,
+    },
+}
+
+impl Rounding {
+    /// [`Rounding::Lechumra`] with no buffer
+    pub const LECHUMRA: Self = Self::Lechumra {
+        buffer: SignedDuration::ZERO,
+    };
+
+    /// Returns `time` rounded according to this policy, as a *zman* with the
+    /// given `bound`. Returns `time` unchanged if rounding would overflow.
+    #[must_use]
+    pub fn round(self, time: &Zoned, bound: Option<ZmanBound>) -> Zoned {
+        let rounded = match (self, bound) {
+            (Self::Exact, _) => return time.clone(),
+            (Self::NearestMinute, _) | (Self::Lechumra { .. }, None) => time.round(Unit::Minute),
+            (Self::Lechumra { buffer }, Some(ZmanBound::Earliest)) => {
+                round_in(time.checked_add(buffer), RoundMode::Ceil)
+            }
+            (Self::Lechumra { buffer }, Some(ZmanBound::Latest)) => {
+                round_in(time.checked_sub(buffer), RoundMode::Floor)
+            }
+        };
+        rounded.unwrap_or_else(|_| time.clone())
+    }
+
+    /// Returns `time` rounded according to this policy, as the earliest time
+    /// for something
+    #[must_use]
+    pub fn round_earliest(self, time: &Zoned) -> Zoned {
+        self.round(time, Some(ZmanBound::Earliest))
+    }
+
+    /// Returns `time` rounded according to this policy, as the latest time for
+    /// something
+    #[must_use]
+    pub fn round_latest(self, time: &Zoned) -> Zoned {
+        self.round(time, Some(ZmanBound::Latest))
+    }
+}
+
+/// Rounds `time` to the minute with `mode`
+fn round_in(time: Result<Zoned, jiff::Error>, mode: RoundMode) -> Result<Zoned, jiff::Error> {
+    time?.round(ZonedRound::new().smallest(Unit::Minute).mode(mode))
+}
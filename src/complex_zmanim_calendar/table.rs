@@ -0,0 +1,290 @@
+//! Tables of *zmanim* for a range of dates, such as a year's *luach*, with
+//! [`ZmanimTable`].
+
+use std::borrow::Cow;
+
+use jiff::civil::Date;
+
+use super::{
+    ComplexZmanimCalendar, MinhagProfile, ProfileError, ProfileItem, Rounding, TwilightFallback,
+    UseElevation, ZmanBound, ZmanKind, ZmanSpec, ZmanValue, find_zman, zman_bound,
+};
+use crate::util::geolocation::GeoLocation;
+
+/// A table of *zmanim* for one location over a range of dates: one
+/// [`ZmanimRow`] per day, with a column for each [registry](super::ALL_ZMANIM)
+/// entry, [`ZmanSpec`], or [`MinhagProfile`] item added.
+///
+/// The rows are calculated lazily by [`rows`](ZmanimTable::rows) with a single
+/// calendar that is [advanced](ComplexZmanimCalendar::advance_day) from day to
+/// day. Within a row, every column's sunrise, sunset, and degree-based event
+/// shares the date's Julian day and the sun's position at solar noon, which
+/// are calculated once, so each event only refines them. Across rows, the
+/// solar events that columns of night *zmanim* (such as `shaah_zmanis_halayla`)
+/// already needed from the following day are reused for that day's row.
+///
+/// ```
+/// # use jiff::{civil, tz::TimeZone};
+/// # use rust_zmanim::prelude::*;
+/// let location = GeoLocation::new(
+///     31.778,
+///     35.234,
+///     754.0,
+///     TimeZone::get("Asia/Jerusalem").unwrap(),
+/// )
+/// .unwrap();
+/// let table = ZmanimTable::new(location, civil::date(2025, 1, 1), civil::date(2025, 12, 31))
+///     .zmanim([find_zman("hanetz").unwrap(), find_zman("shkia").unwrap()]);
+/// assert_eq!(table.column_names().collect::<Vec<_>>(), ["hanetz", "shkia"]);
+/// assert_eq!(table.rows().count(), 365);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZmanimTable {
+    geo_location: GeoLocation,
+    start: Date,
+    end: Date,
+    use_elevation: UseElevation,
+    twilight_fallback: TwilightFallback,
+    rounding: Rounding,
+    columns: Vec<Column>,
+}
+
+/// One column of a [`ZmanimTable`]
+#[derive(Debug, Clone)]
+struct Column {
+    name: Cow<'static, str>,
+    zman: ColumnZman,
+    bound: Option<ZmanBound>,
+}
+
+#[derive(Debug, Clone)]
+enum ColumnZman {
+    Spec(ZmanSpec),
+    /// Candle lighting this many minutes before sunset
+    CandleLighting(f64),
+}
+
+/// One day of a [`ZmanimTable`]
+#[derive(Debug, Clone)]
+pub struct ZmanimRow {
+    /// The day
+    pub date: Date,
+    /// The *zmanim* of the day, in the order of the table's
+    /// [columns](ZmanimTable::column_names), each [`None`] if it does not occur
+    /// on this day
+    pub values: Vec<Option<ZmanValue>>,
+}
+
+impl ZmanimTable {
+    /// Returns a table for `geo_location` from `start` to `end` (inclusive),
+    /// without elevation, [`TwilightFallback`], or [`Rounding`], and with no
+    /// columns
+    #[must_use]
+    pub fn new(geo_location: GeoLocation, start: Date, end: Date) -> Self {
+        Self {
+            geo_location,
+            start,
+            end,
+            use_elevation: UseElevation::No,
+            twilight_fallback: TwilightFallback::Disabled,
+            rounding: Rounding::Exact,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Sets when to account for elevation. See [`UseElevation`]
+    #[must_use]
+    pub fn use_elevation(mut self, use_elevation: UseElevation) -> Self {
+        self.use_elevation = use_elevation;
+        self
+    }
+
+    /// Sets how degree-based *alos* and *tzeis* are calculated when the sun
+    /// does not get that far below the horizon. See [`TwilightFallback`]
+    #[must_use]
+    pub fn twilight_fallback(mut self, twilight_fallback: TwilightFallback) -> Self {
+        self.twilight_fallback = twilight_fallback;
+        self
+    }
+
+    /// Sets how the times are rounded, using the [`ZmanBound`] of each
+    /// registry entry from [`ZMAN_BOUNDS`](super::ZMAN_BOUNDS). Durations are
+    /// not rounded.
+    #[must_use]
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Adds a column for each of `zmanim`, which are [registry](super::ALL_ZMANIM)
+    /// entries or [`ZmanSpec`]s (including [custom](super::CustomZmanim::specs)
+    /// *zmanim*), named the way the spec is
+    /// [displayed](ZmanSpec#impl-Display-for-ZmanSpec), such as
+    /// `sof_zman_shema_gra` or `tzeis(8.5deg)`
+    #[must_use]
+    pub fn zmanim(mut self, zmanim: impl IntoIterator<Item = impl Into<ZmanSpec>>) -> Self {
+        self.columns.extend(zmanim.into_iter().map(|zman| {
+            let spec = zman.into();
+            Column {
+                name: Cow::Owned(spec.to_string()),
+                bound: zman_bound(spec.name()),
+                zman: ColumnZman::Spec(spec),
+            }
+        }));
+        self
+    }
+
+    /// Adds a column for each [`ProfileItem`] of `profile`, named by the item,
+    /// and uses the profile's [`UseElevation`] and [`Rounding`], like
+    /// [`ComplexZmanimCalendar::schedule`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProfileError::UnknownZman`] if one of the profile's *zmanim*
+    /// is not the name of a time in the [registry](super::ALL_ZMANIM).
+    pub fn profile(mut self, profile: &MinhagProfile) -> Result<Self, ProfileError> {
+        for item in ProfileItem::ALL {
+            let zman = match profile.zman_name(item) {
+                None => ColumnZman::CandleLighting(profile.candle_lighting_minutes),
+                Some(name) => ColumnZman::Spec(
+                    find_zman(name)
+                        .filter(|entry| entry.kind == ZmanKind::Time)
+                        .ok_or_else(|| ProfileError::UnknownZman(item, name.to_string()))?
+                        .into(),
+                ),
+            };
+            self.columns.push(Column {
+                name: Cow::Borrowed(item.name()),
+                zman,
+                bound: item.bound(),
+            });
+        }
+        self.use_elevation = profile.use_elevation;
+        self.rounding = profile.rounding;
+        Ok(self)
+    }
+
+    /// The names of the columns, in order
+    pub fn column_names(&self) -> impl Iterator<Item = &str> {
+        self.columns.iter().map(|column| &*column.name)
+    }
+
+    /// Returns an iterator over the rows of the table, one per day from the
+    /// start date to the end date, calculated as they are iterated
+    #[must_use]
+    pub fn rows(&self) -> ZmanimRows<'_> {
+        let mut czc =
+            ComplexZmanimCalendar::new(self.geo_location.clone(), self.start, self.use_elevation);
+        czc.set_twilight_fallback(self.twilight_fallback);
+        ZmanimRows {
+            table: self,
+            czc: (self.start <= self.end).then_some(czc),
+        }
+    }
+
+    fn row(&self, czc: &ComplexZmanimCalendar) -> ZmanimRow {
+        let values = self
+            .columns
+            .iter()
+            .map(|column| {
+                let value = match &column.zman {
+                    ColumnZman::Spec(spec) => spec.compute(czc),
+                    ColumnZman::CandleLighting(minutes) => {
+                        czc.candle_lighting(*minutes).map(ZmanValue::Time)
+                    }
+                };
+                match value {
+                    Some(ZmanValue::Time(time)) => {
+                        Some(ZmanValue::Time(self.rounding.round(&time, column.bound)))
+                    }
+                    value => value,
+                }
+            })
+            .collect();
+        ZmanimRow {
+            date: czc.date(),
+            values,
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl ZmanimTable {
+    /// The number of consecutive days calculated together by
+    /// [`par_rows`](ZmanimTable::par_rows), so that each thread can still
+    /// [advance](ComplexZmanimCalendar::advance_day) from day to day
+    const PARALLEL_DAYS: i64 = 32;
+
+    /// Returns the rows of the table, like [`rows`](ZmanimTable::rows), but
+    /// calculated on the [`rayon`] thread pool. The rows are in order of date,
+    /// and each *zman* that does not occur is a [`None`] in its own cell.
+    #[must_use]
+    pub fn par_rows(&self) -> Vec<ZmanimRow> {
+        use rayon::prelude::*;
+
+        // the start date of each chunk of days
+        let mut starts = Vec::new();
+        let mut start = Some(self.start).filter(|start| *start <= self.end);
+        while let Some(date) = start {
+            starts.push(date);
+            start = date
+                .checked_add(jiff::Span::new().days(Self::PARALLEL_DAYS))
+                .ok()
+                .filter(|next| *next <= self.end);
+        }
+        starts
+            .into_par_iter()
+            .map(|start| {
+                let end = start
+                    .checked_add(jiff::Span::new().days(Self::PARALLEL_DAYS - 1))
+                    .map_or(self.end, |end| end.min(self.end));
+                Self {
+                    start,
+                    end,
+                    ..self.clone()
+                }
+                .rows()
+                .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+/// Returns the rows of each of `tables`, such as the tables of many
+/// communities, calculated on the [`rayon`] thread pool. The results are in
+/// the order of `tables`, each in order of date, and each *zman* that does not
+/// occur is a [`None`] in its own cell.
+#[cfg(feature = "rayon")]
+#[must_use]
+pub fn par_tables(tables: &[ZmanimTable]) -> Vec<Vec<ZmanimRow>> {
+    use rayon::prelude::*;
+
+    tables.par_iter().map(ZmanimTable::par_rows).collect()
+}
+
+/// An iterator over the rows of a [`ZmanimTable`], returned by
+/// [`ZmanimTable::rows`]
+#[derive(Debug)]
+pub struct ZmanimRows<'a> {
+    table: &'a ZmanimTable,
+    // the calendar for the next row, or `None` after the last row
+    czc: Option<ComplexZmanimCalendar>,
+}
+
+impl Iterator for ZmanimRows<'_> {
+    type Item = ZmanimRow;
+
+    fn next(&mut self) -> Option<ZmanimRow> {
+        let czc = self.czc.as_mut()?;
+        let row = self.table.row(czc);
+        if czc.date() < self.table.end {
+            czc.advance_day();
+        } else {
+            self.czc = None;
+        }
+        Some(row)
+    }
+}
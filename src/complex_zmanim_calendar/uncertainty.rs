@@ -0,0 +1,76 @@
+//! Uncertainty intervals: *zmanim* as a range of times rather than a single
+//! instant, from the stated uncertainty of the inputs, for
+//! [`ComplexZmanimCalendar::zman_interval`](super::ComplexZmanimCalendar::zman_interval).
+//!
+//! Sunrise and sunset depend on atmospheric refraction, which varies with the
+//! weather by a large fraction of its standard 34 arcminutes, and on the
+//! height of the visible horizon and the observer's elevation, which are rarely
+//! surveyed. Before relying on elevation-based *zmanim* for a site it is useful
+//! to see how much these can move the result.
+
+use core::fmt;
+
+use jiff::{SignedDuration, Zoned};
+
+/// The uncertainty of each input to a *zman*, each as a symmetric &plusmn;
+/// amount. Defaults to no uncertainty.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Uncertainty {
+    /// Atmospheric refraction at the horizon, in degrees. Only affects
+    /// sunrise and sunset (and *zmanim* calculated from them), not the
+    /// degree-based *zmanim*, which are measured from the geometric horizon.
+    pub refraction: f64,
+    /// The height of the visible horizon, in degrees. Like refraction, it only
+    /// affects sunrise and sunset.
+    pub horizon: f64,
+    /// The elevation of the location, in meters. Only affects *zmanim* that
+    /// use elevation.
+    pub elevation: f64,
+    /// The latitude and longitude of the location, in degrees
+    pub coordinates: f64,
+}
+
+/// A *zman* with the range of times it could be, given an [`Uncertainty`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZmanInterval {
+    /// The earliest the *zman* could be
+    pub earliest: Zoned,
+    /// The *zman* calculated from the stated inputs
+    pub nominal: Zoned,
+    /// The latest the *zman* could be
+    pub latest: Zoned,
+}
+
+impl ZmanInterval {
+    /// The length of time from `earliest` to `latest`
+    #[must_use]
+    pub fn width(&self) -> SignedDuration {
+        self.latest.duration_since(&self.earliest)
+    }
+
+    /// The largest distance from `nominal` to either end of the interval
+    #[must_use]
+    pub fn margin(&self) -> SignedDuration {
+        self.nominal
+            .duration_since(&self.earliest)
+            .max(self.latest.duration_since(&self.nominal))
+    }
+
+    /// Whether `time` is within the interval
+    #[must_use]
+    pub fn contains(&self, time: &Zoned) -> bool {
+        (&self.earliest..=&self.latest).contains(&time)
+    }
+}
+
+/// Formats as the nominal time with the [margin](ZmanInterval::margin) in
+/// whole seconds (rounded up), such as `05:42:10 ± 40s`
+impl fmt::Display for ZmanInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let margin = self.margin();
+        let seconds = margin.as_secs() + i64::from(margin.subsec_nanos() > 0);
+        write!(f, "{} \u{b1} {seconds}s", self.nominal.strftime("%H:%M:%S"))
+    }
+}
@@ -280,6 +280,7 @@
 //! match (entry.compute)(&czc) {
 //!     Some(ZmanValue::Time(time)) => println!("{}: {}", entry.name, time.strftime("%H:%M:%S %Z")),
 //!     Some(ZmanValue::Duration(duration)) => println!("{}: {duration:#}", entry.name),
+//!     Some(ZmanValue::Number(number)) => println!("{}: {number}", entry.name),
 //!     None => println!("{}: does not occur", entry.name),
 //! }
 //!
@@ -287,8 +288,34 @@
 //! for entry in ALL_ZMANIM {
 //!     // ...
 //! }
+//!
+//! // or name any zman as text, including zmanim with arguments
+//! let tzeis = ZmanSpec::parse("tzeis(8.5deg)").unwrap();
+//! if let Some(ZmanValue::Time(time)) = tzeis.compute(&czc) {
+//!     println!("{tzeis}: {}", time.strftime("%H:%M:%S %Z"));
+//! }
 //! ```
 //!
+//! ## Features
+//! - `serde`: `Serialize` and `Deserialize` for the location, settings, and
+//!   results types, such as [`GeoLocation`](util::geolocation::GeoLocation)
+//!   (with its timezone as an IANA name),
+//!   [`ZmanOffset`](zmanim_calculator::ZmanOffset),
+//!   [`UseElevation`](complex_zmanim_calendar::UseElevation),
+//!   [`MinhagProfile`](complex_zmanim_calendar::MinhagProfile), and
+//!   [`ZmanValue`](complex_zmanim_calendar::ZmanValue)
+//! - `rayon`: `ZmanimTable::par_rows` and `par_tables` calculate
+//!   [tables](complex_zmanim_calendar::ZmanimTable) of *zmanim* in parallel
+//! - `sync`: makes
+//!   [`ComplexZmanimCalendar`](complex_zmanim_calendar::ComplexZmanimCalendar)
+//!   `Sync`, so that one calendar (and its cached solar events) can be shared
+//!   between threads
+//! - `cli`: builds the `zmanim` command-line tool, which prints a
+//!   [table](complex_zmanim_calendar::ZmanimTable) of
+//!   [`ZmanSpec`](complex_zmanim_calendar::ZmanSpec)s or a
+//!   [`MinhagProfile`](complex_zmanim_calendar::MinhagProfile) as text, CSV, or
+//!   JSON (run `zmanim --help`)
+//!
 //! ## Notes
 //! - Most APIs return `Option<Zoned>`. A result of `None` means the requested
 //!   event does not occur for the requested date/location (common in high
@@ -337,6 +364,6 @@ pub mod prelude {
         astronomical_calculator,
         complex_zmanim_calendar::*,
         util::geolocation::GeoLocation,
-        zmanim_calculator::{self, ZmanOffset},
+        zmanim_calculator::{self, ZmanError, ZmanOffset},
     };
 }
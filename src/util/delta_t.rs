@@ -0,0 +1,138 @@
+//! &Delta;T, the difference between Terrestrial Time (TT, the uniform time
+//! scale of the solar system ephemeris) and Universal Time (UT, the time scale
+//! of the earth's rotation, which clocks follow).
+//!
+//! The earth's rotation is slowly and irregularly slowing down, so &Delta;T
+//! grows over the centuries: about a minute today, three minutes in 1600, and
+//! over half an hour in 1000. The values are from the polynomial expressions
+//! of Fred Espenak and Jean Meeus, published with NASA's *Five Millennium
+//! Canon of Solar Eclipses*, which fit historical observations from -500 to
+//! the present and extrapolate beyond them.
+//!
+//! Sunrise, sunset, and the other solar events are defined by the earth's
+//! rotation, so they are calculated in UT and need no &Delta;T correction;
+//! &Delta;T only moves the sun a few arcseconds along its orbit. It is applied
+//! to [`solar_ephemeris`](crate::astronomical_calculator::solar_ephemeris),
+//! whose orbital values are defined in TT.
+
+use jiff::{SignedDuration, civil::Date};
+
+/// Returns &Delta;T (TT &minus; UT) in the middle of the month of `date`.
+#[must_use]
+pub fn delta_t(date: Date) -> SignedDuration {
+    let decimal_year = f64::from(date.year()) + (f64::from(date.month()) - 0.5) / 12.0;
+    SignedDuration::from_secs_f64(delta_t_seconds(decimal_year))
+}
+
+/// Returns &Delta;T (TT &minus; UT) in seconds for a decimal year, such as
+/// `1600.5` for the middle of 1600.
+#[must_use]
+pub fn delta_t_seconds(year: f64) -> f64 {
+    if year < -500.0 {
+        long_term_parabola(year)
+    } else if year < 500.0 {
+        polynomial(
+            year / 100.0,
+            &[
+                10_583.6,
+                -1_014.41,
+                33.783_11,
+                -5.952_053,
+                -0.179_845_2,
+                0.022_174_192,
+                0.009_031_652_1,
+            ],
+        )
+    } else if year < 1600.0 {
+        polynomial(
+            (year - 1000.0) / 100.0,
+            &[
+                1_574.2,
+                -556.01,
+                71.234_72,
+                0.319_781,
+                -0.850_346_3,
+                -0.005_050_998,
+                0.008_357_207_3,
+            ],
+        )
+    } else if year < 1700.0 {
+        polynomial(year - 1600.0, &[120.0, -0.9808, -0.01532, 1.0 / 7_129.0])
+    } else if year < 1800.0 {
+        polynomial(
+            year - 1700.0,
+            &[8.83, 0.1603, -0.005_928_5, 0.000_133_36, -1.0 / 1_174_000.0],
+        )
+    } else if year < 1860.0 {
+        polynomial(
+            year - 1800.0,
+            &[
+                13.72,
+                -0.332_447,
+                0.006_861_2,
+                0.004_111_6,
+                -0.000_374_36,
+                0.000_012_127_2,
+                -0.000_000_169_9,
+                0.000_000_000_875,
+            ],
+        )
+    } else if year < 1900.0 {
+        polynomial(
+            year - 1860.0,
+            &[
+                7.62,
+                0.5737,
+                -0.251_754,
+                0.016_806_68,
+                -0.000_447_362_4,
+                1.0 / 233_174.0,
+            ],
+        )
+    } else if year < 1920.0 {
+        polynomial(
+            year - 1900.0,
+            &[-2.79, 1.494_119, -0.059_893_9, 0.006_196_6, -0.000_197],
+        )
+    } else if year < 1941.0 {
+        polynomial(year - 1920.0, &[21.20, 0.844_93, -0.076_100, 0.002_093_6])
+    } else if year < 1961.0 {
+        polynomial(year - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2_547.0])
+    } else if year < 1986.0 {
+        polynomial(year - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0])
+    } else if year < 2005.0 {
+        polynomial(
+            year - 2000.0,
+            &[
+                63.86,
+                0.3345,
+                -0.060_374,
+                0.001_727_5,
+                0.000_651_814,
+                0.000_023_735_99,
+            ],
+        )
+    } else if year < 2050.0 {
+        polynomial(year - 2000.0, &[62.92, 0.322_17, 0.005_589])
+    } else if year < 2150.0 {
+        0.5628f64.mul_add(-(2150.0 - year), long_term_parabola(year))
+    } else {
+        long_term_parabola(year)
+    }
+}
+
+/// The long-term parabola of &Delta;T, used outside of the range of historical
+/// observations
+fn long_term_parabola(year: f64) -> f64 {
+    let u = (year - 1820.0) / 100.0;
+    (32.0 * u).mul_add(u, -20.0)
+}
+
+/// Evaluates the polynomial with `coefficients` (from the constant term up) at
+/// `x`
+fn polynomial(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |acc, coefficient| acc.mul_add(x, *coefficient))
+}
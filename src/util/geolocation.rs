@@ -1,24 +1,75 @@
 //! Geolocation struct, with some math for [local mean
-//! time](crate::astronomical_calculator::local_mean_time)
+//! time](crate::astronomical_calculator::local_mean_time) and for the distance
+//! and direction between locations
+use std::f64::consts::{FRAC_PI_4, PI};
+
 use crate::util::math_helper::HOUR_MINUTES;
 use jiff::tz::TimeZone;
 
+/// The latitude of the site of the *Beis Hamikdash* (the Temple Mount) in
+/// Jerusalem
+pub const BEIS_HAMIKDASH_LATITUDE: f64 = 31.778;
+
+/// The longitude of the site of the *Beis Hamikdash* (the Temple Mount) in
+/// Jerusalem
+pub const BEIS_HAMIKDASH_LONGITUDE: f64 = 35.2354;
+
+/// The semi-major axis (equatorial radius) of the WGS-84 ellipsoid, in meters
+const WGS84_SEMI_MAJOR_AXIS: f64 = 6_378_137.0;
+
+/// The flattening of the WGS-84 ellipsoid
+const WGS84_FLATTENING: f64 = 1.0 / 298.257_223_563;
+
+/// The IUGG mean radius of the earth, in meters, used for rhumb lines
+const EARTH_MEAN_RADIUS: f64 = 6_371_008.8;
+
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedGeoLocation"))]
 /// A struct that contains location information such as latitude and longitude
 /// required for astronomical calculations. The elevation field may be ignored
 /// by calculations that do not account for elevation.
 ///
-/// Construct with [`GeoLocation::new`], which validates the coordinates.
+/// Construct with [`GeoLocation::new`], which validates the coordinates. With
+/// the `serde` feature, the timezone is serialized by its IANA name (such as
+/// `"Asia/Jerusalem"`), and deserializing validates like `new`.
 pub struct GeoLocation {
     pub(crate) latitude: f64,
     pub(crate) longitude: f64,
     pub(crate) elevation: f64,
+    #[cfg_attr(feature = "serde", serde(with = "jiff::fmt::serde::tz::required"))]
     pub(crate) timezone: TimeZone,
 }
 
+/// The fields of a [`GeoLocation`] as deserialized, before they are validated
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedGeoLocation {
+    latitude: f64,
+    longitude: f64,
+    elevation: f64,
+    #[serde(with = "jiff::fmt::serde::tz::required")]
+    timezone: TimeZone,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedGeoLocation> for GeoLocation {
+    type Error = GeoLocationError;
+
+    fn try_from(unchecked: UncheckedGeoLocation) -> Result<Self, Self::Error> {
+        Self::new(
+            unchecked.latitude,
+            unchecked.longitude,
+            unchecked.elevation,
+            unchecked.timezone,
+        )
+    }
+}
+
 /// An invalid [`GeoLocation`] parameter, returned by [`GeoLocation::new`]. The
 /// contained value is the rejected input.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum GeoLocationError {
     /// Latitude was NaN or outside `[-90.0, 90.0]`
@@ -127,4 +178,211 @@ impl GeoLocation {
     pub fn local_mean_time_offset(&self) -> f64 {
         (self.longitude * 4.0) / HOUR_MINUTES
     }
+
+    /// Returns the distance in meters to `destination` along the geodesic (the
+    /// shortest path on the WGS-84 ellipsoid), calculated with [Vincenty's
+    /// inverse formula](https://en.wikipedia.org/wiki/Vincenty%27s_formulae).
+    ///
+    /// Returns `None` if the formula does not converge, which can happen for
+    /// nearly antipodal points.
+    #[must_use]
+    pub fn geodesic_distance(&self, destination: &Self) -> Option<f64> {
+        Some(self.vincenty_inverse(destination)?.distance)
+    }
+
+    /// Returns the initial bearing in degrees (clockwise from true north) of the
+    /// geodesic to `destination`: the direction to face when starting out along
+    /// the shortest path. See [`geodesic_distance`](Self::geodesic_distance).
+    ///
+    /// Returns `None` if Vincenty's formula does not converge.
+    #[must_use]
+    pub fn geodesic_initial_bearing(&self, destination: &Self) -> Option<f64> {
+        Some(self.vincenty_inverse(destination)?.initial_bearing)
+    }
+
+    /// Returns the final bearing in degrees (clockwise from true north) of the
+    /// geodesic to `destination`: the direction of travel on arrival. See
+    /// [`geodesic_distance`](Self::geodesic_distance).
+    ///
+    /// Returns `None` if Vincenty's formula does not converge.
+    #[must_use]
+    pub fn geodesic_final_bearing(&self, destination: &Self) -> Option<f64> {
+        Some(self.vincenty_inverse(destination)?.final_bearing)
+    }
+
+    /// Returns the bearing in degrees (clockwise from true north) of the [rhumb
+    /// line](https://en.wikipedia.org/wiki/Rhumb_line) to `destination`: the
+    /// constant compass direction that leads there, crossing every meridian at
+    /// the same angle.
+    #[must_use]
+    pub fn rhumb_line_bearing(&self, destination: &Self) -> f64 {
+        let delta_longitude =
+            shortest_longitude_difference((destination.longitude - self.longitude).to_radians());
+        let delta_phi = mercator_latitude_difference(self.latitude, destination.latitude);
+        delta_longitude
+            .atan2(delta_phi)
+            .to_degrees()
+            .rem_euclid(360.0)
+    }
+
+    /// Returns the distance in meters along the [rhumb
+    /// line](GeoLocation::rhumb_line_bearing) to `destination`, on a sphere with
+    /// the earth's mean radius.
+    #[must_use]
+    pub fn rhumb_line_distance(&self, destination: &Self) -> f64 {
+        let delta_latitude = (destination.latitude - self.latitude).to_radians();
+        let delta_longitude =
+            shortest_longitude_difference((destination.longitude - self.longitude).to_radians())
+                .abs();
+        let delta_phi = mercator_latitude_difference(self.latitude, destination.latitude);
+        // on an east-west line, the ratio is 0/0
+        let q = if delta_latitude.abs() > 1e-10 {
+            delta_latitude / delta_phi
+        } else {
+            self.latitude.to_radians().cos()
+        };
+        delta_latitude.hypot(q * delta_longitude) * EARTH_MEAN_RADIUS
+    }
+
+    /// Returns the [initial geodesic bearing](GeoLocation::geodesic_initial_bearing)
+    /// in degrees to the site of the *Beis Hamikdash* (the direction to face
+    /// when davening, along the shortest path).
+    ///
+    /// Returns `None` if Vincenty's formula does not converge.
+    #[must_use]
+    pub fn geodesic_bearing_to_beis_hamikdash(&self) -> Option<f64> {
+        self.geodesic_initial_bearing(&beis_hamikdash())
+    }
+
+    /// Returns the [rhumb line bearing](GeoLocation::rhumb_line_bearing) in
+    /// degrees to the site of the *Beis Hamikdash*.
+    #[must_use]
+    pub fn rhumb_line_bearing_to_beis_hamikdash(&self) -> f64 {
+        self.rhumb_line_bearing(&beis_hamikdash())
+    }
+
+    /// Solves the inverse geodesic problem between `self` and `destination`
+    /// with Vincenty's formula, or returns `None` if it does not converge.
+    fn vincenty_inverse(&self, destination: &Self) -> Option<Geodesic> {
+        let a = WGS84_SEMI_MAJOR_AXIS;
+        let f = WGS84_FLATTENING;
+        let b = a * (1.0 - f);
+
+        let l = (destination.longitude - self.longitude).to_radians();
+        let u1 = ((1.0 - f) * self.latitude.to_radians().tan()).atan();
+        let u2 = ((1.0 - f) * destination.latitude.to_radians().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        let mut converged = false;
+        let (mut sin_lambda, mut cos_lambda) = lambda.sin_cos();
+        let (mut sin_sigma, mut cos_sigma, mut sigma) = (0.0, 0.0, 0.0);
+        let (mut cos_sq_alpha, mut cos_2_sigma_m) = (0.0, 0.0);
+        for _ in 0..20 {
+            (sin_lambda, cos_lambda) = lambda.sin_cos();
+            sin_sigma =
+                (cos_u2 * sin_lambda).hypot((cos_u1 * sin_u2) - (sin_u1 * cos_u2 * cos_lambda));
+            if sin_sigma == 0.0 {
+                // coincident points
+                return Some(Geodesic {
+                    distance: 0.0,
+                    initial_bearing: 0.0,
+                    final_bearing: 0.0,
+                });
+            }
+            cos_sigma = (sin_u1 * sin_u2) + (cos_u1 * cos_u2 * cos_lambda);
+            sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            cos_sq_alpha = sin_alpha.mul_add(-sin_alpha, 1.0);
+            cos_2_sigma_m = if cos_sq_alpha == 0.0 {
+                // equatorial line
+                0.0
+            } else {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+            };
+            let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+            let previous_lambda = lambda;
+            lambda = l
+                + (1.0 - c)
+                    * f
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2_sigma_m
+                                + c * cos_sigma
+                                    * (2.0 * cos_2_sigma_m).mul_add(cos_2_sigma_m, -1.0)));
+            if (lambda - previous_lambda).abs() <= 1e-12 {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return None;
+        }
+
+        let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+        let big_a =
+            1.0 + u_sq / 16_384.0 * (4_096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+        let big_b = u_sq / 1_024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+        let delta_sigma = big_b
+            * sin_sigma
+            * (cos_2_sigma_m
+                + big_b / 4.0
+                    * (cos_sigma * (2.0 * cos_2_sigma_m).mul_add(cos_2_sigma_m, -1.0)
+                        - big_b / 6.0
+                            * cos_2_sigma_m
+                            * (4.0 * sin_sigma).mul_add(sin_sigma, -3.0)
+                            * (4.0 * cos_2_sigma_m).mul_add(cos_2_sigma_m, -3.0)));
+
+        let initial_bearing = (cos_u2 * sin_lambda)
+            .atan2((cos_u1 * sin_u2) - (sin_u1 * cos_u2 * cos_lambda))
+            .to_degrees();
+        let final_bearing = (cos_u1 * sin_lambda)
+            .atan2((-sin_u1 * cos_u2) + (cos_u1 * sin_u2 * cos_lambda))
+            .to_degrees();
+        Some(Geodesic {
+            distance: b * big_a * (sigma - delta_sigma),
+            initial_bearing: initial_bearing.rem_euclid(360.0),
+            final_bearing: final_bearing.rem_euclid(360.0),
+        })
+    }
+}
+
+/// The solution of the inverse geodesic problem between two locations
+struct Geodesic {
+    /// Distance in meters
+    distance: f64,
+    /// Initial bearing in degrees
+    initial_bearing: f64,
+    /// Final bearing in degrees
+    final_bearing: f64,
+}
+
+/// Returns the location of the site of the *Beis Hamikdash*
+fn beis_hamikdash() -> GeoLocation {
+    GeoLocation {
+        latitude: BEIS_HAMIKDASH_LATITUDE,
+        longitude: BEIS_HAMIKDASH_LONGITUDE,
+        elevation: 0.0,
+        timezone: TimeZone::UTC,
+    }
+}
+
+/// Returns a longitude difference (in radians) taking the shorter way around
+/// the antimeridian
+fn shortest_longitude_difference(delta_longitude: f64) -> f64 {
+    if delta_longitude > PI {
+        delta_longitude - 2.0 * PI
+    } else if delta_longitude < -PI {
+        delta_longitude + 2.0 * PI
+    } else {
+        delta_longitude
+    }
+}
+
+/// Returns the difference (in radians) between the Mercator-projected
+/// latitudes of `from` and `to`, given in degrees
+fn mercator_latitude_difference(from: f64, to: f64) -> f64 {
+    ((to.to_radians() / 2.0 + FRAC_PI_4).tan() / (from.to_radians() / 2.0 + FRAC_PI_4).tan()).ln()
 }
@@ -0,0 +1,97 @@
+//! Conversions between the Julian calendar and [`Date`], for historical dates.
+//!
+//! `jiff`'s [`Date`] (and so every function in this crate) uses the
+//! [proleptic Gregorian calendar](https://en.wikipedia.org/wiki/Proleptic_Gregorian_calendar),
+//! extending Gregorian leap years back before the calendar was introduced. The
+//! astronomical calculations are correct for any such `Date`, but records
+//! dated before the Gregorian reform of 1582 (and much later in many
+//! countries) used the Julian calendar, which by the 1500s was 10 days behind.
+//! Convert those dates with [`from_julian_calendar`] or
+//! [`from_historical_date`] before calculating *zmanim*.
+
+use jiff::{
+    Span,
+    civil::{self, Date},
+};
+
+/// The first day of the Gregorian calendar, October 15, 1582, which followed
+/// October 4, 1582 of the Julian calendar
+pub const GREGORIAN_REFORM: Date = civil::date(1582, 10, 15);
+
+/// The Julian day number of January 1, 2000 (Gregorian), used to convert day
+/// numbers to [`Date`]s
+const JULIAN_DAY_NUMBER_2000: i64 = 2_451_545;
+
+/// Returns the [`Date`] of the day that was `day` `month` `year` in the Julian
+/// calendar. Years are astronomical (1 BCE is year 0).
+///
+/// Returns `None` if there is no such day in the Julian calendar (such as
+/// February 30), or if the date is out of the range of [`Date`].
+#[must_use]
+pub fn from_julian_calendar(year: i16, month: i8, day: i8) -> Option<Date> {
+    if !(1..=12).contains(&month) || day < 1 || day > julian_days_in_month(year, month) {
+        return None;
+    }
+    let (year, month, day) = (i64::from(year), i64::from(month), i64::from(day));
+    let a = (14 - month).div_euclid(12);
+    let y = year + 4_800 - a;
+    let m = month + 12 * a - 3;
+    let julian_day_number = day + (153 * m + 2).div_euclid(5) + 365 * y + y.div_euclid(4) - 32_083;
+    civil::date(2000, 1, 1)
+        .checked_add(
+            Span::new()
+                .try_days(julian_day_number - JULIAN_DAY_NUMBER_2000)
+                .ok()?,
+        )
+        .ok()
+}
+
+/// Returns the Julian calendar `(year, month, day)` of `date`. Years are
+/// astronomical (1 BCE is year 0).
+#[must_use]
+pub fn to_julian_calendar(date: Date) -> (i16, i8, i8) {
+    let days = i64::from(
+        date.since(civil::date(2000, 1, 1))
+            .unwrap_or_default()
+            .get_days(),
+    );
+    let c = days + JULIAN_DAY_NUMBER_2000 + 32_082;
+    let d = (4 * c + 3).div_euclid(1_461);
+    let e = c - (1_461 * d).div_euclid(4);
+    let m = (5 * e + 2).div_euclid(153);
+    let day = e - (153 * m + 2).div_euclid(5) + 1;
+    let month = m + 3 - 12 * m.div_euclid(10);
+    let year = d - 4_800 + m.div_euclid(10);
+    // a Julian calendar date is within a few weeks of the Gregorian one, so
+    // these are always in range
+    (year as i16, month as i8, day as i8)
+}
+
+/// Returns the [`Date`] of a historical date: in the Julian calendar before
+/// [`GREGORIAN_REFORM`], and in the Gregorian calendar from then on.
+///
+/// Returns `None` for days that do not exist, including October 5&ndash;14,
+/// 1582, which were skipped by the reform. Note that many countries adopted
+/// the Gregorian calendar much later (Great Britain and its colonies in 1752,
+/// Russia in 1918); use [`from_julian_calendar`] directly for records dated in
+/// the Julian calendar after 1582.
+#[must_use]
+pub fn from_historical_date(year: i16, month: i8, day: i8) -> Option<Date> {
+    if (year, month, day) < (1582, 10, 15) {
+        let date = from_julian_calendar(year, month, day)?;
+        (date < GREGORIAN_REFORM).then_some(date)
+    } else {
+        civil::Date::new(year, month, day).ok()
+    }
+}
+
+/// Returns the number of days in `month` of `year` in the Julian calendar,
+/// where every fourth year is a leap year
+fn julian_days_in_month(year: i16, month: i8) -> i8 {
+    match month {
+        2 if year.rem_euclid(4) == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
@@ -1,6 +1,8 @@
 //! Utility modules for the Zmanim API.
 
+pub mod delta_t;
 pub mod geolocation;
+pub mod julian_calendar;
 pub(crate) mod math_helper;
 pub mod noaa_calculator;
 pub mod zenith_adjustments;
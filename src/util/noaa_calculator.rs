@@ -14,13 +14,18 @@ use std::{
     ops::{Add, Sub},
 };
 
-use jiff::{Span, Zoned, civil::Date, tz::TimeZone};
+use jiff::{
+    SignedDuration, Span, Zoned,
+    civil::{Date, Time},
+    tz::TimeZone,
+};
 
 use crate::{
-    astronomical_calculator::Azimuth,
+    astronomical_calculator::{Azimuth, SolarEphemeris, time_at_azimuth},
     util::{
+        delta_t::delta_t,
         geolocation::GeoLocation,
-        math_helper::{HOUR_MINUTES, HOUR_SECONDS, MINUTE_SECONDS, SECOND_NANOS},
+        math_helper::{HOUR_MINUTES, HOUR_SECONDS, MINUTE_NANOS, MINUTE_SECONDS, SECOND_NANOS},
         zenith_adjustments::adjusted_zenith,
     },
 };
@@ -32,6 +37,13 @@ const JULIAN_DAY_JAN_1_2000: f64 = 2_451_545.0;
 /// Julian days per century
 const JULIAN_DAYS_PER_CENTURY: f64 = 36_525.0;
 
+/// Seconds per day
+const DAY_SECONDS: f64 = 86_400.0;
+
+/// The sun's apparent angular semi-diameter (in degrees) at a distance of one
+/// astronomical unit, 959.63 arcseconds
+const SUN_RADIUS_AT_1_AU: f64 = 959.63 / 3_600.0;
+
 /// Returns the civil date adjusted for antimeridian crossover.
 ///
 /// The "absolute time" calculations are based on the longitudinal offset from
@@ -224,6 +236,26 @@ fn sun_apparent_longitude(julian_centuries: f64) -> f64 {
     0.00478f64.mul_add(-omega.to_radians().sin(), true_longitude - 0.00569) // in degrees
 }
 
+/// Return the distance from the earth to the sun (the radius vector), in
+/// astronomical units
+fn sun_radius_vector(julian_centuries: f64) -> f64 {
+    let eccentricity = earth_orbit_eccentricity(julian_centuries);
+    let true_anomaly =
+        sun_geometric_mean_anomaly(julian_centuries) + sun_equation_of_center(julian_centuries);
+    (1.000_001_018 * eccentricity.mul_add(-eccentricity, 1.0))
+        / eccentricity.mul_add(cos_deg(true_anomaly), 1.0) // in AU
+}
+
+/// Return the apparent right ascension of the sun, in degrees
+fn solar_right_ascension(julian_centuries: f64) -> f64 {
+    let correction = obliquity_correction(julian_centuries).to_radians();
+    let apparent_longitude = sun_apparent_longitude(julian_centuries).to_radians();
+    let right_ascension = (correction.cos() * apparent_longitude.sin())
+        .atan2(apparent_longitude.cos())
+        .to_degrees();
+    right_ascension.rem_euclid(360.0) // normalized (0...360)
+}
+
 /// Return the declination of the sun, in degrees
 fn solar_declination(julian_centuries: f64) -> f64 {
     let correction = obliquity_correction(julian_centuries).to_radians();
@@ -234,7 +266,7 @@ fn solar_declination(julian_centuries: f64) -> f64 {
 
 /// Return the approximate UTC in minutes of a given sun position for the given
 /// day at the given location on earth. Used twice within
-/// [`utc_sun_rise_set`] for accuracy.
+/// [`SolarDay::utc_sun_rise_set`] for accuracy.
 fn approximate_utc_sun_position(
     approx_julian_centuries: f64,
     latitude: f64,
@@ -242,8 +274,26 @@ fn approximate_utc_sun_position(
     zenith: f64,
     mode: &Mode,
 ) -> f64 {
-    let eq_time = equation_of_time(approx_julian_centuries);
-    let solar_dec = solar_declination(approx_julian_centuries);
+    utc_sun_position(
+        equation_of_time(approx_julian_centuries),
+        solar_declination(approx_julian_centuries),
+        latitude,
+        longitude,
+        zenith,
+        mode,
+    )
+}
+
+/// Return the UTC in minutes of a given sun position, from the equation of
+/// time and the sun's declination
+fn utc_sun_position(
+    eq_time: f64,
+    solar_dec: f64,
+    latitude: f64,
+    longitude: f64,
+    zenith: f64,
+    mode: &Mode,
+) -> f64 {
     let hour_angle = sun_hour_angle_at_horizon(latitude, solar_dec, zenith, mode);
 
     let delta = longitude - hour_angle.to_degrees();
@@ -251,54 +301,114 @@ fn approximate_utc_sun_position(
     720.0 + time_delta - eq_time
 }
 
-/// Return the UTC in minutes of a given sun position for the given day at the
-/// given location on earth, ([adjusts the
-/// zenith](crate::util::zenith_adjustments::adjusted_zenith) for refraction,
-/// solar radius, and optionally elevation).
-fn utc_sun_rise_set(
-    date: Date,
-    geo_location: &GeoLocation,
-    zenith: f64,
-    adjust_for_elevation: bool,
-    mode: &Mode,
-) -> Option<f64> {
-    let elevation = if adjust_for_elevation {
-        geo_location.elevation
-    } else {
-        0.0
-    };
-
-    let zoned = date.to_zoned(geo_location.timezone.clone()).ok()?;
-    let adjusted_date = antimeridian_adjusted_date(&zoned, geo_location.longitude);
-    let adjusted_zenith = adjusted_zenith(zenith, elevation, adjusted_date);
-    let julian_day = datetime_to_julian_day(&zoned, geo_location.longitude);
+/// The parts of the sunrise and sunset calculation that depend only on the
+/// date and location, not on the zenith or whether it is sunrise or sunset:
+/// the Julian day, solar noon, and the equation of time and declination at
+/// solar noon. Calculating them once lets every sunrise and sunset of a day
+/// share them, with the same results as [`utc_sunrise`] and [`utc_sunset`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SolarDay {
+    /// The date, [adjusted](antimeridian_adjusted_date) for the antimeridian
+    adjusted_date: Date,
+    julian_day: f64,
+    noon_eq_time: f64,
+    noon_declination: f64,
+}
+
+impl SolarDay {
+    /// Returns the `SolarDay` of `date` at `geo_location`
+    pub(crate) fn new(date: Date, geo_location: &GeoLocation) -> Option<Self> {
+        let zoned = date.to_zoned(geo_location.timezone.clone()).ok()?;
+        let julian_day = datetime_to_julian_day(&zoned, geo_location.longitude);
+        let noonmin = solar_noon_utc(
+            julian_centuries_from_julian_day(julian_day),
+            -geo_location.longitude,
+        );
+        let tnoon = julian_centuries_from_julian_day(julian_day + (noonmin / 1_440.0));
+        Some(Self {
+            adjusted_date: antimeridian_adjusted_date(&zoned, geo_location.longitude),
+            julian_day,
+            noon_eq_time: equation_of_time(tnoon),
+            noon_declination: solar_declination(tnoon),
+        })
+    }
 
-    // first pass using solar noon
-    let noonmin = solar_noon_utc(
-        julian_centuries_from_julian_day(julian_day),
-        -geo_location.longitude,
-    );
-    let tnoon = julian_centuries_from_julian_day(julian_day + (noonmin / 1_440.0));
-    let first_pass = approximate_utc_sun_position(
-        tnoon,
-        geo_location.latitude,
-        -geo_location.longitude,
-        adjusted_zenith,
-        mode,
-    );
+    /// The date, [adjusted](antimeridian_adjusted_date) for the antimeridian
+    pub(crate) fn adjusted_date(&self) -> Date {
+        self.adjusted_date
+    }
 
-    // refine using output of first pass
-    let trefinement = julian_centuries_from_julian_day(julian_day + (first_pass / 1_440.0));
+    /// Returns the UTC in hours of a given sun position on this day, ([adjusts
+    /// the zenith](crate::util::zenith_adjustments::adjusted_zenith) for
+    /// refraction, solar radius, and optionally elevation).
+    fn utc_sun_rise_set(
+        &self,
+        geo_location: &GeoLocation,
+        zenith: f64,
+        adjust_for_elevation: bool,
+        mode: &Mode,
+    ) -> Option<f64> {
+        let elevation = if adjust_for_elevation {
+            geo_location.elevation
+        } else {
+            0.0
+        };
+        let adjusted_zenith = adjusted_zenith(zenith, elevation, self.adjusted_date);
+
+        // first pass using solar noon
+        let first_pass = utc_sun_position(
+            self.noon_eq_time,
+            self.noon_declination,
+            geo_location.latitude,
+            -geo_location.longitude,
+            adjusted_zenith,
+            mode,
+        );
+
+        // refine using output of first pass
+        let trefinement =
+            julian_centuries_from_julian_day(self.julian_day + (first_pass / 1_440.0));
+
+        let time = approximate_utc_sun_position(
+            trefinement,
+            geo_location.latitude,
+            -geo_location.longitude,
+            adjusted_zenith,
+            mode,
+        );
+
+        normalize_time(time)
+    }
 
-    let time = approximate_utc_sun_position(
-        trefinement,
-        geo_location.latitude,
-        -geo_location.longitude,
-        adjusted_zenith,
-        mode,
-    );
+    /// Returns the UTC of sunrise in hours on this day. See [`utc_sunrise`]
+    pub(crate) fn utc_sunrise(
+        &self,
+        geo_location: &GeoLocation,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        self.utc_sun_rise_set(
+            geo_location,
+            zenith,
+            adjust_for_elevation,
+            &Mode::SunriseNoon,
+        )
+    }
 
-    normalize_time(time)
+    /// Returns the UTC of sunset in hours on this day. See [`utc_sunset`]
+    pub(crate) fn utc_sunset(
+        &self,
+        geo_location: &GeoLocation,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        self.utc_sun_rise_set(
+            geo_location,
+            zenith,
+            adjust_for_elevation,
+            &Mode::SunsetMidnight,
+        )
+    }
 }
 
 /// Converts a UTC time in minutes to fractional hours normalized into
@@ -380,15 +490,7 @@ fn acos_deg(x: f64) -> f64 {
 /// [`solar_azimuth`]. The position is based on sea level (it is not adjusted
 /// for altitude).
 fn solar_position(instant: &Zoned, loc: &GeoLocation) -> (f64, f64, f64) {
-    let utc = instant.with_time_zone(TimeZone::UTC);
-    let fractional_day = (f64::from(utc.hour())
-        + (f64::from(utc.minute())
-            + (f64::from(utc.second()) + f64::from(utc.subsec_nanosecond()) / SECOND_NANOS)
-                / MINUTE_SECONDS)
-            / HOUR_MINUTES)
-        / 24.0;
-    let julian_day = datetime_to_julian_day(&utc, loc.longitude) + fractional_day;
-    let julian_centuries = julian_centuries_from_julian_day(julian_day);
+    let (julian_centuries, fractional_day) = julian_centuries_at(instant);
     let declination = solar_declination(julian_centuries);
     let eq_time = equation_of_time(julian_centuries);
 
@@ -404,6 +506,21 @@ fn solar_position(instant: &Zoned, loc: &GeoLocation) -> (f64, f64, f64) {
     (zenith, hour_angle, declination)
 }
 
+/// Returns the Julian centuries since J2000.0 at `instant`, along with the
+/// fraction of the UTC day that has elapsed at `instant`.
+fn julian_centuries_at(instant: &Zoned) -> (f64, f64) {
+    let utc = instant.with_time_zone(TimeZone::UTC);
+    let fractional_day = (f64::from(utc.hour())
+        + (f64::from(utc.minute())
+            + (f64::from(utc.second()) + f64::from(utc.subsec_nanosecond()) / SECOND_NANOS)
+                / MINUTE_SECONDS)
+            / HOUR_MINUTES)
+        / 24.0;
+    // no antimeridian adjustment applies to a UTC datetime
+    let julian_day = datetime_to_julian_day(&utc, 0.0) + fractional_day;
+    (julian_centuries_from_julian_day(julian_day), fractional_day)
+}
+
 /// Applies an atmospheric refraction adjustment to a solar `elevation` (in
 /// degrees), returning the adjustment in degrees.
 ///
@@ -436,46 +553,25 @@ fn adjust_elevation_for_refraction(elevation: f64) -> f64 {
 
 /// Returns the UTC (in hours) of the time when the sun is directly due
 /// [east](Azimuth::East) (azimuth 90&deg;) or due [west](Azimuth::West)
-/// (azimuth 270&deg;) for the given day at the given location.
+/// (azimuth 270&deg;) for the given day at the given location. This is
+/// [`time_at_azimuth`] as a
+/// UTC time of day.
 ///
 /// Returns `None` when the azimuth is never reached for the date and location
-/// (for example in the tropics, at the poles, or on the equator).
+/// (for example in the tropics when the sun passes north of the zenith, or
+/// near the poles in the winter).
 #[must_use]
 pub fn utc_time_at_azimuth(
     date: Date,
     geo_location: &GeoLocation,
     target_azimuth: Azimuth,
 ) -> Option<f64> {
-    let julian_day = datetime_to_julian_day(
-        &date.to_zoned(geo_location.timezone.clone()).ok()?,
-        geo_location.longitude,
-    );
-    let solar_noon_base = 0.5 - (geo_location.longitude / 360.0);
-    let quarter = match target_azimuth {
-        Azimuth::East => 0.25,
-        Azimuth::West => 0.75,
-    };
-    let mut date_time = solar_noon_base + quarter;
-
-    for _ in 0..3 {
-        let julian_centuries = julian_centuries_from_julian_day(julian_day + date_time);
-        // Handle Tropics, the Poles, and Equator line divisions
-        let ratio = tan_deg(solar_declination(julian_centuries)) / tan_deg(geo_location.latitude);
-        if ratio.is_nan() || !(-1.0..=1.0).contains(&ratio) {
-            return None;
-        }
-        let sign = match target_azimuth {
-            Azimuth::East => -1.0,
-            Azimuth::West => 1.0,
-        };
-        let offset = sign * (acos_deg(ratio) / 360.0);
-        date_time = solar_noon_base + offset - (equation_of_time(julian_centuries) / 1_440.0);
-    }
-
-    Some((date_time * 24.0 % 24.0 + 24.0) % 24.0)
+    let time = time_at_azimuth(date, geo_location, target_azimuth)?;
+    let utc = time.with_time_zone(TimeZone::UTC).time();
+    Some(utc.duration_since(Time::midnight()).as_secs_f64() / HOUR_SECONDS)
 }
 
-// public interface for utc_sun_rise_set
+// public interface for SolarDay::utc_sun_rise_set
 /// Returns the UTC of sunrise in hours, adjusting the zenith for refraction,
 /// solar radius, and optionally elevation
 #[must_use]
@@ -485,13 +581,7 @@ pub fn utc_sunrise(
     zenith: f64,
     adjust_for_elevation: bool,
 ) -> Option<f64> {
-    utc_sun_rise_set(
-        date,
-        geo_location,
-        zenith,
-        adjust_for_elevation,
-        &Mode::SunriseNoon,
-    )
+    SolarDay::new(date, geo_location)?.utc_sunrise(geo_location, zenith, adjust_for_elevation)
 }
 
 /// Returns the UTC of sunset in hours, adjusting the zenith for refraction,
@@ -503,13 +593,7 @@ pub fn utc_sunset(
     zenith: f64,
     adjust_for_elevation: bool,
 ) -> Option<f64> {
-    utc_sun_rise_set(
-        date,
-        geo_location,
-        zenith,
-        adjust_for_elevation,
-        &Mode::SunsetMidnight,
-    )
+    SolarDay::new(date, geo_location)?.utc_sunset(geo_location, zenith, adjust_for_elevation)
 }
 
 /// Returns the UTC of solar noon for the given day at the given location on
@@ -533,11 +617,19 @@ pub fn utc_midnight(date: Date, geo_location: &GeoLocation) -> Option<f64> {
 /// location. Can be negative if the sun is below the horizon.
 #[must_use]
 pub fn solar_elevation(instant: &Zoned, geo_location: &GeoLocation) -> f64 {
-    let (zenith, _, _) = solar_position(instant, geo_location);
-    let elevation = 90.0 - zenith;
+    let elevation = solar_geometric_elevation(instant, geo_location);
     elevation + adjust_elevation_for_refraction(elevation)
 }
 
+/// Returns the geometric solar elevation (in degrees) at the given datetime and
+/// location, i.e. the elevation of the center of the sun without any
+/// adjustment for refraction. Can be negative if the sun is below the horizon.
+#[must_use]
+pub fn solar_geometric_elevation(instant: &Zoned, geo_location: &GeoLocation) -> f64 {
+    let (zenith, _, _) = solar_position(instant, geo_location);
+    90.0 - zenith
+}
+
 /// Returns the solar azimuth (in degrees, clockwise from due north) at the
 /// given datetime and location.
 #[must_use]
@@ -557,6 +649,27 @@ pub fn solar_azimuth(instant: &Zoned, geo_location: &GeoLocation) -> f64 {
     (azimuth + 360.0) % 360.0
 }
 
+/// Returns the sun's [`SolarEphemeris`] at the given instant. The orbital
+/// values are calculated in Terrestrial Time, [`delta_t`] after `instant`.
+#[must_use]
+pub fn solar_ephemeris(instant: &Zoned) -> SolarEphemeris {
+    let (julian_centuries, _) = julian_centuries_at(instant);
+    let julian_centuries = julian_centuries
+        + delta_t(instant.date()).as_secs_f64() / DAY_SECONDS / JULIAN_DAYS_PER_CENTURY;
+    let earth_sun_distance = sun_radius_vector(julian_centuries);
+    SolarEphemeris {
+        declination: solar_declination(julian_centuries),
+        right_ascension: solar_right_ascension(julian_centuries),
+        equation_of_time: SignedDuration::from_nanos(
+            (equation_of_time(julian_centuries) * MINUTE_NANOS) as i64,
+        ),
+        earth_sun_distance,
+        apparent_radius: SUN_RADIUS_AT_1_AU / earth_sun_distance,
+        obliquity: obliquity_correction(julian_centuries),
+        eccentricity: earth_orbit_eccentricity(julian_centuries),
+    }
+}
+
 /// Used internally to specify which solar event should be calculated, to a
 /// function that calculates both.
 #[derive(PartialEq)]
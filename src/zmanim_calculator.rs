@@ -13,7 +13,9 @@
 //! - temporal (*zmaniyos*) minutes derived from a supplied *shaah zmanis*.
 //!
 //! Most public APIs return `Option<Zoned>` and propagate `None` when the
-//! underlying astronomical event does not occur.
+//! underlying astronomical event does not occur. The `try_` variants of the
+//! sunrise/sunset based functions (such as [`try_alos`]) return a [`ZmanError`]
+//! explaining why instead.
 
 use std::ops::{Add, Sub};
 
@@ -21,7 +23,10 @@ use jiff::{SignedDuration, Zoned, civil::Date};
 
 use crate::{
     astronomical_calculator,
-    util::{geolocation::GeoLocation, math_helper::MINUTE_NANOS},
+    util::{
+        geolocation::GeoLocation, math_helper::MINUTE_NANOS, noaa_calculator,
+        zenith_adjustments::adjusted_zenith,
+    },
 };
 
 /// Returns *alos hashachar* (dawn).
@@ -184,6 +189,37 @@ pub fn chatzos_hayom_as_half_day(day_start: &Zoned, day_end: &Zoned) -> Zoned {
     day_start.add(day_end.duration_since(day_start) / 2)
 }
 
+/// Returns *chatzos halayla* (midnight) calculated as halfway between
+/// `night_start` and `night_end` (commonly *shkia* or *tzeis* and the next
+/// day's *hanetz* or *alos*), as opposed to [astronomical
+/// midnight](chatzos_halayla).
+#[must_use]
+pub fn chatzos_halayla_as_half_night(night_start: &Zoned, night_end: &Zoned) -> Zoned {
+    chatzos_hayom_as_half_day(night_start, night_end)
+}
+
+/// Returns the beginning of each of the `ashmuros` (watches) that the night
+/// from `night_start` to `night_end` is divided into, followed by the end of
+/// the night, so the returned `Vec` has `ashmuros + 1` elements.
+///
+/// The *Gemara* (*Berachos* 3a) brings two opinions: *Rebbi* holds that the
+/// night has four watches, and Rabbi Nasan that it has three. The beginning of
+/// the second watch (*chiluf mishmaros*, the "changing of the guard") is
+/// according to some opinions the correct time to start *selichos*. Passing
+/// 12 divides the night into its *shaos zmaniyos* (temporal hours) instead.
+///
+/// Returns an empty `Vec` if `ashmuros` is 0.
+#[must_use]
+pub fn ashmuros(night_start: &Zoned, night_end: &Zoned, ashmuros: u32) -> Vec<Zoned> {
+    if ashmuros == 0 {
+        return Vec::new();
+    }
+    let night = night_end.duration_since(night_start);
+    (0..=ashmuros)
+        .map(|ashmura| night_start.add(night.mul_f64(f64::from(ashmura) / f64::from(ashmuros))))
+        .collect()
+}
+
 /// Returns *mincha gedola* calculated as 30 minutes after *chatzos* and not 1/2
 /// of a *shaah zmanis* after *chatzos* as calculated by [`mincha_gedola`].
 ///
@@ -196,6 +232,20 @@ pub fn mincha_gedola_30_minutes(date: Date, geo_location: &GeoLocation) -> Optio
     Some(chatzos_hayom(date, geo_location)?.add(SignedDuration::from_mins(30)))
 }
 
+/// Returns the later of `mincha_gedola` and 30 clock minutes after `chatzos`
+/// (see [`mincha_gedola_30_minutes`]).
+///
+/// In the winter, when 1/2 of a *shaah zmanis* is less than 30 minutes, the
+/// 30-minute time is returned; otherwise `mincha_gedola` is returned. This
+/// applies to *mincha gedola* of any definition of the day, such as the GRA's
+/// sunrise to sunset (see [`mincha_gedola`]), or the MGA's *alos* to *tzeis*.
+#[must_use]
+pub fn mincha_gedola_greater_than_30_minutes(mincha_gedola: &Zoned, chatzos: &Zoned) -> Zoned {
+    mincha_gedola
+        .clone()
+        .max(chatzos.add(SignedDuration::from_mins(30)))
+}
+
 /// Returns *mincha gedola* using `day_start` and `day_end`.
 ///
 /// Mincha gedola is the earliest time one can pray mincha. The Rambam is of the
@@ -313,11 +363,36 @@ fn offset_by_minutes_zmanis(time: &Zoned, minutes: f64, shaah_zmanis: SignedDura
 /// The time from the start of day to the end of day is divided into 12 *shaos
 /// zmaniyos*, and the returned `Zoned` is `shaos` of those *shaos zmaniyos*
 /// after the beginning of the day.
-fn shaos_into_day(day_start: &Zoned, day_end: &Zoned, shaos: f64) -> Zoned {
+#[must_use]
+pub fn shaos_into_day(day_start: &Zoned, day_end: &Zoned, shaos: f64) -> Zoned {
     let shaah_zmanis = astronomical_calculator::temporal_hour(day_start, day_end);
     offset_by_minutes_zmanis(day_start, shaos * 60.0, shaah_zmanis)
 }
 
+/// Returns the length of a nighttime *shaah zmanis* (temporal hour).
+///
+/// This is computed from the provided night start and night end (commonly
+/// *shkia*/*tzeis* to the next day's *hanetz*/*alos*), which is divided into 12
+/// *shaos zmaniyos* just like the day.
+#[must_use]
+pub fn shaah_zmanis_halayla(night_start: &Zoned, night_end: &Zoned) -> SignedDuration {
+    astronomical_calculator::temporal_hour(night_start, night_end)
+}
+
+/// Returns the time `shaos` nighttime *shaos zmaniyos* (temporal hours) after
+/// the start of the night, calculated using the start and end of the night
+/// passed to this function (commonly *shkia*/*tzeis* and the next day's
+/// *hanetz*/*alos*).
+///
+/// For example, [*chatzos halayla*](chatzos_halayla_as_half_night) (the time
+/// for *tikkun chatzos*) is 6 hours into the night, and the first *chiluf
+/// mishmaros* is 4 hours into the night, or 3 according to the opinion that
+/// the night has four [watches](ashmuros).
+#[must_use]
+pub fn shaos_into_night(night_start: &Zoned, night_end: &Zoned, shaos: f64) -> Zoned {
+    shaos_into_day(night_start, night_end, shaos)
+}
+
 /// Returns the length of a *shaah zmanis* based on 1/6 of a half-day,
 /// calculated from the start to the end of the half-day passed to this
 /// function.
@@ -363,6 +438,7 @@ pub fn half_day_based_zman(
 /// astronomical degrees below the horizon, fixed clock minutes, or temporal
 /// (*zmaniyos*) minutes - and this enum captures which one applies.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ZmanOffset {
     /// Degrees below the horizon.
     Degrees(f64),
@@ -379,3 +455,187 @@ pub enum ZmanOffset {
         shaah_zmanis: SignedDuration,
     },
 }
+
+/// The reason a sunrise/sunset based *zman* could not be calculated, returned
+/// by the `try_` functions (such as [`try_alos`]) in place of `None`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum ZmanError {
+    /// The sun stays above the horizon all day (the midnight sun), so there is
+    /// no sunrise or sunset
+    PolarDay,
+    /// The sun stays below the horizon all day, so there is no sunrise or
+    /// sunset
+    PolarNight,
+    /// The sun never gets the contained number of degrees below the horizon,
+    /// such as during the white nights of summer far from the equator, when it
+    /// never gets dark enough for *alos* at 18&deg;
+    DepressionNotReached(f64),
+    /// The sun stays more than the contained number of degrees below the
+    /// horizon all day, so it never climbs to that depression
+    DepressionNotCleared(f64),
+    /// The sun does reach the requested position on this date, but the time
+    /// could not be calculated or represented in the location's timezone
+    InvalidTime,
+}
+
+impl core::fmt::Display for ZmanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PolarDay => write!(f, "the sun does not set on this date"),
+            Self::PolarNight => write!(f, "the sun does not rise on this date"),
+            Self::DepressionNotReached(deg) => {
+                write!(f, "the sun never gets {deg} degrees below the horizon")
+            }
+            Self::DepressionNotCleared(deg) => write!(
+                f,
+                "the sun stays more than {deg} degrees below the horizon all day"
+            ),
+            Self::InvalidTime => write!(
+                f,
+                "the time could not be represented in the location's timezone"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ZmanError {}
+
+/// Returns [`hanetz`], or a [`ZmanError`] explaining why there is none.
+///
+/// # Errors
+///
+/// Returns [`ZmanError::PolarDay`] or [`ZmanError::PolarNight`] when the sun
+/// does not rise on `date`, or [`ZmanError::InvalidTime`] when the time cannot
+/// be represented.
+pub fn try_hanetz(
+    date: Date,
+    geo_location: &GeoLocation,
+    use_elevation: bool,
+) -> Result<Zoned, ZmanError> {
+    hanetz(date, geo_location, use_elevation)
+        .ok_or_else(|| missing_sunrise_error(date, geo_location, use_elevation, None))
+}
+
+/// Returns [`shkia`], or a [`ZmanError`] explaining why there is none.
+///
+/// # Errors
+///
+/// Returns [`ZmanError::PolarDay`] or [`ZmanError::PolarNight`] when the sun
+/// does not set on `date`, or [`ZmanError::InvalidTime`] when the time cannot
+/// be represented.
+pub fn try_shkia(
+    date: Date,
+    geo_location: &GeoLocation,
+    use_elevation: bool,
+) -> Result<Zoned, ZmanError> {
+    shkia(date, geo_location, use_elevation)
+        .ok_or_else(|| missing_sunset_error(date, geo_location, use_elevation, None))
+}
+
+/// Returns [`alos`], or a [`ZmanError`] explaining why there is none.
+///
+/// # Errors
+///
+/// For a [`Degrees`](ZmanOffset::Degrees) offset, returns
+/// [`ZmanError::DepressionNotReached`] or [`ZmanError::DepressionNotCleared`]
+/// when the sun never passes that depression before the morning. For other
+/// offsets, returns the error from [`try_hanetz`].
+pub fn try_alos(
+    date: Date,
+    geo_location: &GeoLocation,
+    use_elevation: bool,
+    offset: &ZmanOffset,
+) -> Result<Zoned, ZmanError> {
+    alos(date, geo_location, use_elevation, offset)
+        .ok_or_else(|| missing_sunrise_error(date, geo_location, use_elevation, Some(offset)))
+}
+
+/// Returns [`tzeis`], or a [`ZmanError`] explaining why there is none.
+///
+/// # Errors
+///
+/// For a [`Degrees`](ZmanOffset::Degrees) offset, returns
+/// [`ZmanError::DepressionNotReached`] or [`ZmanError::DepressionNotCleared`]
+/// when the sun never passes that depression in the evening. For other
+/// offsets, returns the error from [`try_shkia`].
+pub fn try_tzeis(
+    date: Date,
+    geo_location: &GeoLocation,
+    use_elevation: bool,
+    offset: &ZmanOffset,
+) -> Result<Zoned, ZmanError> {
+    tzeis(date, geo_location, use_elevation, offset)
+        .ok_or_else(|| missing_sunset_error(date, geo_location, use_elevation, Some(offset)))
+}
+
+/// Explains a missing morning event: sunrise, or (with `offset`) *alos*. The
+/// sun's lowest point is taken from the previous night.
+pub(crate) fn missing_sunrise_error(
+    date: Date,
+    geo_location: &GeoLocation,
+    use_elevation: bool,
+    offset: Option<&ZmanOffset>,
+) -> ZmanError {
+    let Ok(yesterday) = date.yesterday() else {
+        return ZmanError::InvalidTime;
+    };
+    missing_event_error(date, yesterday, geo_location, use_elevation, offset)
+}
+
+/// Explains a missing evening event: sunset, or (with `offset`) *tzeis*. The
+/// sun's lowest point is taken from the following night.
+pub(crate) fn missing_sunset_error(
+    date: Date,
+    geo_location: &GeoLocation,
+    use_elevation: bool,
+    offset: Option<&ZmanOffset>,
+) -> ZmanError {
+    missing_event_error(date, date, geo_location, use_elevation, offset)
+}
+
+/// Classifies why the sun does not reach the position of a sunrise/sunset
+/// event on `date`, by comparing the position to the sun's geometric elevation
+/// at solar noon (its highest) and at the solar midnight following
+/// `midnight_date` (its lowest).
+fn missing_event_error(
+    date: Date,
+    midnight_date: Date,
+    geo_location: &GeoLocation,
+    use_elevation: bool,
+    offset: Option<&ZmanOffset>,
+) -> ZmanError {
+    let depression = match offset {
+        Some(ZmanOffset::Degrees(deg)) => Some(*deg),
+        _ => None,
+    };
+    let target = match depression {
+        Some(deg) => -deg,
+        None => {
+            let elevation = if use_elevation {
+                geo_location.elevation
+            } else {
+                0.0
+            };
+            astronomical_calculator::GEOMETRIC_ZENITH
+                - adjusted_zenith(astronomical_calculator::GEOMETRIC_ZENITH, elevation, date)
+        }
+    };
+
+    let (Some(noon), Some(midnight)) = (
+        astronomical_calculator::solar_noon(date, geo_location),
+        astronomical_calculator::solar_midnight(midnight_date, geo_location),
+    ) else {
+        return ZmanError::InvalidTime;
+    };
+    let highest = noaa_calculator::solar_geometric_elevation(&noon, geo_location);
+    let lowest = noaa_calculator::solar_geometric_elevation(&midnight, geo_location);
+
+    match depression {
+        Some(deg) if lowest > target => ZmanError::DepressionNotReached(deg),
+        Some(deg) if highest < target => ZmanError::DepressionNotCleared(deg),
+        None if lowest > target => ZmanError::PolarDay,
+        None if highest < target => ZmanError::PolarNight,
+        _ => ZmanError::InvalidTime,
+    }
+}
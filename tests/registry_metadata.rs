@@ -0,0 +1,321 @@
+//! Sanity checks of the metadata in [`ALL_ZMANIM`]: that it agrees with the
+//! entries' names, kinds, and accessor docs, and can be used to group and
+//! filter them.
+
+use rust_zmanim::complex_zmanim_calendar::{
+    ALL_ZMANIM, EntryDay, EntryOffset, ZmanCategory, ZmanKind, find_zman,
+};
+use std::collections::HashMap;
+
+const CZC_SRC: &str = include_str!("../src/complex_zmanim_calendar/czc_struct.rs");
+const DOC_MACROS_SRC: [&str; 5] = [
+    include_str!("../src/complex_zmanim_calendar/doc_macros/alos_docs.rs"),
+    include_str!("../src/complex_zmanim_calendar/doc_macros/tzeis_docs.rs"),
+    include_str!("../src/complex_zmanim_calendar/doc_macros/mga_degrees_docs.rs"),
+    include_str!("../src/complex_zmanim_calendar/doc_macros/mga_minutes_docs.rs"),
+    include_str!("../src/complex_zmanim_calendar/doc_macros/mga_minutes_zmanis_docs.rs"),
+];
+
+/// Parses the offset at the end of a name such as `alos_16_1_degrees` or
+/// `tzeis_72_minutes_zmanis`
+fn offset_from_name(name: &str) -> Option<EntryOffset> {
+    let (rest, unit): (&str, fn(f64) -> EntryOffset) =
+        if let Some(rest) = name.strip_suffix("_minutes_zmanis") {
+            (rest, EntryOffset::MinutesZmaniyos)
+        } else if let Some(rest) = name.strip_suffix("_minutes") {
+            (rest, EntryOffset::Minutes)
+        } else {
+            (name.strip_suffix("_degrees")?, EntryOffset::Degrees)
+        };
+    let number: Vec<&str> = rest
+        .split('_')
+        .rev()
+        .take_while(|part| part.chars().all(|c| c.is_ascii_digit()))
+        .collect();
+    let number = number.into_iter().rev().collect::<Vec<_>>().join(".");
+    Some(unit(number.parse().ok()?))
+}
+
+#[test]
+fn every_entry_has_a_summary() {
+    for entry in ALL_ZMANIM {
+        let summary = entry.summary;
+        assert!(!summary.is_empty(), "{}", entry.name);
+        assert_eq!(summary, summary.trim(), "{}", entry.name);
+        assert!(
+            summary.starts_with(|c: char| c.is_ascii_uppercase()),
+            "{}: {summary}",
+            entry.name
+        );
+        assert!(!summary.ends_with('.'), "{}: {summary}", entry.name);
+    }
+    assert_eq!(
+        find_zman("plag_gra").unwrap().summary,
+        "Returns *plag hamincha* calculated as 10.75 *shaos zmaniyos* (solar hours) after \
+         sunrise, according to the GRA"
+    );
+}
+
+#[test]
+fn categories_match_names_and_kinds() {
+    for entry in ALL_ZMANIM {
+        assert_eq!(
+            entry.category == ZmanCategory::ShaahZmanis,
+            entry.kind == ZmanKind::Duration,
+            "{}",
+            entry.name
+        );
+        let name = entry.name.trim_start_matches("polar_");
+        let category = entry.category.name();
+        let prefix = match entry.category {
+            ZmanCategory::Shema => "sof_zman_shema",
+            ZmanCategory::Tefila => "sof_zman_tefila",
+            ZmanCategory::BiurChametz => "sof_zman_biur_chametz",
+            _ => category,
+        };
+        assert!(
+            name.starts_with(prefix)
+                || name.contains(category)
+                || name.contains("sunrise") && entry.category == ZmanCategory::Hanetz
+                || name.contains("sunset") && entry.category == ZmanCategory::Shkia
+                || name.contains("lemincha") && entry.category == ZmanCategory::Mincha,
+            "{} is not {category}",
+            entry.name
+        );
+    }
+    for category in ZmanCategory::ALL {
+        assert!(
+            ALL_ZMANIM.iter().any(|entry| entry.category == category),
+            "{category:?}"
+        );
+    }
+}
+
+#[test]
+fn offsets_match_names() {
+    for entry in ALL_ZMANIM {
+        if let Some(offset) = entry.offset {
+            assert!(entry.day.is_none(), "{}", entry.name);
+            if let Some(from_name) = offset_from_name(entry.name) {
+                // bein hashmashos of the Yereim is before sunset
+                let from_name = match (entry.opinion, from_name) {
+                    (Some("Yereim"), EntryOffset::Degrees(d)) => EntryOffset::Degrees(-d),
+                    (Some("Yereim"), EntryOffset::Minutes(m)) => EntryOffset::Minutes(-m),
+                    (_, offset) => offset,
+                };
+                assert_eq!(offset, from_name, "{}", entry.name);
+            }
+        }
+        if matches!(
+            entry.category,
+            ZmanCategory::Alos | ZmanCategory::Misheyakir
+        ) {
+            assert!(entry.offset.is_some(), "{}", entry.name);
+        }
+        // the MGA's day is from alos to tzeis at the same offset
+        if entry.opinion == Some("MGA") {
+            let day = entry.day.unwrap();
+            assert_eq!(day.start, day.end, "{}", entry.name);
+            assert_eq!(
+                Some(day.start),
+                offset_from_name(entry.name.trim_end_matches("_greater_than_30_minutes")),
+                "{}",
+                entry.name
+            );
+        }
+    }
+    assert_eq!(
+        find_zman("sof_zman_shema_gra").unwrap().day,
+        Some(EntryDay {
+            start: EntryOffset::Minutes(0.0),
+            end: EntryOffset::Minutes(0.0),
+        })
+    );
+}
+
+#[test]
+fn filter_by_metadata() {
+    let mga_shema: Vec<_> = ALL_ZMANIM
+        .iter()
+        .filter(|entry| entry.category == ZmanCategory::Shema && entry.opinion == Some("MGA"))
+        .map(|entry| entry.name)
+        .collect();
+    assert!(mga_shema.contains(&"sof_zman_shema_mga_72_minutes"));
+    assert!(!mga_shema.contains(&"sof_zman_shema_gra"));
+
+    let lechumra: Vec<_> = ALL_ZMANIM
+        .iter()
+        .filter(|entry| entry.lechumra)
+        .map(|entry| entry.name)
+        .collect();
+    assert!(lechumra.contains(&"plag_mga_72_minutes"));
+    assert!(lechumra.contains(&"alos_120_minutes"));
+    assert!(!lechumra.contains(&"plag_gra"));
+    assert!(!lechumra.contains(&"alos_72_minutes"));
+}
+
+/// The documentation of every accessor in `czc_struct.rs`, by name: the `///`
+/// comments of the written ones, and the expanded `$doc` of the ones generated
+/// by `zmanim_for_offset!`
+fn accessor_docs() -> HashMap<&'static str, String> {
+    let macros = doc_macros();
+    let mut docs = HashMap::new();
+    let mut comment = Vec::new();
+    for line in CZC_SRC.lines() {
+        let trimmed = line.trim();
+        if let Some(doc) = trimmed.strip_prefix("///") {
+            comment.push(doc.trim());
+        } else if let Some(after) = trimmed.strip_prefix("pub fn ") {
+            let name = &after[..after.find(['(', '<']).unwrap()];
+            docs.insert(name, comment.join(" "));
+            comment.clear();
+        } else if let Some((name, rest)) = trimmed.split_once(" => ")
+            && let Some((method, _)) = rest.split_once(", ")
+            && method
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            // the doc expression can go on for several lines
+            let start = line.as_ptr() as usize - CZC_SRC.as_ptr() as usize;
+            let doc = &CZC_SRC[start..].split_once(", ").unwrap().1;
+            docs.insert(name, doc_expression(doc, &macros).0);
+        } else if !trimmed.starts_with("#[") {
+            comment.clear();
+        }
+    }
+    docs
+}
+
+/// The parameters and `concat!` pieces of each doc macro
+fn doc_macros() -> HashMap<&'static str, (Vec<&'static str>, &'static str)> {
+    let mut macros = HashMap::new();
+    for src in DOC_MACROS_SRC {
+        for definition in src.split("macro_rules! ").skip(1) {
+            let (name, rest) = definition.split_once(" {").unwrap();
+            let (params, body) = rest.split_once(") => {").unwrap();
+            let params = params
+                .trim_start_matches([' ', '\n', '('])
+                .split(", ")
+                .map(|param| param.trim_end_matches(":expr"))
+                .collect();
+            macros.insert(name.trim(), (params, body.trim_start()));
+        }
+    }
+    macros
+}
+
+/// Evaluates the doc expression at the start of `src`: a string literal, a
+/// `concat!`, a call of a doc macro, or (in a doc macro) a parameter, possibly
+/// stringified, from `args`. Returns the doc and the rest of `src`.
+fn doc_expression<'a>(
+    src: &'a str,
+    macros: &HashMap<&str, (Vec<&str>, &str)>,
+) -> (String, &'a str) {
+    expression_with_args(src, macros, &HashMap::new())
+}
+
+fn expression_with_args<'a>(
+    src: &'a str,
+    macros: &HashMap<&str, (Vec<&str>, &str)>,
+    args: &HashMap<&str, &str>,
+) -> (String, &'a str) {
+    let src = src.trim_start();
+    if let Some(literal) = src.strip_prefix('"') {
+        let end = literal.find('"').unwrap();
+        return (literal[..end].to_string(), &literal[end + 1..]);
+    }
+    let end = src.find([',', ')', '(']).unwrap();
+    let (name, rest) = (src[..end].trim(), &src[end..]);
+    if let Some(param) = args.get(name) {
+        return (param.trim_matches('"').to_string(), rest);
+    }
+    let name = name.strip_suffix('!').unwrap();
+    // the arguments of the call
+    let mut values = Vec::new();
+    let mut rest = &rest[1..];
+    while let Some(after) = rest.trim_start().strip_prefix(',').or(Some(rest)) {
+        let after = after.trim_start();
+        if let Some(after) = after.strip_prefix(')') {
+            rest = after;
+            break;
+        }
+        if name == "concat" {
+            let (value, after) = expression_with_args(after, macros, args);
+            values.push(value);
+            rest = after;
+        } else {
+            let end = after.find([',', ')']).unwrap();
+            let value = after[..end].trim();
+            values.push(args.get(value).unwrap_or(&value).to_string());
+            rest = &after[end..];
+        }
+    }
+    let doc = match name {
+        "concat" => values.concat(),
+        "stringify" => values.concat(),
+        _ => {
+            let Some((params, body)) = macros.get(name) else {
+                panic!("unknown doc macro {name}")
+            };
+            let args = params
+                .iter()
+                .copied()
+                .zip(values.iter().map(String::as_str))
+                .collect();
+            expression_with_args(body, macros, &args).0
+        }
+    };
+    (doc, rest)
+}
+
+/// Collapses the whitespace of Markdown, writes `&deg;` as `°`, and replaces
+/// links with their text
+fn normalize(markdown: &str) -> String {
+    let mut text = markdown
+        .replace("\\n", " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("&deg;", "°");
+    while let Some(start) = text.find('[') {
+        let end = start + text[start..].find(']').unwrap();
+        let link_end = match text[end + 1..].strip_prefix('(') {
+            Some(target) => end + 2 + target.find(')').unwrap() + 1,
+            None => end + 1,
+        };
+        let link_text = text[start + 1..end].to_owned();
+        text.replace_range(start..link_end, &link_text);
+    }
+    text
+}
+
+/// The first sentence of a doc comment, skipping a leading bold note (such as
+/// the one on *hanetz amiti*)
+fn first_sentence(doc: &str) -> &str {
+    let doc = match doc.strip_prefix("**Note:") {
+        Some(note) => note.split_once("** ").unwrap().1,
+        None => doc,
+    };
+    match doc.find(". ") {
+        Some(end) => &doc[..end],
+        None => doc.trim_end_matches('.'),
+    }
+}
+
+#[test]
+fn summaries_match_accessor_docs() {
+    let docs = accessor_docs();
+    let mut mismatched = Vec::new();
+    for entry in ALL_ZMANIM {
+        let Some(doc) = docs.get(entry.name) else {
+            mismatched.push(format!("{}: no accessor", entry.name));
+            continue;
+        };
+        let doc = normalize(doc);
+        let summary = normalize(entry.summary);
+        if summary != first_sentence(&doc) {
+            mismatched.push(format!("{}:\n  {summary}\n  {doc}", entry.name));
+        }
+    }
+    assert!(mismatched.is_empty(), "{}", mismatched.join("\n"));
+}
@@ -0,0 +1,319 @@
+//! Checks [`PARAMETERIZED_ZMANIM`] and [`ZmanSpec`]: that parsed *zmanim*
+//! match calling their accessors directly, that parse errors are reported,
+//! and that every accessor with offset, degree, minute, or number parameters is
+//! in the registry.
+
+use rust_zmanim::{prelude::*, zmanim_calculator::ZmanOffset};
+use std::collections::BTreeSet;
+mod test_helper;
+
+const SRC: &str = include_str!("../src/complex_zmanim_calendar/czc_struct.rs");
+
+fn compute(czc: &ComplexZmanimCalendar, spec: &str) -> Option<ZmanValue> {
+    ZmanSpec::parse(spec).unwrap().compute(czc)
+}
+
+fn time(czc: &ComplexZmanimCalendar, spec: &str) -> jiff::Zoned {
+    match compute(czc, spec) {
+        Some(ZmanValue::Time(time)) => time,
+        value => panic!("{spec}: {value:?}"),
+    }
+}
+
+#[test]
+fn test_matches_accessors() {
+    let czc = test_helper::czc_at(test_helper::jerusalem());
+    assert_eq!(
+        time(&czc, "tzeis(8.5deg)"),
+        czc.tzeis_geonim_8_5_degrees().unwrap()
+    );
+    assert_eq!(time(&czc, "alos(72min)"), czc.alos_72_minutes().unwrap());
+    assert_eq!(
+        time(&czc, "plag_mga(90zmanis)"),
+        czc.plag_mga_90_minutes_zmanis().unwrap()
+    );
+    assert_eq!(
+        time(&czc, "sof_zman_shema_gra"),
+        czc.sof_zman_shema_gra().unwrap()
+    );
+    assert_eq!(
+        time(&czc, "alos(-1min)"),
+        czc.alos(&ZmanOffset::Minutes(-1.0)).unwrap()
+    );
+    assert_eq!(
+        time(&czc, "candle_lighting(18min)"),
+        time(&czc, "candle_lighting(18)")
+    );
+    assert_eq!(
+        time(&czc, "shaos_into_night_tzeis_to_alos(72min, 3)"),
+        czc.shaos_into_night_tzeis_to_alos(&ZmanOffset::Minutes(72.0), 3.0)
+            .unwrap()
+    );
+    match compute(&czc, "shaah_zmanis_mga(16.1deg)") {
+        Some(ZmanValue::Duration(duration)) => {
+            assert_eq!(duration, czc.shaah_zmanis_mga_16_1_degrees().unwrap());
+        }
+        value => panic!("{value:?}"),
+    }
+    match compute(
+        &czc,
+        "percent_of_shaah_zmanis_from_degrees(16.1deg, sunset)",
+    ) {
+        Some(ZmanValue::Number(percent)) => assert_eq!(
+            Some(percent),
+            czc.percent_of_shaah_zmanis_from_degrees(16.1, true)
+        ),
+        value => panic!("{value:?}"),
+    }
+}
+
+#[test]
+fn test_day_definitions() {
+    let mut czc = test_helper::czc_at(test_helper::lakewood());
+    czc.set_use_astronomical_chatzos_for_other_zmanim(true);
+    let day = DayDefinition::new(
+        DayEvent::new(DayAnchor::Sunrise, ZmanOffset::Degrees(16.1)),
+        DayEvent::new(DayAnchor::Sunset, ZmanOffset::Degrees(7.083)),
+    );
+    let spec = |name: &str| format!("{name}(alos(16.1deg)..tzeis(7.083deg))");
+    assert_eq!(
+        time(&czc, &spec("sof_zman_shema")),
+        czc.sof_zman_shema(&day).unwrap()
+    );
+    assert_eq!(time(&czc, &spec("plag")), czc.plag(&day).unwrap());
+    assert_eq!(
+        time(&czc, "shaos_into_day(alos(16.1deg)..tzeis(7.083deg), 2.5)"),
+        czc.shaos_into_day(&day, 2.5).unwrap()
+    );
+    assert_eq!(
+        time(&czc, "mincha_gedola(sunrise..sunset)"),
+        czc.mincha_gedola_gra().unwrap()
+    );
+    assert_eq!(
+        time(&czc, "sof_zman_tefila(alos(72zmanis)..tzeis(72zmanis))"),
+        czc.sof_zman_tefila_mga_72_minutes_zmanis().unwrap()
+    );
+    match compute(&czc, "shaah_zmanis(alos(72min)..tzeis(72min))") {
+        Some(ZmanValue::Duration(duration)) => {
+            assert_eq!(Some(duration), czc.shaah_zmanis_mga_72_minutes());
+        }
+        value => panic!("{value:?}"),
+    }
+}
+
+#[test]
+fn test_display() {
+    for spec in [
+        "tzeis(8.5deg)",
+        "alos(72min)",
+        "plag_mga(90zmanis)",
+        "alos(-1min)",
+        "sof_zman_shema_gra",
+        "candle_lighting(18min)",
+        "shaos_into_night(3)",
+        "minutes_for_degrees(16.1deg, sunrise)",
+        "plag(alos(16.1deg)..tzeis(7.083deg))",
+        "sof_zman_shema(sunrise..tzeis(72min))",
+        "shaos_into_day(alos(-1zmanis)..sunset, 3)",
+    ] {
+        assert_eq!(ZmanSpec::parse(spec).unwrap().to_string(), spec);
+    }
+    let spaced = ZmanSpec::parse(" degrees_for_offset( 72min ,sunset ) ").unwrap();
+    assert_eq!(spaced.to_string(), "degrees_for_offset(72min, sunset)");
+    assert_eq!(spaced.args().len(), 2);
+    assert_eq!(spaced.category(), None);
+    assert_eq!(
+        ZmanSpec::parse("candle_lighting(18)").unwrap(),
+        ZmanSpec::parse("candle_lighting(18min)").unwrap()
+    );
+    assert_eq!(
+        ZmanSpec::from(find_zman("plag_gra").unwrap()),
+        "plag_gra".parse().unwrap()
+    );
+    assert_eq!(
+        ZmanSpec::parse("chatzos_as_half_day(alos(0min) .. tzeis(0min))")
+            .unwrap()
+            .to_string(),
+        "chatzos_as_half_day(sunrise..sunset)"
+    );
+    assert_eq!(
+        ZmanSpec::parse("candle_lighting(18min)")
+            .unwrap()
+            .category(),
+        None
+    );
+}
+
+#[test]
+fn test_errors() {
+    for (spec, error) in [
+        (
+            "",
+            ExpressionError::Syntax(0, "expected the name of a zman"),
+        ),
+        (
+            "tzeis_9_degrees",
+            ExpressionError::UnknownName(0, "tzeis_9_degrees".into()),
+        ),
+        (
+            "teiz(8.5deg)",
+            ExpressionError::UnknownName(0, "teiz".into()),
+        ),
+        ("tzeis", ExpressionError::Syntax(5, "expected arguments")),
+        (
+            "plag_gra(1)",
+            ExpressionError::Syntax(8, "expected no arguments"),
+        ),
+        (
+            "tzeis(3)",
+            ExpressionError::TypeMismatch(6, "expected an offset in deg, min, or zmanis"),
+        ),
+        (
+            "candle_lighting(18deg)",
+            ExpressionError::TypeMismatch(16, "expected minutes"),
+        ),
+        (
+            "minutes_for_degrees(16.1, noon)",
+            ExpressionError::TypeMismatch(26, "expected sunrise or sunset"),
+        ),
+        (
+            "tzeis()",
+            ExpressionError::Syntax(6, "wrong number of arguments"),
+        ),
+        (
+            "tzeis(8.5deg, 1)",
+            ExpressionError::Syntax(12, "wrong number of arguments"),
+        ),
+        (
+            "tzeis(8.5deg",
+            ExpressionError::Syntax(12, "wrong number of arguments"),
+        ),
+        (
+            "tzeis(8.5deg) x",
+            ExpressionError::Syntax(14, "expected the end"),
+        ),
+        (
+            "tzeis(8.5hours)",
+            ExpressionError::Syntax(9, "expected a unit of deg, min, or zmanis"),
+        ),
+        (
+            "plag(72min)",
+            ExpressionError::TypeMismatch(5, "expected a day such as alos(16.1deg)..tzeis(8.5deg)"),
+        ),
+        (
+            "plag(tzeis(8.5deg)..sunset)",
+            ExpressionError::TypeMismatch(5, "expected alos(offset) or sunrise"),
+        ),
+        (
+            "plag(sunrise..alos(72min))",
+            ExpressionError::TypeMismatch(14, "expected tzeis(offset) or sunset"),
+        ),
+        (
+            "plag(sunrise, sunset)",
+            ExpressionError::Syntax(12, "expected .. between the start and end of the day"),
+        ),
+        (
+            "plag(alos..sunset)",
+            ExpressionError::Syntax(9, "expected an offset in parentheses"),
+        ),
+        (
+            "plag(alos(3)..sunset)",
+            ExpressionError::TypeMismatch(10, "expected an offset in deg, min, or zmanis"),
+        ),
+    ] {
+        assert_eq!(ZmanSpec::parse(spec), Err(error), "{spec}");
+    }
+}
+
+#[test]
+fn test_every_entry() {
+    let czc = test_helper::czc_at(test_helper::jerusalem());
+    let mut names = BTreeSet::new();
+    for entry in PARAMETERIZED_ZMANIM {
+        assert!(names.insert(entry.name), "{} is listed twice", entry.name);
+        assert!(find_zman(entry.name).is_none(), "{}", entry.name);
+        assert!(!entry.summary.is_empty(), "{}", entry.name);
+
+        // the default argument of each parameter
+        let args: Vec<_> = entry
+            .params
+            .iter()
+            .map(|param| match param.kind {
+                ParamKind::Offset => "72min",
+                ParamKind::Degrees => "16.1deg",
+                ParamKind::Minutes => "18min",
+                ParamKind::Number => "3",
+                ParamKind::SunriseOrSunset => "sunset",
+                ParamKind::Day => "alos(16.1deg)..sunset",
+            })
+            .collect();
+        let spec = ZmanSpec::parse(&format!("{}({})", entry.name, args.join(", "))).unwrap();
+        assert_eq!(spec.kind(), entry.kind);
+        let value = spec.compute(&czc);
+        match (entry.kind, &value) {
+            (ZmanKind::Time, Some(ZmanValue::Time(_)))
+            | (ZmanKind::Duration, Some(ZmanValue::Duration(_)))
+            | (ZmanKind::Number, Some(ZmanValue::Number(_))) => {}
+            _ => panic!("{spec}: {value:?}"),
+        }
+
+        // arguments that don't match the parameters
+        assert!((entry.compute)(&czc, &[]).is_none(), "{}", entry.name);
+    }
+
+    // minutes zmaniyos are of the GRA's shaah zmanis
+    let zmaniyos = ZmanOffset::MinutesZmaniyos {
+        minutes_zmaniyos: 90.0,
+        shaah_zmanis: czc.shaah_zmanis_gra().unwrap(),
+    };
+    assert_eq!(
+        EntryOffset::MinutesZmaniyos(90.0).zman_offset(&czc),
+        Some(zmaniyos)
+    );
+}
+
+/// Every `pub fn NAME(&self, PARAMS) -> Option<Zoned|SignedDuration|f64>` whose
+/// parameters are all offsets, numbers, flags, or days
+#[test]
+fn test_completeness() {
+    let mut expected = BTreeSet::new();
+    for (start, _) in SRC.match_indices("pub fn ") {
+        let after = &SRC[start + "pub fn ".len()..];
+        let (Some(paren), Some(close), Some(brace)) =
+            (after.find('('), after.find(')'), after.find('{'))
+        else {
+            continue;
+        };
+        let params: String = after[paren + 1..close]
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let returns: String = after[close + 1..brace]
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let Some(params) = params.strip_prefix("&self,") else {
+            continue;
+        };
+        let simple = params.trim_end_matches(',').split(',').all(|param| {
+            [":&ZmanOffset", ":f64", ":bool", ":&DayDefinition"]
+                .iter()
+                .any(|kind| param.ends_with(kind))
+        });
+        if simple
+            && [
+                "->Option<Zoned>",
+                "->Option<SignedDuration>",
+                "->Option<f64>",
+            ]
+            .contains(&returns.as_str())
+        {
+            expected.insert(after[..paren].trim());
+        }
+    }
+    let actual: BTreeSet<_> = PARAMETERIZED_ZMANIM
+        .iter()
+        .map(|entry| entry.name)
+        .collect();
+    assert_eq!(actual, expected);
+}
@@ -4,7 +4,7 @@
 
 use std::iter::zip;
 
-use rust_zmanim::astronomical_calculator::*;
+use rust_zmanim::{astronomical_calculator::*, util::noaa_calculator};
 mod test_helper;
 use jiff::civil;
 
@@ -43,3 +43,177 @@ fn test_local_mean_time_invalid_hours() {
         assert!(local_mean_time(date, &loc, 25.0).is_none());
     }
 }
+
+#[test]
+fn test_times_at_elevation_matches_offset_by_degrees() {
+    let date = civil::date(2026, 3, 20);
+    for loc in test_helper::basic_locations() {
+        let crossings = times_at_elevation(date, &loc, -16.1);
+        let alos = sunrise_offset_by_degrees(date, &loc, GEOMETRIC_ZENITH + 16.1);
+        let tzeis = sunset_offset_by_degrees(date, &loc, GEOMETRIC_ZENITH + 16.1);
+
+        for (expected, direction) in [
+            (alos, SolarDirection::Rising),
+            (tzeis, SolarDirection::Setting),
+        ] {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let found = crossings.iter().any(|c| {
+                c.direction == direction && c.time.duration_since(&expected).abs().as_secs() < 10
+            });
+            assert!(
+                found,
+                "no {direction:?} crossing near {expected} in {crossings:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_times_at_elevation_above_horizon() {
+    let loc = test_helper::jerusalem();
+    let crossings = times_at_elevation(civil::date(2026, 6, 21), &loc, 10.0);
+    assert_eq!(crossings.len(), 2);
+    assert_eq!(crossings[0].direction, SolarDirection::Rising);
+    assert_eq!(crossings[1].direction, SolarDirection::Setting);
+    for crossing in &crossings {
+        let elevation = solar_geometric_elevation(&crossing.time, &loc);
+        assert!((elevation - 10.0).abs() < 1e-3, "{elevation}");
+    }
+}
+
+#[test]
+fn test_times_at_elevation_never_crossed() {
+    let loc = test_helper::arctic_nunavut();
+    // midnight sun: the sun never sets, let alone reaches twilight depression
+    assert!(times_at_elevation(civil::date(2026, 6, 21), &loc, -6.0).is_empty());
+    // polar night: the sun never rises
+    assert!(times_at_elevation(civil::date(2025, 12, 21), &loc, 0.0).is_empty());
+}
+
+#[test]
+fn test_times_at_azimuth_due_south_is_solar_noon() {
+    let date = civil::date(2026, 3, 20);
+    for loc in [
+        test_helper::lakewood(),
+        test_helper::jerusalem(),
+        test_helper::tokyo(),
+    ] {
+        let noon = solar_noon(date, &loc).expect("solar noon");
+        let crossings = times_at_azimuth(date, &loc, 180.0);
+        assert_eq!(crossings.len(), 1, "{crossings:?}");
+        assert!(crossings[0].time.duration_since(&noon).abs().as_secs() < 10);
+        let azimuth = solar_azimuth(&crossings[0].time, &loc);
+        assert!((azimuth - 180.0).abs() < 1e-3, "{azimuth}");
+    }
+}
+
+#[test]
+fn test_times_at_azimuth_east_and_west() {
+    let loc = test_helper::lakewood();
+    let date = civil::date(2026, 6, 21);
+    let east = times_at_azimuth(date, &loc, Azimuth::East.degrees());
+    let west = times_at_azimuth(date, &loc, Azimuth::West.degrees());
+    assert_eq!(east.len(), 1);
+    assert_eq!(west.len(), 1);
+    assert_eq!(east[0].direction, SolarDirection::Rising);
+    assert_eq!(west[0].direction, SolarDirection::Setting);
+    assert_eq!(
+        time_at_azimuth(date, &loc, Azimuth::East).as_ref(),
+        Some(&east[0].time)
+    );
+}
+
+#[test]
+fn test_times_at_azimuth_near_zenith() {
+    // the sun passes just south of the zenith, so its azimuth swings from east
+    // to west within minutes of noon
+    let loc = test_helper::honolulu();
+    for date in [civil::date(2026, 5, 25), civil::date(2026, 7, 17)] {
+        let noon = solar_noon(date, &loc).expect("solar noon");
+        assert!(solar_geometric_elevation(&noon, &loc) > 89.5);
+        let south = times_at_azimuth(date, &loc, 180.0);
+        assert_eq!(south.len(), 1, "{date}: {south:?}");
+        assert!(south[0].time.duration_since(&noon).abs().as_secs() < 10);
+        let azimuth = solar_azimuth(&south[0].time, &loc);
+        assert!((azimuth - 180.0).abs() < 0.5, "{date}: {azimuth}");
+        for direction in [Azimuth::East, Azimuth::West] {
+            let crossings = times_at_azimuth(date, &loc, direction.degrees());
+            assert_eq!(crossings.len(), 1, "{date}: {crossings:?}");
+        }
+    }
+}
+
+#[test]
+fn test_utc_time_at_azimuth() {
+    let loc = test_helper::lakewood();
+    let date = civil::date(2026, 6, 21);
+    for direction in [Azimuth::East, Azimuth::West] {
+        let time = time_at_azimuth(date, &loc, direction).unwrap();
+        let utc = time.with_time_zone(jiff::tz::TimeZone::UTC);
+        let hours = noaa_calculator::utc_time_at_azimuth(date, &loc, direction).unwrap();
+        let expected = f64::from(utc.hour())
+            + f64::from(utc.minute()) / 60.0
+            + f64::from(utc.second()) / 3600.0;
+        assert!(
+            (hours - expected).abs() < 1.0 / 3600.0,
+            "{hours} {expected}"
+        );
+    }
+    assert_eq!(
+        noaa_calculator::utc_time_at_azimuth(date, &test_helper::honolulu(), Azimuth::East),
+        None
+    );
+}
+
+#[test]
+fn test_times_at_azimuth_never_reached() {
+    // the sun passes north of the zenith, so it is never due east or south
+    let loc = test_helper::honolulu();
+    let date = civil::date(2026, 6, 21);
+    assert!(times_at_azimuth(date, &loc, 90.0).is_empty());
+    assert!(times_at_azimuth(date, &loc, 180.0).is_empty());
+}
+
+#[test]
+fn test_solar_ephemeris_meeus() {
+    // Meeus, Astronomical Algorithms, examples 25.a and 28.b: 1992 October 13
+    // at 0h dynamical time, which is delta T before 0h UT
+    let date = civil::date(1992, 10, 13);
+    let instant = date
+        .at(0, 0, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap()
+        - rust_zmanim::util::delta_t::delta_t(date);
+    let ephemeris = solar_ephemeris(&instant);
+
+    assert!(
+        (ephemeris.declination - -7.78507).abs() < 0.01,
+        "{ephemeris:?}"
+    );
+    assert!(
+        (ephemeris.right_ascension - 198.38083).abs() < 0.01,
+        "{ephemeris:?}"
+    );
+    assert!(
+        (ephemeris.earth_sun_distance - 0.99766).abs() < 1e-4,
+        "{ephemeris:?}"
+    );
+    assert!(
+        (ephemeris.eccentricity - 0.016711668).abs() < 1e-8,
+        "{ephemeris:?}"
+    );
+    assert!(
+        (ephemeris.obliquity - 23.43999).abs() < 0.001,
+        "{ephemeris:?}"
+    );
+    // 13m 42.6s
+    let eq_time = ephemeris.equation_of_time.as_secs_f64();
+    assert!((eq_time - 822.6).abs() < 2.0, "{eq_time}");
+    // 959.63" / 0.99766
+    assert!(
+        (ephemeris.apparent_radius - 0.267194).abs() < 1e-5,
+        "{ephemeris:?}"
+    );
+}
@@ -0,0 +1,144 @@
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+#[test]
+fn test_astronomical_chatzos_mode() {
+    let mut czc = test_helper::czc_at(test_helper::lakewood());
+    czc.set_use_elevation(UseElevation::No);
+    let mut astronomical = czc.clone();
+    astronomical.set_use_astronomical_chatzos_for_other_zmanim(true);
+    assert!(astronomical.use_astronomical_chatzos_for_other_zmanim());
+    assert_ne!(czc, astronomical);
+
+    for date in test_helper::sample_dates() {
+        czc.set_date(date);
+        astronomical.set_date(date);
+        let chatzos = czc.chatzos_hayom().unwrap();
+        let sunrise = czc.sea_level_sunrise().unwrap();
+        let sunset = czc.sea_level_sunset().unwrap();
+        let alos = czc.alos_72_minutes().unwrap();
+        let tzeis = czc.tzeis_72_minutes().unwrap();
+        let morning =
+            |start: &_, hours| zmanim_calculator::half_day_based_zman(start, &chatzos, hours);
+        let afternoon =
+            |end: &_, hours| zmanim_calculator::half_day_based_zman(&chatzos, end, hours);
+
+        for (actual, expected) in [
+            (astronomical.sof_zman_shema_gra(), morning(&sunrise, 3.0)),
+            (
+                astronomical.sof_zman_biur_chametz_gra(),
+                morning(&sunrise, 5.0),
+            ),
+            (astronomical.mincha_gedola_gra(), afternoon(&sunset, 0.5)),
+            (
+                astronomical.samuch_lemincha_ketana_gra(),
+                afternoon(&sunset, 3.0),
+            ),
+            (astronomical.plag_gra(), afternoon(&sunset, 4.75)),
+            (
+                astronomical.sof_zman_tefila_mga_72_minutes(),
+                morning(&alos, 4.0),
+            ),
+            (
+                astronomical.mincha_ketana_mga_72_minutes(),
+                afternoon(&tzeis, 3.5),
+            ),
+        ] {
+            assert_eq!(actual, Some(expected), "on {date}");
+        }
+
+        // the Baal Hatanya's zmanim are MGA zmanim, and so are affected
+        let hanetz_amiti = czc.hanetz_amiti_baal_hatanya().unwrap();
+        assert_eq!(
+            astronomical.sof_zman_shema_baal_hatanya(),
+            Some(morning(&hanetz_amiti, 3.0))
+        );
+        // asymmetric days are not
+        assert_eq!(astronomical.plag_ateret_torah(), czc.plag_ateret_torah());
+        assert_eq!(
+            astronomical.sof_zman_shema_alos_16_1_to_sunset(),
+            czc.sof_zman_shema_alos_16_1_to_sunset()
+        );
+
+        // and neither are asymmetric day definitions
+        let alos_16_1_to_tzeis_7_083 = DayDefinition::new(
+            DayEvent::new(DayAnchor::Sunrise, ZmanOffset::Degrees(16.1)),
+            DayEvent::new(DayAnchor::Sunset, ZmanOffset::Degrees(7.0 + 5.0 / 60.0)),
+        );
+        assert_eq!(
+            astronomical.sof_zman_shema(&alos_16_1_to_tzeis_7_083),
+            czc.sof_zman_shema_alos_16_1_to_tzeis_7_083()
+        );
+        assert_eq!(
+            astronomical.plag(&alos_16_1_to_tzeis_7_083),
+            czc.plag(&alos_16_1_to_tzeis_7_083)
+        );
+        let sunrise_to_noon = DayDefinition::new(
+            DayEvent::at(DayAnchor::Sunrise),
+            DayEvent::at(DayAnchor::Noon),
+        );
+        let plag = astronomical.plag(&sunrise_to_noon).unwrap();
+        assert_eq!(Some(plag.clone()), czc.plag(&sunrise_to_noon), "on {date}");
+        assert!(plag > sunrise && plag < chatzos, "on {date}");
+        // while symmetric ones are
+        assert_eq!(
+            astronomical.plag(&DayDefinition::mga(ZmanOffset::Minutes(72.0))),
+            Some(afternoon(&tzeis, 4.75))
+        );
+    }
+}
+
+#[test]
+fn test_astronomical_chatzos_mode_in_registry() {
+    let mut czc = test_helper::czc_at(test_helper::jerusalem());
+    czc.set_use_astronomical_chatzos_for_other_zmanim(true);
+    let entry = find_zman("plag_gra").unwrap();
+    let Some(ZmanValue::Time(plag)) = (entry.compute)(&czc) else {
+        panic!("no plag");
+    };
+    assert_eq!(Some(plag), czc.plag_gra());
+    assert_ne!(
+        czc.plag_gra(),
+        test_helper::czc_at(test_helper::jerusalem()).plag_gra()
+    );
+}
+
+#[test]
+fn test_astronomical_chatzos_mode_day_definitions() {
+    let mut czc = test_helper::czc_at(test_helper::lakewood());
+    czc.set_use_astronomical_chatzos_for_other_zmanim(true);
+    let gra = DayDefinition::gra();
+    let mga = DayDefinition::mga(ZmanOffset::Minutes(72.0));
+    for date in test_helper::sample_dates() {
+        czc.set_date(date);
+        for (actual, expected) in [
+            (czc.sof_zman_shema(&gra), czc.sof_zman_shema_gra()),
+            (czc.sof_zman_tefila(&gra), czc.sof_zman_tefila_gra()),
+            (
+                czc.sof_zman_biur_chametz(&gra),
+                czc.sof_zman_biur_chametz_gra(),
+            ),
+            (czc.mincha_gedola(&gra), czc.mincha_gedola_gra()),
+            (
+                czc.samuch_lemincha_ketana(&gra),
+                czc.samuch_lemincha_ketana_gra(),
+            ),
+            (czc.mincha_ketana(&gra), czc.mincha_ketana_gra()),
+            (czc.plag(&gra), czc.plag_gra()),
+            (czc.shaos_into_day(&gra, 3.0), czc.sof_zman_shema_gra()),
+            (
+                czc.sof_zman_shema(&mga),
+                czc.sof_zman_shema_mga_72_minutes(),
+            ),
+            (
+                czc.sof_zman_tefila(&mga),
+                czc.sof_zman_tefila_mga_72_minutes(),
+            ),
+            (czc.mincha_gedola(&mga), czc.mincha_gedola_mga_72_minutes()),
+            (czc.mincha_ketana(&mga), czc.mincha_ketana_mga_72_minutes()),
+            (czc.plag(&mga), czc.plag_mga_72_minutes()),
+        ] {
+            assert_eq!(actual, expected, "on {date}");
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use jiff::civil;
+use rust_zmanim::{prelude::*, zmanim_calculator::ZmanOffset};
+mod test_helper;
+
+fn jerusalem_equilux() -> ComplexZmanimCalendar {
+    ComplexZmanimCalendar::new(
+        test_helper::jerusalem(),
+        civil::date(2025, 3, 17),
+        UseElevation::No,
+    )
+}
+
+#[test]
+fn test_degrees_for_offset() {
+    let czc = jerusalem_equilux();
+    for (minutes, degrees) in [(72.0, 16.1), (90.0, 19.8), (120.0, 26.0)] {
+        for sunset in [false, true] {
+            let calibrated = czc
+                .degrees_for_offset(&ZmanOffset::Minutes(minutes), sunset)
+                .unwrap();
+            assert!(
+                (calibrated - degrees).abs() < 0.1,
+                "{minutes}: {calibrated}"
+            );
+        }
+    }
+    assert_eq!(
+        czc.degrees_for_offset(&ZmanOffset::Degrees(11.5), false),
+        Some(11.5)
+    );
+
+    // 72 minutes zmaniyos are about 72 clock minutes on the equilux
+    let zmaniyos = ZmanOffset::MinutesZmaniyos {
+        minutes_zmaniyos: 72.0,
+        shaah_zmanis: czc.shaah_zmanis_gra().unwrap(),
+    };
+    let by_zmaniyos = czc.degrees_for_offset(&zmaniyos, false).unwrap();
+    assert!((by_zmaniyos - 16.1).abs() < 0.2, "{by_zmaniyos}");
+}
+
+#[test]
+fn test_minutes_for_degrees() {
+    let czc = jerusalem_equilux();
+    for sunset in [false, true] {
+        let minutes = czc.minutes_for_degrees(16.1, sunset).unwrap();
+        assert!((minutes - 72.0).abs() < 0.5, "{minutes}");
+        let zmaniyos = czc.minutes_zmaniyos_for_degrees(16.1, sunset).unwrap();
+        let percent = czc
+            .percent_of_shaah_zmanis_from_degrees(16.1, sunset)
+            .unwrap();
+        assert!((zmaniyos - percent * 60.0).abs() < 1e-6);
+
+        // the two calibrations are inverses
+        let degrees = czc
+            .degrees_for_offset(&ZmanOffset::Minutes(minutes), sunset)
+            .unwrap();
+        assert!((degrees - 16.1).abs() < 0.001, "{degrees}");
+    }
+
+    // a local equivalent: 16.1 degrees is longer than 72 minutes in Lakewood,
+    // which is farther north
+    let lakewood = ComplexZmanimCalendar::new(
+        test_helper::lakewood(),
+        civil::date(2025, 3, 17),
+        UseElevation::No,
+    );
+    assert!(lakewood.minutes_for_degrees(16.1, false).unwrap() > 72.0);
+    let local = lakewood
+        .degrees_for_offset(&ZmanOffset::Minutes(72.0), false)
+        .unwrap();
+    assert!(local < 16.1);
+
+    // the sun does not get 18 degrees below the horizon in an arctic summer
+    let arctic = ComplexZmanimCalendar::new(
+        test_helper::arctic_nunavut(),
+        civil::date(2025, 6, 21),
+        UseElevation::No,
+    );
+    assert_eq!(arctic.minutes_for_degrees(18.0, false), None);
+    assert_eq!(arctic.minutes_zmaniyos_for_degrees(18.0, false), None);
+}
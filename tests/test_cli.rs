@@ -0,0 +1,231 @@
+#![cfg(feature = "cli")]
+
+use jiff::{civil, tz::TimeZone};
+use rust_zmanim::prelude::*;
+use std::process::{Command, Output};
+
+fn zmanim(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_zmanim"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = zmanim(args);
+    assert!(output.status.success(), "{args:?}: {output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn lakewood() -> GeoLocation {
+    let timezone = TimeZone::get("America/New_York").unwrap();
+    GeoLocation::new(40.0821, -74.2097, 20.0, timezone).unwrap()
+}
+
+#[test]
+fn test_csv_matches_library() {
+    let csv = stdout(&[
+        "--lat=40.0821",
+        "--lon=-74.2097",
+        "--elevation=20",
+        "--tz=America/New_York",
+        "--from=2025-06-20",
+        "--to=2025-06-22",
+        "--zman=hanetz",
+        "--zman",
+        "tzeis(8.5deg)",
+        "--zman",
+        "shaah_zmanis_gra",
+        "--use-elevation",
+        "hanetz-shkia",
+        "--format",
+        "csv",
+    ]);
+    let lines: Vec<_> = csv.lines().collect();
+    assert_eq!(lines[0], "date,hanetz,tzeis(8.5deg),shaah_zmanis_gra");
+    assert_eq!(lines.len(), 4);
+
+    let mut czc = ComplexZmanimCalendar::new(
+        lakewood(),
+        civil::date(2025, 6, 20),
+        UseElevation::HanetzShkia,
+    );
+    for line in &lines[1..] {
+        let fields: Vec<_> = line.split(',').collect();
+        assert_eq!(fields[0], czc.date().to_string());
+        let hanetz = czc.hanetz().unwrap();
+        assert_eq!(
+            fields[1],
+            hanetz.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string()
+        );
+        assert_eq!(
+            fields[2],
+            czc.tzeis_geonim_8_5_degrees()
+                .unwrap()
+                .strftime("%Y-%m-%dT%H:%M:%S%:z")
+                .to_string()
+        );
+        assert_eq!(fields[3], czc.shaah_zmanis_gra().unwrap().to_string());
+        czc.set_date(czc.date().tomorrow().unwrap());
+    }
+}
+
+#[test]
+fn test_json_profile() {
+    let json = stdout(&[
+        "--city",
+        "lakewood",
+        "--date",
+        "2025-06-20",
+        "--profile",
+        "chabad",
+        "--zman",
+        "percent_of_shaah_zmanis_from_degrees(16.1deg, sunset)",
+        "--zman",
+        "hanetz",
+        "--zman",
+        "percent_of_shaah_zmanis_from_degrees(16.1deg, sunset)",
+        "--format",
+        "json",
+    ]);
+    let days: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let days = days.as_array().unwrap();
+    assert_eq!(days.len(), 1);
+    let day = days[0].as_object().unwrap();
+    assert_eq!(day["date"], "2025-06-20");
+    // each zman once, even when also given with --zman
+    assert_eq!(day.len(), ProfileItem::ALL.len() + 2);
+    for item in ProfileItem::ALL {
+        assert!(day[item.name()]["Time"].is_string(), "{}", item.name());
+    }
+    // the profile rounds to the minute
+    let hanetz: ZmanValue = serde_json::from_value(day["hanetz"].clone()).unwrap();
+    assert!(
+        matches!(hanetz, ZmanValue::Time(time) if time.second() == 0 && time.subsec_nanosecond() == 0)
+    );
+    assert!(day["percent_of_shaah_zmanis_from_degrees(16.1deg, sunset)"]["Number"].is_number());
+}
+
+#[test]
+fn test_table() {
+    let table = stdout(&[
+        "--city",
+        "jerusalem",
+        "--date",
+        "2025-03-14",
+        "--zman",
+        "hanetz",
+        "--zman",
+        "tzeis(16.1deg)",
+    ]);
+    let lines: Vec<_> = table.lines().collect();
+    assert_eq!(lines.len(), 2);
+    let header: Vec<_> = lines[0].split_whitespace().collect();
+    assert_eq!(header, ["date", "hanetz", "tzeis(16.1deg)"]);
+    let row: Vec<_> = lines[1].split_whitespace().collect();
+    assert_eq!(row[0], "2025-03-14");
+    assert_eq!(row.len(), 3);
+    assert!(row[1].starts_with("05:"), "{row:?}");
+
+    // times on another date show the date
+    let table = stdout(&[
+        "--city",
+        "lakewood",
+        "--date",
+        "2025-06-20",
+        "--zman",
+        "chatzos_halayla",
+    ]);
+    let row: Vec<_> = table.lines().nth(1).unwrap().split_whitespace().collect();
+    assert_eq!(row[..2], ["2025-06-20", "2025-06-21"]);
+    assert!(row[2].starts_with("00:"), "{row:?}");
+
+    // the default zmanim, and zmanim that don't occur
+    let table = stdout(&[
+        "--lat",
+        "70",
+        "--lon",
+        "0",
+        "--tz",
+        "UTC",
+        "--date",
+        "2025-06-21",
+    ]);
+    let lines: Vec<_> = table.lines().collect();
+    assert!(lines[0].contains("sof_zman_shema_gra"));
+    assert!(lines[1].split_whitespace().any(|cell| cell == "-"));
+}
+
+#[test]
+fn test_city_elevation() {
+    let hanetz = |args: &[&str]| {
+        let args = [
+            &[
+                "--date",
+                "2025-06-20",
+                "--zman",
+                "hanetz",
+                "--format",
+                "csv",
+            ],
+            args,
+        ]
+        .concat();
+        stdout(&args).lines().nth(1).unwrap().to_string()
+    };
+    let city = ["--city", "jerusalem", "--use-elevation", "all"];
+    let sea_level = ["--lat=31.778", "--lon=35.2354", "--tz=Asia/Jerusalem"];
+    assert_eq!(
+        hanetz(&[&city[..], &["--elevation", "0"]].concat()),
+        hanetz(&sea_level)
+    );
+    assert_ne!(hanetz(&city), hanetz(&sea_level));
+}
+
+#[test]
+fn test_lists() {
+    assert!(stdout(&["--help"]).starts_with("Usage: zmanim"));
+    assert!(stdout(&["--list-cities"]).contains("jerusalem"));
+    assert!(stdout(&["--list-profiles"]).contains("lakewood_ashkenaz"));
+    let list = stdout(&["--list"]);
+    assert!(list.contains("sof_zman_shema_gra\t"));
+    assert!(list.contains("tzeis(offset)\t"));
+}
+
+#[test]
+fn test_errors() {
+    for (args, message) in [
+        (&["--date", "2025-01-01"][..], "a location is needed"),
+        (&["--city", "atlantis"], "unknown city"),
+        (&["--city", "london", "--lat", "51"], "either --city"),
+        (
+            &["--city", "london", "--zman", "tzeis"],
+            "expected arguments",
+        ),
+        (&["--city", "london", "--profile", "x"], "unknown profile"),
+        (
+            &["--city", "london", "--date", "2025-13-01"],
+            "expects a date",
+        ),
+        (
+            &[
+                "--city",
+                "london",
+                "--from",
+                "2025-02-01",
+                "--to",
+                "2025-01-01",
+            ],
+            "--from is after --to",
+        ),
+        (&["--city", "london", "--format", "xml"], "unknown --format"),
+        (&["--city"], "--city needs a value"),
+        (&["--lat", "91", "--lon", "0", "--tz", "UTC"], "latitude 91"),
+        (&["--tz", "Nowhere/City"], "unknown time zone"),
+    ] {
+        let output = zmanim(args);
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains(message), "{args:?}: {stderr}");
+    }
+}
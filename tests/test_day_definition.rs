@@ -0,0 +1,90 @@
+use jiff::SignedDuration;
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+#[test]
+fn test_day_definitions_match_premade_zmanim() {
+    let mut czc = test_helper::czc_at(test_helper::lakewood());
+    let gra = DayDefinition::gra();
+    let mga = DayDefinition::mga(ZmanOffset::Degrees(16.1));
+    // 7.083 is 7&deg; 5'
+    let alos_16_1_to_tzeis_7_083 = DayDefinition::new(
+        DayEvent::new(DayAnchor::Sunrise, ZmanOffset::Degrees(16.1)),
+        DayEvent::new(DayAnchor::Sunset, ZmanOffset::Degrees(7.0 + 5.0 / 60.0)),
+    );
+
+    for date in test_helper::sample_dates() {
+        czc.set_date(date);
+        assert_eq!(czc.sof_zman_shema(&gra), czc.sof_zman_shema_gra());
+        assert_eq!(czc.sof_zman_tefila(&gra), czc.sof_zman_tefila_gra());
+        assert_eq!(czc.mincha_gedola(&gra), czc.mincha_gedola_gra());
+        assert_eq!(czc.plag(&gra), czc.plag_gra());
+        assert_eq!(czc.shaah_zmanis(&gra), czc.shaah_zmanis_gra());
+
+        assert_eq!(
+            czc.sof_zman_shema(&mga),
+            czc.sof_zman_shema_mga_16_1_degrees()
+        );
+        assert_eq!(
+            czc.mincha_ketana(&mga),
+            czc.mincha_ketana_mga_16_1_degrees()
+        );
+        assert_eq!(
+            czc.sof_zman_biur_chametz(&mga),
+            czc.sof_zman_biur_chametz_mga_16_1_degrees()
+        );
+
+        assert_eq!(
+            czc.sof_zman_shema(&alos_16_1_to_tzeis_7_083),
+            czc.sof_zman_shema_alos_16_1_to_tzeis_7_083()
+        );
+        assert_eq!(
+            czc.plag(&alos_16_1_to_tzeis_7_083),
+            czc.plag_alos_16_1_to_tzeis_7_083()
+        );
+        assert_eq!(
+            czc.shaos_into_day(&alos_16_1_to_tzeis_7_083, 10.75),
+            czc.plag_alos_16_1_to_tzeis_7_083()
+        );
+    }
+}
+
+#[test]
+fn test_day_anchors() {
+    let czc = test_helper::czc_at(test_helper::lakewood());
+    let noon = czc.chatzos_hayom().unwrap();
+    assert_eq!(
+        czc.day_event(&DayEvent::at(DayAnchor::Noon)),
+        Some(noon.clone())
+    );
+    assert_eq!(
+        czc.day_event(&DayEvent::new(DayAnchor::Noon, ZmanOffset::Minutes(30.0))),
+        czc.mincha_gedola_30_minutes()
+    );
+    // degrees are only measured from sunrise and sunset
+    assert_eq!(
+        czc.day_event(&DayEvent::new(DayAnchor::Noon, ZmanOffset::Degrees(6.0))),
+        None
+    );
+
+    // the sun is due south at (about) solar noon
+    let south = czc
+        .day_event(&DayEvent::at(DayAnchor::Azimuth(180.0)))
+        .unwrap();
+    assert!(south.duration_since(&noon).abs() < SignedDuration::from_secs(1));
+
+    // half of the morning, from sunrise to noon
+    let morning = DayDefinition::new(
+        DayEvent::at(DayAnchor::Sunrise),
+        DayEvent::at(DayAnchor::Noon),
+    );
+    let difference = czc
+        .chatzos_as_half_day(&morning)
+        .unwrap()
+        .duration_since(&czc.shaos_into_day(&morning, 6.0).unwrap());
+    assert!(difference.abs() < SignedDuration::from_micros(1));
+    let shaah = czc.shaah_zmanis(&morning).unwrap();
+    // the morning is almost exactly half of the day
+    let half_gra = czc.shaah_zmanis_gra().unwrap() / 2;
+    assert!((shaah - half_gra).abs() < SignedDuration::from_secs(5));
+}
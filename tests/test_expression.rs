@@ -0,0 +1,240 @@
+use jiff::SignedDuration;
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+fn time(custom: &CustomZmanim, czc: &ComplexZmanimCalendar, name: &str) -> Option<jiff::Zoned> {
+    match custom.compute(czc, name)? {
+        ZmanValue::Time(time) => Some(time),
+        value => panic!("{name} is not a time: {value:?}"),
+    }
+}
+
+#[test]
+fn test_expressions_match_premade_zmanim() {
+    let custom = CustomZmanim::parse(
+        "alos = sunrise - 16.1deg;
+         tzeis = sunset + (7deg + 5deg / 60); # 7.083 degrees
+         szks = alos + 3 * shaah(alos, tzeis);
+         plag = alos + 10.75 * shaah(alos, tzeis);
+         alos_72 = alos(72min);
+         alos_72_zmanis = sunrise - 72zmanis;
+         shaah_mga = (tzeis(16.1deg) - alos) / 12;
+         mincha_gedola = max(mincha_gedola_gra, noon + 30min);",
+    )
+    .unwrap();
+    assert_eq!(
+        custom.names().collect::<Vec<_>>(),
+        [
+            "alos",
+            "tzeis",
+            "szks",
+            "plag",
+            "alos_72",
+            "alos_72_zmanis",
+            "shaah_mga",
+            "mincha_gedola"
+        ]
+    );
+
+    let mut czc = test_helper::czc_at(test_helper::lakewood());
+    for date in test_helper::sample_dates() {
+        czc.set_date(date);
+        assert_eq!(time(&custom, &czc, "alos"), czc.alos_16_1_degrees());
+        assert_eq!(
+            time(&custom, &czc, "tzeis"),
+            czc.tzeis_geonim_7_083_degrees()
+        );
+        assert_eq!(time(&custom, &czc, "alos_72"), czc.alos_72_minutes());
+        assert_eq!(
+            time(&custom, &czc, "alos_72_zmanis"),
+            czc.alos_72_minutes_zmanis()
+        );
+        assert_eq!(
+            time(&custom, &czc, "mincha_gedola"),
+            czc.mincha_gedola_gra_greater_than_30_minutes()
+        );
+        for (name, expected) in [
+            ("szks", czc.sof_zman_shema_alos_16_1_to_tzeis_7_083()),
+            ("plag", czc.plag_alos_16_1_to_tzeis_7_083()),
+        ] {
+            let difference = time(&custom, &czc, name)
+                .unwrap()
+                .duration_since(&expected.unwrap());
+            assert!(difference.abs() < SignedDuration::from_micros(1), "{name}");
+        }
+        let Some(ZmanValue::Duration(shaah)) = custom.compute(&czc, "shaah_mga") else {
+            panic!("shaah_mga is not a duration");
+        };
+        let difference = shaah - czc.shaah_zmanis_mga_16_1_degrees().unwrap();
+        assert!(difference.abs() < SignedDuration::from_micros(1));
+    }
+
+    // registry zmanim can be looked up the same way
+    assert_eq!(custom.kind("shaah_mga"), Some(ZmanKind::Duration));
+    assert_eq!(custom.kind("sof_zman_shema_gra"), Some(ZmanKind::Time));
+    assert_eq!(custom.kind("unknown"), None);
+    assert_eq!(
+        time(&custom, &czc, "sof_zman_shema_gra"),
+        czc.sof_zman_shema_gra()
+    );
+    assert!(custom.compute(&czc, "unknown").is_none());
+}
+
+#[test]
+fn test_expression_errors() {
+    for (source, error) in [
+        (
+            "a = sunrise +",
+            ExpressionError::Syntax(13, "expected a value"),
+        ),
+        (
+            "a = sunrise parsecs",
+            ExpressionError::Syntax(12, "expected ; or an operator"),
+        ),
+        (
+            "a = 5parsecs",
+            ExpressionError::Syntax(5, "expected a unit of deg, min, or zmanis"),
+        ),
+        (
+            "a = sunrise $",
+            ExpressionError::Syntax(12, "unexpected character"),
+        ),
+        (
+            "a = later",
+            ExpressionError::UnknownName(4, "later".to_string()),
+        ),
+        (
+            "a = b; b = sunrise",
+            ExpressionError::UnknownName(4, "b".to_string()),
+        ),
+        (
+            "a = sunrise; a = sunset",
+            ExpressionError::Redefined(13, "a".to_string()),
+        ),
+        (
+            "shkia = sunset",
+            ExpressionError::Redefined(0, "shkia".to_string()),
+        ),
+        (
+            "noon = sunset",
+            ExpressionError::Redefined(0, "noon".to_string()),
+        ),
+        (
+            "a = sunrise + sunset",
+            ExpressionError::TypeMismatch(12, "cannot add or subtract these values"),
+        ),
+        (
+            "a = noon - 8.5deg",
+            ExpressionError::TypeMismatch(
+                9,
+                "degrees can only be added to or subtracted from sunrise or sunset",
+            ),
+        ),
+        (
+            "a = 3 * 4",
+            ExpressionError::TypeMismatch(4, "a zman must be a time or a duration"),
+        ),
+        (
+            "a = shaah(sunrise)",
+            ExpressionError::TypeMismatch(4, "expected the start and end of the day"),
+        ),
+    ] {
+        assert_eq!(CustomZmanim::parse(source).unwrap_err(), error, "{source}");
+    }
+    assert!("a = sunrise; # no more".parse::<CustomZmanim>().is_ok());
+}
+
+#[test]
+fn test_nesting_limit() {
+    let nested = |depth: usize| format!("a = {}sunrise{}", "(".repeat(depth), ")".repeat(depth));
+    let chain = |length: usize| format!("a = sunrise{}", " + 1min - 1min".repeat(length));
+    let definitions = |count: usize| {
+        let mut source = "a0 = sunrise;".to_string();
+        for i in 1..count {
+            source += &format!("a{i} = a{} + 1min;", i - 1);
+        }
+        source
+    };
+    for source in [nested(50), chain(50), definitions(100)] {
+        assert!(CustomZmanim::parse(&source).is_ok());
+    }
+    // deep enough to overflow the stack without the limit
+    for source in [nested(100_000), chain(100_000), definitions(10_000)] {
+        assert!(matches!(
+            CustomZmanim::parse(&source),
+            Err(ExpressionError::Syntax(
+                _,
+                "the expression is nested too deeply"
+            ))
+        ));
+    }
+}
+
+#[test]
+fn test_definitions_calculated_once() {
+    // each definition uses the one before it three times, so calculating them
+    // again for each use would take 3^60 steps
+    let mut source = "a0 = 1min;".to_string();
+    for i in 1..60 {
+        let previous = format!("a{}", i - 1);
+        source += &format!("a{i} = {previous} + {previous} - {previous};");
+    }
+    let custom = CustomZmanim::parse(&source).unwrap();
+    let czc = test_helper::czc_at(test_helper::lakewood());
+    let Some(ZmanValue::Duration(duration)) = custom.compute(&czc, "a59") else {
+        panic!("no a59");
+    };
+    assert_eq!(duration, SignedDuration::from_mins(1));
+}
+
+#[test]
+fn test_custom_specs() {
+    let custom = CustomZmanim::parse(
+        "alos = sunrise - 16.1deg;
+         shaah_mga = (tzeis(16.1deg) - alos) / 12;",
+    )
+    .unwrap();
+    let alos = custom.parse_spec("alos").unwrap();
+    assert_eq!(alos.name(), "alos");
+    assert_eq!(alos.to_string(), "alos");
+    assert_eq!(alos.kind(), ZmanKind::Time);
+    assert_eq!(alos.category(), None);
+    assert_eq!(
+        custom.parse_spec("shaah_mga").unwrap().kind(),
+        ZmanKind::Duration
+    );
+    // other names are parsed as usual
+    assert_eq!(
+        custom.parse_spec("tzeis(8.5deg)").unwrap(),
+        ZmanSpec::parse("tzeis(8.5deg)").unwrap()
+    );
+    assert!(custom.parse_spec("later").is_err());
+
+    let czc = test_helper::czc_at(test_helper::lakewood());
+    match alos.compute(&czc) {
+        Some(ZmanValue::Time(time)) => assert_eq!(Some(time), czc.alos_16_1_degrees()),
+        value => panic!("{value:?}"),
+    }
+
+    let (start, end) = (
+        jiff::civil::date(2025, 6, 20),
+        jiff::civil::date(2025, 6, 22),
+    );
+    let table = ZmanimTable::new(test_helper::lakewood(), start, end)
+        .zmanim(custom.specs())
+        .zmanim([find_zman("alos_16_1_degrees").unwrap()]);
+    assert!(
+        table
+            .column_names()
+            .eq(["alos", "shaah_mga", "alos_16_1_degrees"])
+    );
+    for row in table.rows() {
+        match (&row.values[0], &row.values[2]) {
+            (Some(ZmanValue::Time(custom)), Some(ZmanValue::Time(registry))) => {
+                assert_eq!(custom, registry);
+            }
+            values => panic!("{values:?}"),
+        }
+        assert!(matches!(row.values[1], Some(ZmanValue::Duration(_))));
+    }
+}
@@ -0,0 +1,72 @@
+use jiff::tz::TimeZone;
+use rust_zmanim::util::geolocation::GeoLocation;
+mod test_helper;
+
+fn dms(degrees: f64, minutes: f64, seconds: f64) -> f64 {
+    degrees.signum() * (degrees.abs() + minutes / 60.0 + seconds / 3_600.0)
+}
+
+fn location(latitude: f64, longitude: f64) -> GeoLocation {
+    GeoLocation::new(latitude, longitude, 0.0, TimeZone::UTC).unwrap()
+}
+
+#[test]
+fn test_geodesic_vincenty_example() {
+    // Vincenty's example, from Flinders Peak to Buninyong (Australia)
+    let flinders_peak = location(dms(-37.0, 57.0, 3.72030), dms(144.0, 25.0, 29.52440));
+    let buninyong = location(dms(-37.0, 39.0, 10.15610), dms(143.0, 55.0, 35.38390));
+
+    let distance = flinders_peak.geodesic_distance(&buninyong).unwrap();
+    assert!((distance - 54_972.271).abs() < 0.001, "{distance}");
+    let initial = flinders_peak.geodesic_initial_bearing(&buninyong).unwrap();
+    assert!((initial - dms(306.0, 52.0, 5.37)).abs() < 1e-5, "{initial}");
+    let final_bearing = flinders_peak.geodesic_final_bearing(&buninyong).unwrap();
+    assert!(
+        (final_bearing - dms(307.0, 10.0, 25.07)).abs() < 1e-5,
+        "{final_bearing}"
+    );
+}
+
+#[test]
+fn test_geodesic_edge_cases() {
+    let lakewood = test_helper::lakewood();
+    assert_eq!(lakewood.geodesic_distance(&lakewood), Some(0.0));
+
+    // nearly antipodal points on the equator don't converge
+    let a = location(0.0, 0.0);
+    let b = location(0.5, 179.7);
+    assert_eq!(a.geodesic_distance(&b), None);
+}
+
+#[test]
+fn test_rhumb_line() {
+    // due east along the equator, a quarter of the way around
+    let a = location(0.0, 0.0);
+    let b = location(0.0, 90.0);
+    assert!((a.rhumb_line_bearing(&b) - 90.0).abs() < 1e-9);
+    let quarter = std::f64::consts::FRAC_PI_2 * 6_371_008.8;
+    assert!((a.rhumb_line_distance(&b) - quarter).abs() < 1e-3);
+
+    // the short way across the antimeridian is westward
+    let fiji = test_helper::fiji();
+    let samoa = test_helper::samoa();
+    let bearing = samoa.rhumb_line_bearing(&fiji);
+    assert!((180.0..360.0).contains(&bearing), "{bearing}");
+    assert!(samoa.rhumb_line_distance(&fiji) < 2_000_000.0);
+}
+
+#[test]
+fn test_bearing_to_beis_hamikdash() {
+    // from the New York area, the great circle sets out to the northeast,
+    // while the rhumb line heads just south of due east
+    let lakewood = test_helper::lakewood();
+    let geodesic = lakewood.geodesic_bearing_to_beis_hamikdash().unwrap();
+    assert!((50.0..60.0).contains(&geodesic), "{geodesic}");
+    let rhumb = lakewood.rhumb_line_bearing_to_beis_hamikdash();
+    assert!((90.0..100.0).contains(&rhumb), "{rhumb}");
+
+    // from Tokyo, west-northwest
+    let tokyo = test_helper::tokyo();
+    let geodesic = tokyo.geodesic_bearing_to_beis_hamikdash().unwrap();
+    assert!((280.0..310.0).contains(&geodesic), "{geodesic}");
+}
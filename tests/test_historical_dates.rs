@@ -0,0 +1,83 @@
+use jiff::civil;
+use rust_zmanim::{
+    prelude::*,
+    util::{delta_t::*, julian_calendar::*},
+};
+mod test_helper;
+
+#[test]
+fn test_delta_t_espenak_meeus() {
+    // values at the start of each polynomial's range
+    for (year, expected) in [
+        (1000.0, 1_574.2),
+        (1600.0, 120.0),
+        (1700.0, 8.83),
+        (1800.0, 13.72),
+        (1900.0, -2.79),
+        (1950.0, 29.07),
+        (2000.0, 63.86),
+    ] {
+        let actual = delta_t_seconds(year);
+        assert!((actual - expected).abs() < 1e-9, "{year}: {actual}");
+    }
+    // the long-term parabola, and its continuity with the polynomials
+    assert!((delta_t_seconds(-1180.0) - 28_780.0).abs() < 1e-9);
+    assert!((delta_t_seconds(2150.0) - delta_t_seconds(2149.999)).abs() < 0.01);
+    assert!((delta_t_seconds(500.0) - delta_t_seconds(499.999)).abs() < 0.1);
+
+    let dt = delta_t(civil::date(1992, 10, 13));
+    assert!((dt.as_secs_f64() - 59.0).abs() < 0.5, "{dt}");
+}
+
+#[test]
+fn test_julian_calendar_conversion() {
+    // the day before the Gregorian reform
+    assert_eq!(
+        from_julian_calendar(1582, 10, 4),
+        Some(civil::date(1582, 10, 14))
+    );
+    assert_eq!(
+        from_julian_calendar(2000, 1, 1),
+        Some(civil::date(2000, 1, 14))
+    );
+    // a Julian leap day that the proleptic Gregorian calendar doesn't have
+    assert_eq!(
+        from_julian_calendar(1500, 2, 29),
+        Some(civil::date(1500, 3, 10))
+    );
+    assert_eq!(from_julian_calendar(1500, 2, 30), None);
+    assert_eq!(from_julian_calendar(1500, 13, 1), None);
+
+    for date in [
+        civil::date(1066, 10, 24),
+        civil::date(1500, 3, 10),
+        civil::date(2024, 2, 29),
+        civil::date(-500, 1, 1),
+    ] {
+        let (y, m, d) = to_julian_calendar(date);
+        assert_eq!(from_julian_calendar(y, m, d), Some(date));
+    }
+    assert_eq!(to_julian_calendar(civil::date(1582, 10, 15)), (1582, 10, 5));
+}
+
+#[test]
+fn test_historical_dates() {
+    assert_eq!(
+        from_historical_date(1582, 10, 4),
+        Some(civil::date(1582, 10, 14))
+    );
+    assert_eq!(from_historical_date(1582, 10, 15), Some(GREGORIAN_REFORM));
+    assert_eq!(from_historical_date(1582, 10, 10), None);
+    assert_eq!(
+        from_historical_date(1492, 8, 2),
+        Some(civil::date(1492, 8, 11))
+    );
+
+    // zmanim on a historical date are calculated for the converted day
+    let czc = ComplexZmanimCalendar::new(
+        test_helper::jerusalem(),
+        from_historical_date(1564, 7, 15).unwrap(),
+        UseElevation::No,
+    );
+    assert_eq!(czc.hanetz().unwrap().date(), civil::date(1564, 7, 25));
+}
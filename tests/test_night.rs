@@ -0,0 +1,145 @@
+use jiff::{SignedDuration, Zoned, civil, tz::TimeZone};
+use rust_zmanim::prelude::*;
+
+fn yeshiva_university() -> ComplexZmanimCalendar {
+    let new_york = TimeZone::get("America/New_York").unwrap();
+    let yu = GeoLocation::new(40.8506041, -73.9297205, 0.0, new_york).unwrap();
+    ComplexZmanimCalendar::new(yu, civil::date(2025, 9, 13), UseElevation::No)
+}
+
+#[test]
+fn test_ashmuros() {
+    let czc = yeshiva_university();
+    let next_day = ComplexZmanimCalendar::new(
+        czc.geo_location().clone(),
+        czc.date().tomorrow().unwrap(),
+        UseElevation::No,
+    );
+    let ashmuros = czc.ashmuros(3).unwrap();
+    assert_eq!(ashmuros.len(), 4);
+    assert_eq!(ashmuros[0], czc.sea_level_sunset().unwrap());
+    assert_eq!(ashmuros[3], next_day.sea_level_sunrise().unwrap());
+    assert_eq!(
+        ashmuros[1].strftime("%Y-%m-%d %H:%M:%S").to_string(),
+        "2025-09-13 22:56:58"
+    );
+    // watches are equal
+    assert_eq!(
+        ashmuros[1].duration_since(&ashmuros[0]),
+        ashmuros[3].duration_since(&ashmuros[2])
+    );
+
+    assert_eq!(czc.ashmuros(12).unwrap().len(), 13);
+    assert!(czc.ashmuros(0).unwrap().is_empty());
+}
+
+#[test]
+fn test_chatzos_halayla_as_half_night() {
+    let czc = yeshiva_university();
+    let half_night = czc.chatzos_halayla_as_half_night().unwrap();
+    assert_eq!(half_night, czc.ashmuros(2).unwrap()[1]);
+    assert_eq!(half_night, czc.ashmuros(4).unwrap()[2]);
+    // close to, but not exactly, astronomical midnight
+    let difference = half_night.duration_since(&czc.chatzos_halayla().unwrap());
+    assert!(difference.abs() < SignedDuration::from_mins(2));
+
+    // the same fixed offset on both ends doesn't move the midpoint
+    assert_eq!(
+        czc.chatzos_halayla_tzeis_to_alos(&ZmanOffset::Minutes(72.0)),
+        Some(half_night)
+    );
+    let night = czc
+        .ashmuros_tzeis_to_alos(&ZmanOffset::Degrees(8.5), 3)
+        .unwrap();
+    assert_eq!(night[0], czc.tzeis_geonim_8_5_degrees().unwrap());
+}
+
+#[test]
+fn test_night_with_elevation() {
+    // the half night, the watches, and the nighttime shaos zmaniyos all divide
+    // the same night
+    let jerusalem = TimeZone::get("Asia/Jerusalem").unwrap();
+    let location = GeoLocation::new(31.7781161, 35.233804, 740.0, jerusalem).unwrap();
+    for use_elevation in [
+        UseElevation::No,
+        UseElevation::HanetzShkia,
+        UseElevation::All,
+    ] {
+        let czc =
+            ComplexZmanimCalendar::new(location.clone(), civil::date(2025, 12, 21), use_elevation);
+        let half_night = czc.chatzos_halayla_as_half_night().unwrap();
+        assert_close(&czc.ashmuros(2).unwrap()[1], &half_night);
+        assert_close(&czc.shaos_into_night(6.0).unwrap(), &half_night);
+        assert_close(
+            &czc.chatzos_halayla_tzeis_to_alos(&ZmanOffset::Minutes(72.0))
+                .unwrap(),
+            &half_night,
+        );
+    }
+}
+
+/// Different ways of dividing the night round to different nanoseconds
+fn assert_close(a: &Zoned, b: &Zoned) {
+    assert!(
+        a.duration_since(b).abs() < SignedDuration::from_micros(1),
+        "{a} != {b}"
+    );
+}
+
+#[test]
+fn test_shaos_into_night() {
+    let czc = yeshiva_university();
+    let ashmuros = czc.ashmuros(12).unwrap();
+    let shaah = czc.shaah_zmanis_halayla().unwrap();
+    assert_eq!(shaah, ashmuros[1].duration_since(&ashmuros[0]));
+    assert_eq!(czc.shaos_into_night(0.0), Some(ashmuros[0].clone()));
+    assert_close(
+        &czc.shaos_into_night(4.0).unwrap(),
+        &czc.ashmuros(3).unwrap()[1],
+    );
+    assert_close(
+        &czc.shaos_into_night(6.0).unwrap(),
+        &czc.chatzos_halayla_as_half_night().unwrap(),
+    );
+    // in mid-September the night is still shorter than the day
+    assert!(shaah < czc.shaah_zmanis_gra().unwrap());
+
+    let offset = ZmanOffset::Degrees(16.1);
+    let tzeis_to_alos = czc.shaah_zmanis_halayla_tzeis_to_alos(&offset).unwrap();
+    assert!(tzeis_to_alos < shaah);
+    assert_close(
+        &czc.shaos_into_night_tzeis_to_alos(&offset, 6.0).unwrap(),
+        &czc.chatzos_halayla_tzeis_to_alos(&offset).unwrap(),
+    );
+}
+
+#[test]
+fn test_next_day_follows_settings() {
+    let mut czc = yeshiva_university();
+    let sea_level = czc.shaah_zmanis_halayla().unwrap();
+    let mut elevated = czc.clone();
+    elevated.set_geo_location(
+        GeoLocation::new(
+            40.8506041,
+            -73.9297205,
+            500.0,
+            czc.geo_location().timezone().clone(),
+        )
+        .unwrap(),
+    );
+    let elevated_sea_level = elevated.shaah_zmanis_halayla().unwrap();
+    assert_eq!(elevated_sea_level, sea_level);
+    // changing a setting after the next day was cached applies to it too
+    elevated.set_use_elevation(UseElevation::All);
+    let elevated_sunrise = ComplexZmanimCalendar::new(
+        elevated.geo_location().clone(),
+        elevated.date().tomorrow().unwrap(),
+        UseElevation::All,
+    )
+    .elevation_sunrise()
+    .unwrap();
+    assert_eq!(elevated.ashmuros(1).unwrap()[1], elevated_sunrise);
+
+    czc.set_date(czc.date().tomorrow().unwrap());
+    assert_ne!(czc.shaah_zmanis_halayla(), Some(sea_level));
+}
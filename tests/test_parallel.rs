@@ -0,0 +1,65 @@
+#![cfg(feature = "rayon")]
+
+use jiff::civil;
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+fn rows_debug(rows: &[ZmanimRow]) -> Vec<String> {
+    rows.iter().map(|row| format!("{row:?}")).collect()
+}
+
+#[test]
+fn test_par_rows_match_rows() {
+    for (start, end) in [
+        (civil::date(2025, 1, 1), civil::date(2025, 4, 10)),
+        (civil::date(2025, 1, 1), civil::date(2025, 2, 1)),
+        (civil::date(2025, 1, 1), civil::date(2025, 1, 1)),
+        (civil::date(2025, 1, 2), civil::date(2025, 1, 1)),
+    ] {
+        let table = ZmanimTable::new(test_helper::arctic_nunavut(), start, end)
+            .use_elevation(UseElevation::All)
+            .zmanim(ALL_ZMANIM);
+        let rows: Vec<_> = table.rows().collect();
+        let par_rows = table.par_rows();
+        assert_eq!(rows_debug(&par_rows), rows_debug(&rows));
+    }
+}
+
+#[test]
+fn test_par_tables() {
+    let (start, end) = (civil::date(2025, 6, 1), civil::date(2025, 7, 31));
+    let tables: Vec<_> = [
+        test_helper::lakewood(),
+        test_helper::jerusalem(),
+        test_helper::arctic_nunavut(),
+        test_helper::samoa(),
+    ]
+    .into_iter()
+    .map(|location| {
+        ZmanimTable::new(location, start, end)
+            .profile(&MinhagProfile::LAKEWOOD_ASHKENAZ)
+            .unwrap()
+    })
+    .collect();
+    let results = par_tables(&tables);
+    assert_eq!(results.len(), tables.len());
+    for (table, rows) in tables.iter().zip(&results) {
+        assert_eq!(rows.len(), 61);
+        assert_eq!(
+            rows_debug(rows),
+            rows_debug(&table.rows().collect::<Vec<_>>())
+        );
+    }
+    // in the arctic summer some cells are missing, without affecting the rest
+    let arctic = &results[2];
+    assert!(
+        arctic
+            .iter()
+            .any(|row| row.values.iter().any(Option::is_none))
+    );
+    assert!(
+        arctic
+            .iter()
+            .all(|row| row.values.iter().any(Option::is_some))
+    );
+}
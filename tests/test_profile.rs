@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+#[test]
+fn test_built_in_profiles() {
+    for loc in [test_helper::lakewood(), test_helper::jerusalem()] {
+        let mut czc = test_helper::czc_at(loc);
+        for date in test_helper::sample_dates() {
+            czc.set_date(date);
+            for profile in MinhagProfile::BUILT_IN {
+                assert_eq!(MinhagProfile::find(&profile.name), Some(profile));
+                let schedule = czc.schedule(profile).unwrap();
+                assert_eq!(schedule.len(), ProfileItem::ALL.len());
+                let times: Vec<_> = schedule
+                    .iter()
+                    .map(|entry| entry.time.clone().unwrap())
+                    .collect();
+                // the zmanim are in order, and rounded to the minute
+                assert!(times.is_sorted(), "{}: {times:?}", profile.name);
+                for time in &times {
+                    assert_eq!(time.second(), 0);
+                    assert_eq!(time.subsec_nanosecond(), 0);
+                }
+            }
+        }
+    }
+    assert_eq!(MinhagProfile::find("unknown"), None);
+}
+
+#[test]
+fn test_built_in_profiles_use_practical_zmanim() {
+    // the *amiti* sunrise and sunset are only for deriving other zmanim, and
+    // shkia amiti is after visible sunset
+    for profile in MinhagProfile::BUILT_IN {
+        for item in ProfileItem::ALL {
+            if let Some(name) = profile.zman_name(item) {
+                assert!(!name.contains("_amiti_"), "{}: {name}", profile.name);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_schedule_uses_profile_settings() {
+    let czc = test_helper::czc_at(test_helper::jerusalem());
+    let profile = MinhagProfile {
+        rounding: Rounding::Exact,
+        ..MinhagProfile::YERUSHALMI
+    };
+    let schedule = czc.schedule(&profile).unwrap();
+    let time_of = |item| {
+        schedule
+            .iter()
+            .find(|entry| entry.item == item)
+            .unwrap()
+            .time
+            .clone()
+    };
+    assert_eq!(
+        time_of(ProfileItem::SofZmanShema),
+        czc.sof_zman_shema_mga_16_1_degrees()
+    );
+    assert_eq!(
+        time_of(ProfileItem::CandleLighting),
+        czc.candle_lighting(40.0)
+    );
+    // the calendar uses elevation for everything, the profile only for hanetz
+    // and shkia
+    assert_eq!(czc.use_elevation(), UseElevation::All);
+    assert_eq!(time_of(ProfileItem::Hanetz), czc.elevation_sunrise());
+    assert_eq!(time_of(ProfileItem::Chatzos), czc.chatzos_hayom());
+    assert_ne!(time_of(ProfileItem::MinchaGedola), czc.mincha_gedola_gra());
+
+    let rounded = czc.schedule(&MinhagProfile::YERUSHALMI).unwrap();
+    // alos is the earliest time, so it is rounded later
+    assert_eq!(
+        rounded[0].time,
+        Some(Rounding::LECHUMRA.round_earliest(schedule[0].time.as_ref().unwrap()))
+    );
+}
+
+#[test]
+fn test_invalid_profile() {
+    let czc = test_helper::czc_at(test_helper::lakewood());
+    let profile = MinhagProfile {
+        tzeis: Cow::Borrowed("tzeis_7_degrees"),
+        ..MinhagProfile::LAKEWOOD_ASHKENAZ
+    };
+    assert_eq!(
+        czc.schedule(&profile),
+        Err(ProfileError::UnknownZman(
+            ProfileItem::Tzeis,
+            "tzeis_7_degrees".to_string()
+        ))
+    );
+    // durations aren't times
+    let profile = MinhagProfile {
+        chatzos: Cow::Borrowed("shaah_zmanis_gra"),
+        ..MinhagProfile::LAKEWOOD_ASHKENAZ
+    };
+    assert!(czc.schedule(&profile).is_err());
+}
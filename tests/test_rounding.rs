@@ -0,0 +1,146 @@
+use jiff::{SignedDuration, Zoned};
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+fn time(s: &str) -> Zoned {
+    format!("2025-06-10T{s}-04:00[America/New_York]")
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn test_rounding_directions() {
+    let t = time("09:41:20");
+    let earliest = Some(ZmanBound::Earliest);
+    let latest = Some(ZmanBound::Latest);
+
+    assert_eq!(Rounding::Exact.round(&t, earliest), t);
+    assert_eq!(Rounding::NearestMinute.round(&t, latest), time("09:41"));
+    assert_eq!(Rounding::LECHUMRA.round(&t, earliest), time("09:42"));
+    assert_eq!(Rounding::LECHUMRA.round(&t, latest), time("09:41"));
+    assert_eq!(Rounding::LECHUMRA.round(&t, None), time("09:41"));
+    assert_eq!(
+        Rounding::LECHUMRA.round_earliest(&time("09:41:40")),
+        time("09:42")
+    );
+    assert_eq!(
+        Rounding::LECHUMRA.round_latest(&time("09:41:40")),
+        time("09:41")
+    );
+
+    // times already on the minute are unchanged
+    assert_eq!(
+        Rounding::LECHUMRA.round_earliest(&time("09:41")),
+        time("09:41")
+    );
+    assert_eq!(
+        Rounding::LECHUMRA.round_latest(&time("09:41")),
+        time("09:41")
+    );
+
+    let buffered = Rounding::Lechumra {
+        buffer: SignedDuration::from_mins(2),
+    };
+    assert_eq!(buffered.round_earliest(&t), time("09:44"));
+    assert_eq!(buffered.round_latest(&t), time("09:39"));
+    assert_eq!(buffered.round(&t, None), time("09:41"));
+}
+
+#[test]
+fn test_every_time_zman_has_a_bound_entry() {
+    for entry in ALL_ZMANIM.iter().filter(|e| e.kind == ZmanKind::Time) {
+        assert!(
+            ZMAN_BOUNDS
+                .iter()
+                .any(|(prefix, _)| entry.name.starts_with(prefix)),
+            "{} is not in ZMAN_BOUNDS",
+            entry.name
+        );
+    }
+    assert_eq!(zman_bound("sof_zman_shema_mga"), Some(ZmanBound::Latest));
+    assert_eq!(zman_bound("chatzos_halayla"), Some(ZmanBound::Latest));
+    assert_eq!(zman_bound("chatzos_hayom"), None);
+    assert_eq!(
+        zman_bound("samuch_lemincha_ketana_gra"),
+        Some(ZmanBound::Latest)
+    );
+    assert_eq!(zman_bound("mincha_ketana_gra"), Some(ZmanBound::Earliest));
+    assert_eq!(
+        zman_bound("tzeis_geonim_8_5_degrees"),
+        Some(ZmanBound::Earliest)
+    );
+    assert_eq!(zman_bound("unknown"), None);
+}
+
+/// The bound intended for a registry *zman* by its [`ZmanCategory`]. Chatzos
+/// and mincha are split by which of their *zmanim* it is
+fn intended_bound(entry: &ZmanEntry) -> Option<ZmanBound> {
+    match entry.category {
+        ZmanCategory::Alos
+        | ZmanCategory::Misheyakir
+        | ZmanCategory::Hanetz
+        | ZmanCategory::Tzeis => Some(ZmanBound::Earliest),
+        ZmanCategory::Shema
+        | ZmanCategory::Tefila
+        | ZmanCategory::BiurChametz
+        | ZmanCategory::Shkia
+        | ZmanCategory::BeinHashmashos => Some(ZmanBound::Latest),
+        ZmanCategory::Chatzos if entry.name.contains("halayla") => Some(ZmanBound::Latest),
+        ZmanCategory::Chatzos | ZmanCategory::Plag => None,
+        ZmanCategory::Mincha if entry.name.starts_with("samuch_lemincha_ketana") => {
+            Some(ZmanBound::Latest)
+        }
+        ZmanCategory::Mincha => Some(ZmanBound::Earliest),
+        ZmanCategory::ShaahZmanis => panic!("{} is a duration", entry.name),
+    }
+}
+
+#[test]
+fn test_bounds_match_registry_categories() {
+    // a new entry whose name falls under the wrong prefix fails here
+    for entry in ALL_ZMANIM.iter().filter(|e| e.kind == ZmanKind::Time) {
+        assert_eq!(
+            zman_bound(entry.name),
+            intended_bound(entry),
+            "{} ({:?})",
+            entry.name,
+            entry.category
+        );
+    }
+}
+
+#[test]
+fn test_profile_items_match_table() {
+    for profile in MinhagProfile::BUILT_IN {
+        for item in ProfileItem::ALL {
+            if let Some(name) = profile.zman_name(item) {
+                assert_eq!(item.bound(), zman_bound(name), "{name}");
+            }
+        }
+    }
+    assert_eq!(
+        ProfileItem::CandleLighting.bound(),
+        zman_bound("candle_lighting")
+    );
+}
+
+#[test]
+fn test_lechumra_schedule() {
+    let czc = test_helper::czc_at(test_helper::lakewood());
+    let exact = MinhagProfile {
+        rounding: Rounding::Exact,
+        ..MinhagProfile::LAKEWOOD_ASHKENAZ
+    };
+    let exact = czc.schedule(&exact).unwrap();
+    let rounded = czc.schedule(&MinhagProfile::LAKEWOOD_ASHKENAZ).unwrap();
+    for (exact, rounded) in exact.iter().zip(&rounded) {
+        let (exact_time, rounded_time) =
+            (exact.time.as_ref().unwrap(), rounded.time.as_ref().unwrap());
+        match exact.item.bound() {
+            Some(ZmanBound::Earliest) => assert!(rounded_time >= exact_time),
+            Some(ZmanBound::Latest) => assert!(rounded_time <= exact_time),
+            None => {}
+        }
+        assert!(exact_time.duration_until(rounded_time).abs() < SignedDuration::from_mins(1));
+    }
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "serde")]
+
+use core::fmt::Debug;
+
+use jiff::SignedDuration;
+use rust_zmanim::{
+    astronomical_calculator::Azimuth, prelude::*, util::geolocation::GeoLocationError,
+};
+use serde::{Serialize, de::DeserializeOwned};
+mod test_helper;
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+    let json = serde_json::to_string(value).unwrap();
+    let back: T = serde_json::from_str(&json).unwrap();
+    assert_eq!(&back, value, "{json}");
+}
+
+#[test]
+fn test_geo_location() {
+    let jerusalem = test_helper::jerusalem();
+    let json = serde_json::to_value(&jerusalem).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "latitude": 31.7781161,
+            "longitude": 35.233804,
+            "elevation": 740.0,
+            "timezone": "Asia/Jerusalem",
+        })
+    );
+    round_trip(&jerusalem);
+    round_trip(&test_helper::lakewood());
+
+    // deserializing validates the location
+    let invalid = r#"{"latitude": 91.0, "longitude": 0.0, "elevation": 0.0, "timezone": "UTC"}"#;
+    let error = serde_json::from_str::<GeoLocation>(invalid).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        GeoLocationError::InvalidLatitude(91.0).to_string()
+    );
+    let unknown_tz =
+        r#"{"latitude": 0.0, "longitude": 0.0, "elevation": 0.0, "timezone": "Nowhere/Nothing"}"#;
+    assert!(serde_json::from_str::<GeoLocation>(unknown_tz).is_err());
+    round_trip(&GeoLocationError::InvalidElevation(-1.0));
+}
+
+#[test]
+fn test_settings() {
+    for offset in [
+        ZmanOffset::Degrees(16.1),
+        ZmanOffset::Minutes(72.0),
+        ZmanOffset::MinutesZmaniyos {
+            minutes_zmaniyos: 90.0,
+            shaah_zmanis: SignedDuration::from_mins(63),
+        },
+    ] {
+        round_trip(&offset);
+    }
+    for use_elevation in [
+        UseElevation::No,
+        UseElevation::HanetzShkia,
+        UseElevation::All,
+    ] {
+        round_trip(&use_elevation);
+    }
+    round_trip(&TwilightFallback::SeventhOfNight);
+    round_trip(&Azimuth::East);
+    round_trip(&Azimuth::West);
+    round_trip(&DayDefinition::mga(ZmanOffset::Degrees(16.1)));
+    round_trip(&DayEvent::at(DayAnchor::Azimuth(270.0)));
+    for profile in MinhagProfile::BUILT_IN {
+        round_trip(profile);
+    }
+    round_trip(&Rounding::Lechumra {
+        buffer: SignedDuration::from_secs(30),
+    });
+    round_trip(&Uncertainty {
+        refraction: 0.2,
+        ..Uncertainty::default()
+    });
+}
+
+#[test]
+fn test_values() {
+    let czc = test_helper::czc_at(test_helper::jerusalem());
+    for entry in ALL_ZMANIM {
+        round_trip(&entry.kind);
+        let json = serde_json::to_string(&(entry.compute)(&czc)).unwrap();
+        let back: Option<ZmanValue> = serde_json::from_str(&json).unwrap();
+        match ((entry.compute)(&czc), back) {
+            (Some(ZmanValue::Time(a)), Some(ZmanValue::Time(b))) => assert_eq!(a, b),
+            (Some(ZmanValue::Duration(a)), Some(ZmanValue::Duration(b))) => assert_eq!(a, b),
+            (None, None) => {}
+            (a, b) => panic!("{}: {a:?} != {b:?}", entry.name),
+        }
+    }
+    for entry in czc.schedule(&MinhagProfile::CHABAD).unwrap() {
+        round_trip(&entry);
+    }
+}
+
+#[test]
+fn test_zman_spec() {
+    let spec = ZmanSpec::parse("plag_mga(90zmanis)").unwrap();
+    assert_eq!(
+        serde_json::to_value(&spec).unwrap(),
+        serde_json::json!("plag_mga(90zmanis)")
+    );
+    round_trip(&spec);
+    round_trip(&ZmanSpec::parse("sof_zman_shema_gra").unwrap());
+    assert!(serde_json::from_str::<ZmanSpec>(r#""tzeis(3)""#).is_err());
+    round_trip(&ParamValue::Offset(EntryOffset::Degrees(8.5)));
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "sync")]
+
+use std::{sync::Arc, thread};
+
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_shared_calendar() {
+    assert_send_sync::<ComplexZmanimCalendar>();
+
+    let czc = Arc::new(test_helper::czc_at(test_helper::jerusalem()));
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            let czc = Arc::clone(&czc);
+            thread::spawn(move || {
+                ALL_ZMANIM
+                    .iter()
+                    .map(|entry| format!("{:?}", (entry.compute)(&czc)))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    let fresh = test_helper::czc_at(test_helper::jerusalem());
+    let expected: Vec<_> = ALL_ZMANIM
+        .iter()
+        .map(|entry| format!("{:?}", (entry.compute)(&fresh)))
+        .collect();
+    for thread in threads {
+        assert_eq!(thread.join().unwrap(), expected);
+    }
+    // the night zmanim use the shared next day's calendar
+    assert_eq!(czc.shaah_zmanis_halayla(), fresh.shaah_zmanis_halayla());
+}
@@ -0,0 +1,207 @@
+use jiff::civil;
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+fn same(a: &Option<ZmanValue>, b: &Option<ZmanValue>) -> bool {
+    match (a, b) {
+        (Some(ZmanValue::Time(a)), Some(ZmanValue::Time(b))) => a == b,
+        (Some(ZmanValue::Duration(a)), Some(ZmanValue::Duration(b))) => a == b,
+        (Some(ZmanValue::Number(a)), Some(ZmanValue::Number(b))) => a == b,
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test_advance_day() {
+    let mut advanced = test_helper::czc_at(test_helper::lakewood());
+    let mut fresh = advanced.clone();
+    for _ in 0..10 {
+        // the night zmanim calculate the next day's events
+        let _ = advanced.shaah_zmanis_halayla();
+        advanced.advance_day();
+        fresh.set_date(fresh.date().tomorrow().unwrap());
+        assert_eq!(advanced, fresh);
+        for entry in ALL_ZMANIM {
+            assert!(
+                same(&(entry.compute)(&advanced), &(entry.compute)(&fresh)),
+                "{}",
+                entry.name
+            );
+        }
+    }
+    // without the next day's events
+    advanced.advance_day();
+    fresh.set_date(fresh.date().tomorrow().unwrap());
+    assert_eq!(advanced, fresh);
+}
+
+/// The number of the calendar's cached solar events that are not calculated
+/// yet, from its `Debug` output
+fn uncalculated_events(czc: &ComplexZmanimCalendar) -> usize {
+    format!("{czc:?}").matches("<uninit>").count()
+}
+
+#[test]
+fn test_advance_day_reuses_night_events() {
+    let start = test_helper::czc_at(test_helper::lakewood());
+    let mut fresh = start.clone();
+    fresh.set_date(fresh.date().tomorrow().unwrap());
+
+    // the night zmanim calculate the next day's sunrise
+    let mut night = start.clone();
+    let _ = night.shaah_zmanis_halayla();
+    let _ = night.chatzos_halayla_tzeis_to_alos(&ZmanOffset::Minutes(72.0));
+    night.advance_day();
+    assert_eq!(night, fresh);
+    assert!(uncalculated_events(&night) < uncalculated_events(&fresh));
+
+    // daytime zmanim leave nothing to reuse from the next day
+    let mut day = start;
+    let _ = day.sof_zman_shema_gra();
+    let _ = day.plag_gra();
+    day.advance_day();
+    assert_eq!(uncalculated_events(&day), uncalculated_events(&fresh));
+}
+
+#[test]
+fn test_solar_events_share_the_day() {
+    // the calendar's events share the date's solar noon ephemeris, and match
+    // the events calculated on their own exactly
+    let location = test_helper::lakewood();
+    let mut czc = test_helper::czc_at(location.clone());
+    for date in test_helper::sample_dates() {
+        czc.set_date(date);
+        assert_eq!(
+            czc.hanetz(),
+            zmanim_calculator::hanetz(date, &location, true),
+            "on {date}"
+        );
+        assert_eq!(
+            czc.shkia(),
+            zmanim_calculator::shkia(date, &location, true),
+            "on {date}"
+        );
+        assert_eq!(
+            czc.sea_level_sunrise(),
+            astronomical_calculator::sea_level_sunrise(date, &location),
+            "on {date}"
+        );
+        assert_eq!(
+            czc.alos_16_1_degrees(),
+            astronomical_calculator::sunrise_offset_by_degrees(
+                date,
+                &location,
+                astronomical_calculator::GEOMETRIC_ZENITH + 16.1
+            ),
+            "on {date}"
+        );
+        assert_eq!(
+            czc.tzeis_geonim_8_5_degrees(),
+            astronomical_calculator::sunset_offset_by_degrees(
+                date,
+                &location,
+                astronomical_calculator::GEOMETRIC_ZENITH + 8.5
+            ),
+            "on {date}"
+        );
+    }
+}
+
+#[test]
+fn test_table_matches_calendar() {
+    let location = test_helper::jerusalem();
+    let (start, end) = (civil::date(2025, 3, 25), civil::date(2025, 4, 5));
+    let table = ZmanimTable::new(location.clone(), start, end)
+        .use_elevation(UseElevation::HanetzShkia)
+        .twilight_fallback(TwilightFallback::SeventhOfNight)
+        .zmanim(ALL_ZMANIM);
+    assert_eq!(table.column_names().count(), ALL_ZMANIM.len());
+    assert!(table.column_names().eq(ALL_ZMANIM.iter().map(|e| e.name)));
+
+    let rows: Vec<_> = table.rows().collect();
+    assert_eq!(rows.len(), 12);
+    let mut czc = ComplexZmanimCalendar::new(location, start, UseElevation::HanetzShkia);
+    czc.set_twilight_fallback(TwilightFallback::SeventhOfNight);
+    for row in rows {
+        czc.set_date(row.date);
+        for (entry, value) in ALL_ZMANIM.iter().zip(&row.values) {
+            assert!(same(value, &(entry.compute)(&czc)), "{}", entry.name);
+        }
+    }
+    assert_eq!(
+        table.rows().map(|row| row.date).last(),
+        Some(civil::date(2025, 4, 5))
+    );
+
+    // an empty range
+    let table = ZmanimTable::new(test_helper::jerusalem(), end, start);
+    assert_eq!(table.rows().count(), 0);
+}
+
+#[test]
+fn test_table_of_profile() {
+    let location = test_helper::lakewood();
+    let start = civil::date(2025, 6, 1);
+    let table = ZmanimTable::new(location.clone(), start, civil::date(2025, 6, 7))
+        .profile(&MinhagProfile::LAKEWOOD_ASHKENAZ)
+        .unwrap();
+    assert!(
+        table
+            .column_names()
+            .eq(ProfileItem::ALL.iter().map(|item| item.name()))
+    );
+    let mut czc = ComplexZmanimCalendar::new(location, start, UseElevation::No);
+    for row in table.rows() {
+        czc.set_date(row.date);
+        let schedule = czc.schedule(&MinhagProfile::LAKEWOOD_ASHKENAZ).unwrap();
+        for (entry, value) in schedule.iter().zip(&row.values) {
+            assert!(same(value, &entry.time.clone().map(ZmanValue::Time)));
+        }
+    }
+
+    let invalid = MinhagProfile {
+        shkia: "sunset".into(),
+        ..MinhagProfile::LAKEWOOD_ASHKENAZ
+    };
+    assert_eq!(
+        ZmanimTable::new(test_helper::lakewood(), start, start)
+            .profile(&invalid)
+            .unwrap_err(),
+        ProfileError::UnknownZman(ProfileItem::Shkia, "sunset".to_string())
+    );
+}
+
+#[test]
+fn test_table_rounding() {
+    let start = civil::date(2025, 6, 1);
+    let table = ZmanimTable::new(test_helper::lakewood(), start, start)
+        .rounding(Rounding::LECHUMRA)
+        .zmanim([
+            find_zman("tzeis_geonim_8_5_degrees").unwrap(),
+            find_zman("sof_zman_shema_gra").unwrap(),
+            find_zman("shaah_zmanis_gra").unwrap(),
+        ]);
+    let czc = ComplexZmanimCalendar::new(test_helper::lakewood(), start, UseElevation::No);
+    let row = table.rows().next().unwrap();
+    let [
+        Some(ZmanValue::Time(tzeis)),
+        Some(ZmanValue::Time(shema)),
+        Some(shaah),
+    ] = &row.values[..]
+    else {
+        panic!("{:?}", row.values);
+    };
+    assert_eq!(
+        tzeis,
+        &Rounding::LECHUMRA.round_earliest(&czc.tzeis_geonim_8_5_degrees().unwrap())
+    );
+    assert_eq!(
+        shema,
+        &Rounding::LECHUMRA.round_latest(&czc.sof_zman_shema_gra().unwrap())
+    );
+    assert!(same(
+        &Some(shaah.clone()),
+        &czc.shaah_zmanis_gra().map(ZmanValue::Duration)
+    ));
+}
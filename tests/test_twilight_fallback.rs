@@ -0,0 +1,162 @@
+use jiff::{civil, tz::TimeZone};
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+fn manchester_czc(fallback: TwilightFallback) -> ComplexZmanimCalendar {
+    let loc = GeoLocation::new(
+        53.4808,
+        -2.2426,
+        38.0,
+        TimeZone::get("Europe/London").unwrap(),
+    )
+    .unwrap();
+    let mut czc = ComplexZmanimCalendar::new(loc, civil::date(2026, 6, 21), UseElevation::No);
+    czc.set_twilight_fallback(fallback);
+    czc
+}
+
+#[test]
+fn test_fallback_disabled_by_default() {
+    let czc = manchester_czc(TwilightFallback::Disabled);
+    assert_eq!(czc.twilight_fallback(), TwilightFallback::Disabled);
+    assert_eq!(czc.alos_18_degrees(), None);
+    assert_eq!(czc.tzeis_18_degrees(), None);
+    // reached angles are unaffected by the fallback
+    assert_eq!(
+        czc.hanetz_amiti_baal_hatanya(),
+        manchester_czc(TwilightFallback::SeventhOfNight).hanetz_amiti_baal_hatanya()
+    );
+}
+
+#[test]
+fn test_fallback_solar_midnight() {
+    let czc = manchester_czc(TwilightFallback::SolarMidnight);
+    assert_eq!(czc.tzeis_18_degrees(), czc.chatzos_halayla());
+
+    let mut yesterday = czc.clone();
+    yesterday.set_date(civil::date(2026, 6, 20));
+    assert_eq!(czc.alos_18_degrees(), yesterday.chatzos_halayla());
+}
+
+#[test]
+fn test_fallback_seventh_of_night() {
+    let czc = manchester_czc(TwilightFallback::SeventhOfNight);
+    let sunset = czc.shkia().unwrap();
+    let mut tomorrow = czc.clone();
+    tomorrow.set_date(civil::date(2026, 6, 22));
+    let night = tomorrow.hanetz().unwrap().duration_since(&sunset);
+    assert_eq!(czc.tzeis_18_degrees(), Some(&sunset + night / 7));
+    assert!(czc.alos_18_degrees().unwrap() < czc.hanetz().unwrap());
+}
+
+#[test]
+fn test_fallback_minutes_from_other_days() {
+    let mut equinox = manchester_czc(TwilightFallback::Disabled);
+    equinox.set_date(civil::date(2026, 3, 20));
+    let equinox_minutes = equinox
+        .hanetz()
+        .unwrap()
+        .duration_since(&equinox.alos_18_degrees().unwrap());
+
+    let czc = manchester_czc(TwilightFallback::EquinoxMinutes);
+    assert_eq!(
+        czc.hanetz()
+            .unwrap()
+            .duration_since(&czc.alos_18_degrees().unwrap()),
+        equinox_minutes
+    );
+
+    // the last day 18 degrees was reached is in May, with a long twilight
+    let czc = manchester_czc(TwilightFallback::LastDayReached);
+    let alos = czc.alos_18_degrees().unwrap();
+    let twilight = czc.hanetz().unwrap().duration_since(&alos);
+    assert!(twilight.as_mins() > 120, "{twilight}");
+    assert!(czc.tzeis_18_degrees().unwrap() > czc.shkia().unwrap());
+}
+
+#[test]
+fn test_fallback_equinox_is_computed() {
+    // the 2007 March equinox was at 00:07 UTC on March 21
+    let mut czc = manchester_czc(TwilightFallback::EquinoxMinutes);
+    czc.set_date(civil::date(2007, 6, 21));
+    let mut equinox = manchester_czc(TwilightFallback::Disabled);
+    equinox.set_date(civil::date(2007, 3, 21));
+    assert_eq!(
+        czc.hanetz()
+            .unwrap()
+            .duration_since(&czc.alos_18_degrees().unwrap()),
+        equinox
+            .hanetz()
+            .unwrap()
+            .duration_since(&equinox.alos_18_degrees().unwrap())
+    );
+}
+
+#[test]
+fn test_fallback_changes_mga_degree_zmanim() {
+    let disabled = manchester_czc(TwilightFallback::Disabled);
+    assert_eq!(disabled.sof_zman_shema_mga_16_1_degrees(), None);
+    assert_eq!(disabled.plag_mga_16_1_degrees(), None);
+    assert_eq!(disabled.shaah_zmanis_mga_16_1_degrees(), None);
+
+    let czc = manchester_czc(TwilightFallback::SeventhOfNight);
+    let alos = czc.alos_16_1_degrees().unwrap();
+    let tzeis = czc.tzeis_16_1_degrees().unwrap();
+    let shaah = czc.shaah_zmanis_mga_16_1_degrees().unwrap();
+    assert_eq!(shaah, tzeis.duration_since(&alos) / 12);
+    assert_eq!(
+        czc.sof_zman_shema_mga_16_1_degrees(),
+        Some(&alos + shaah * 3)
+    );
+}
+
+#[test]
+fn test_last_day_reached_carried_forward() {
+    // through the summer, when 18 degrees is not reached, and out of it
+    let mut advanced = manchester_czc(TwilightFallback::LastDayReached);
+    advanced.set_date(civil::date(2026, 5, 1));
+    let mut fresh = advanced.clone();
+    while advanced.date() < civil::date(2026, 8, 31) {
+        if advanced.date() == civil::date(2026, 7, 1) {
+            // the twilight is measured from sunrise and sunset with elevation
+            advanced.set_use_elevation(UseElevation::All);
+            fresh.set_use_elevation(UseElevation::All);
+        }
+        assert_eq!(advanced.alos_18_degrees(), fresh.alos_18_degrees());
+        assert_eq!(advanced.tzeis_18_degrees(), fresh.tzeis_18_degrees());
+        assert_eq!(advanced.alos_16_1_degrees(), fresh.alos_16_1_degrees());
+        advanced.advance_day();
+        fresh.set_date(fresh.date().tomorrow().unwrap());
+    }
+}
+
+#[test]
+fn test_fallback_not_used_in_polar_night() {
+    let mut czc = ComplexZmanimCalendar::new(
+        test_helper::arctic_nunavut(),
+        civil::date(2025, 12, 21),
+        UseElevation::No,
+    );
+    czc.set_twilight_fallback(TwilightFallback::SolarMidnight);
+    assert_eq!(czc.alos(&ZmanOffset::Degrees(1.0)), None);
+}
+
+#[test]
+fn test_baal_hatanya_does_not_fall_back() {
+    // the sun sets, but never gets 1.583 degrees below the horizon
+    let loc = GeoLocation::new(65.4, 25.0, 0.0, TimeZone::get("Europe/Helsinki").unwrap()).unwrap();
+    let mut czc = ComplexZmanimCalendar::new(loc, civil::date(2026, 6, 21), UseElevation::No);
+    czc.set_twilight_fallback(TwilightFallback::SeventhOfNight);
+    assert!(czc.shkia().is_some());
+    assert!(czc.tzeis(&ZmanOffset::Degrees(1.583)).is_some());
+    assert_eq!(czc.hanetz_amiti_baal_hatanya(), None);
+    assert_eq!(czc.shkia_amiti_baal_hatanya(), None);
+    assert_eq!(czc.shaah_zmanis_baal_hatanya(), None);
+    assert_eq!(czc.sof_zman_shema_baal_hatanya(), None);
+    assert_eq!(czc.mincha_gedola_baal_hatanya(), None);
+    assert_eq!(
+        czc.mincha_gedola_baal_hatanya_greater_than_30_minutes(),
+        None
+    );
+    assert_eq!(czc.plag_baal_hatanya(), None);
+}
@@ -0,0 +1,117 @@
+use jiff::SignedDuration;
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+#[test]
+fn test_no_uncertainty() {
+    let czc = test_helper::czc_at(test_helper::jerusalem());
+    for entry in ALL_ZMANIM.iter().filter(|e| e.kind == ZmanKind::Time) {
+        let zman = |czc: &ComplexZmanimCalendar| match (entry.compute)(czc) {
+            Some(ZmanValue::Time(time)) => Some(time),
+            _ => None,
+        };
+        let Some(interval) = czc.zman_interval(&Uncertainty::default(), zman) else {
+            assert_eq!(zman(&czc), None, "{}", entry.name);
+            continue;
+        };
+        assert_eq!(interval.width(), SignedDuration::ZERO, "{}", entry.name);
+        assert_eq!(Some(interval.nominal), zman(&czc), "{}", entry.name);
+    }
+}
+
+#[test]
+fn test_refraction_and_horizon() {
+    let czc = test_helper::czc_at(test_helper::lakewood());
+    let uncertainty = Uncertainty {
+        refraction: 0.1,
+        ..Uncertainty::default()
+    };
+    let sunrise = czc
+        .zman_interval(&uncertainty, ComplexZmanimCalendar::hanetz)
+        .unwrap();
+    assert_eq!(Some(sunrise.nominal.clone()), czc.hanetz());
+    assert!(sunrise.earliest < sunrise.nominal && sunrise.nominal < sunrise.latest);
+    // about 4 minutes per degree, more away from the equator
+    let margin = sunrise.margin();
+    assert!(
+        margin > SignedDuration::from_secs(20) && margin < SignedDuration::from_secs(45),
+        "{margin:?}"
+    );
+    assert!(sunrise.contains(&sunrise.nominal));
+
+    // the same uncertainty in the horizon moves sunrise the same amount
+    let horizon = Uncertainty {
+        horizon: 0.1,
+        ..Uncertainty::default()
+    };
+    let by_horizon = czc
+        .zman_interval(&horizon, ComplexZmanimCalendar::hanetz)
+        .unwrap();
+    assert!((by_horizon.width() - sunrise.width()).abs() < SignedDuration::from_micros(1));
+    let both = Uncertainty {
+        refraction: 0.1,
+        horizon: 0.1,
+        ..Uncertainty::default()
+    };
+    let both = czc
+        .zman_interval(&both, ComplexZmanimCalendar::hanetz)
+        .unwrap();
+    assert!(both.width() > sunrise.width());
+
+    // degree-based zmanim are measured from the geometric horizon
+    let alos = czc
+        .zman_interval(&uncertainty, ComplexZmanimCalendar::alos_16_1_degrees)
+        .unwrap();
+    assert_eq!(alos.width(), SignedDuration::ZERO);
+
+    // zmanim calculated from sunrise and sunset have intervals too
+    let plag = czc
+        .zman_interval(&uncertainty, ComplexZmanimCalendar::plag_gra)
+        .unwrap();
+    assert!(plag.width() > SignedDuration::ZERO);
+}
+
+#[test]
+fn test_elevation_and_coordinates() {
+    let czc = test_helper::czc_at(test_helper::jerusalem());
+    let elevation = Uncertainty {
+        elevation: 50.0,
+        ..Uncertainty::default()
+    };
+    let sea_level = czc
+        .zman_interval(&elevation, ComplexZmanimCalendar::sea_level_sunrise)
+        .unwrap();
+    assert_eq!(sea_level.width(), SignedDuration::ZERO);
+    let sunrise = czc
+        .zman_interval(&elevation, ComplexZmanimCalendar::elevation_sunrise)
+        .unwrap();
+    assert!(sunrise.width() > SignedDuration::ZERO);
+
+    let coordinates = Uncertainty {
+        coordinates: 0.01,
+        ..Uncertainty::default()
+    };
+    let sunrise = czc
+        .zman_interval(&coordinates, ComplexZmanimCalendar::sea_level_sunrise)
+        .unwrap();
+    assert!(sunrise.width() > SignedDuration::ZERO);
+    assert!(sunrise.margin() < SignedDuration::from_secs(10));
+}
+
+#[test]
+fn test_interval_display() {
+    let czc = test_helper::czc_at(test_helper::lakewood());
+    let uncertainty = Uncertainty {
+        refraction: 0.1,
+        ..Uncertainty::default()
+    };
+    let sunrise = czc
+        .zman_interval(&uncertainty, ComplexZmanimCalendar::hanetz)
+        .unwrap();
+    let margin = sunrise.margin();
+    let seconds = margin.as_secs() + i64::from(margin.subsec_nanos() > 0);
+    assert_eq!(
+        sunrise.to_string(),
+        format!("{} ± {seconds}s", sunrise.nominal.strftime("%H:%M:%S"))
+    );
+}
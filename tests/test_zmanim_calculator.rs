@@ -0,0 +1,124 @@
+use jiff::{civil, tz::TimeZone};
+use rust_zmanim::prelude::*;
+mod test_helper;
+
+fn manchester() -> GeoLocation {
+    GeoLocation::new(
+        53.4808,
+        -2.2426,
+        38.0,
+        TimeZone::get("Europe/London").unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_try_alos_white_nights() {
+    let loc = manchester();
+    let date = civil::date(2026, 6, 21);
+    assert_eq!(
+        zmanim_calculator::try_alos(date, &loc, false, &ZmanOffset::Degrees(18.0)),
+        Err(ZmanError::DepressionNotReached(18.0))
+    );
+    assert_eq!(
+        zmanim_calculator::try_tzeis(date, &loc, false, &ZmanOffset::Degrees(18.0)),
+        Err(ZmanError::DepressionNotReached(18.0))
+    );
+    // the sun does still set and rise
+    assert!(zmanim_calculator::try_hanetz(date, &loc, true).is_ok());
+    assert!(zmanim_calculator::try_tzeis(date, &loc, false, &ZmanOffset::Minutes(72.0)).is_ok());
+
+    let czc = ComplexZmanimCalendar::new(loc, date, UseElevation::No);
+    assert_eq!(czc.alos_18_degrees(), None);
+    assert_eq!(
+        czc.try_alos(&ZmanOffset::Degrees(18.0)),
+        Err(ZmanError::DepressionNotReached(18.0))
+    );
+}
+
+#[test]
+fn test_try_matches_option() {
+    let loc = manchester();
+    for date in test_helper::sample_dates() {
+        let offset = ZmanOffset::Degrees(16.1);
+        assert_eq!(
+            zmanim_calculator::try_alos(date, &loc, false, &offset).ok(),
+            zmanim_calculator::alos(date, &loc, false, &offset)
+        );
+        assert_eq!(
+            zmanim_calculator::try_shkia(date, &loc, true).ok(),
+            zmanim_calculator::shkia(date, &loc, true)
+        );
+    }
+}
+
+#[test]
+fn test_try_polar_day_and_night() {
+    let czc = ComplexZmanimCalendar::new(
+        test_helper::arctic_nunavut(),
+        civil::date(2026, 6, 21),
+        UseElevation::No,
+    );
+    assert_eq!(czc.try_hanetz(), Err(ZmanError::PolarDay));
+    assert_eq!(czc.try_shkia(), Err(ZmanError::PolarDay));
+    assert_eq!(
+        czc.try_tzeis(&ZmanOffset::Minutes(72.0)),
+        Err(ZmanError::PolarDay)
+    );
+
+    let czc = ComplexZmanimCalendar::new(
+        test_helper::arctic_nunavut(),
+        civil::date(2025, 12, 21),
+        UseElevation::No,
+    );
+    assert_eq!(czc.try_hanetz(), Err(ZmanError::PolarNight));
+    assert_eq!(czc.try_shkia(), Err(ZmanError::PolarNight));
+    assert_eq!(
+        czc.try_alos(&ZmanOffset::Degrees(1.0)),
+        Err(ZmanError::DepressionNotCleared(1.0))
+    );
+}
+
+#[test]
+fn test_mincha_gedola_greater_than_30_minutes() {
+    let mut czc = test_helper::czc_at(test_helper::lakewood());
+    for date in test_helper::sample_dates() {
+        czc.set_date(date);
+        let mg_30 = czc.mincha_gedola_30_minutes().unwrap();
+        for (greater, mincha_gedola) in [
+            (
+                czc.mincha_gedola_mga_72_minutes_greater_than_30_minutes(),
+                czc.mincha_gedola_mga_72_minutes(),
+            ),
+            (
+                czc.mincha_gedola_mga_16_1_degrees_greater_than_30_minutes(),
+                czc.mincha_gedola_mga_16_1_degrees(),
+            ),
+            (
+                czc.mincha_gedola_ateret_torah_greater_than_30_minutes(),
+                czc.mincha_gedola_ateret_torah(),
+            ),
+            (
+                czc.mincha_gedola_gra_greater_than_30_minutes(),
+                czc.mincha_gedola_gra(),
+            ),
+        ] {
+            let mincha_gedola = mincha_gedola.unwrap();
+            assert_eq!(greater, Some(mg_30.clone().max(mincha_gedola.clone())));
+            assert_eq!(
+                greater,
+                Some(zmanim_calculator::mincha_gedola_greater_than_30_minutes(
+                    &mincha_gedola,
+                    &czc.chatzos_hayom().unwrap()
+                ))
+            );
+        }
+    }
+
+    // in the winter, half of a GRA shaah zmanis is less than 30 minutes
+    czc.set_date(civil::date(2025, 12, 21));
+    assert_eq!(
+        czc.mincha_gedola_gra_greater_than_30_minutes(),
+        czc.mincha_gedola_30_minutes()
+    );
+}